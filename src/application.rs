@@ -154,6 +154,12 @@ impl Application {
                     app.quit();
                 })
                 .build(),
+            // Preferences
+            gio::ActionEntry::builder("preferences")
+                .activate(|app: &Self, _, _| {
+                    view::PreferencesDialog::default().present(Some(&app.main_window()));
+                })
+                .build(),
             // About
             gio::ActionEntry::builder("about")
                 .activate(|app: &Self, _, _| {
@@ -166,6 +172,7 @@ impl Application {
     // Sets up keyboard shortcuts
     fn setup_accels(&self) {
         self.set_accels_for_action("app.quit", &["<Control>q"]);
+        self.set_accels_for_action("app.preferences", &["<Control>comma"]);
     }
 
     fn show_about_dialog(&self) {
@@ -227,6 +227,7 @@ fn init() {
     view::ImagesRow::static_type();
     view::InfoPanel::static_type();
     view::InfoRow::static_type();
+    view::MiniView::static_type();
     view::PodMenuButton::static_type();
     view::PodRow::static_type();
     view::PodSelectionPage::static_type();
@@ -259,5 +260,6 @@ fn init() {
     widget::SourceViewSearchWidget::static_type();
     widget::Spinner::static_type();
     widget::TextSearchEntry::static_type();
+    widget::ThroughputGraph::static_type();
     widget::ZoomControl::static_type();
 }
@@ -15,6 +15,7 @@ use futures::future;
 use futures::lock::Mutex;
 use futures::stream;
 use gettextrs::gettext;
+use gettextrs::ngettext;
 use gio::subclass::prelude::*;
 use glib::Properties;
 use glib::clone;
@@ -59,13 +60,17 @@ pub(crate) enum Type {
     Undefined,
 }
 
+/// Starts the operation of an action again, unless the action list is gone.
+type Retry = Box<dyn Fn() -> Option<future::LocalBoxFuture<'static, Action>>>;
+
 mod imp {
     use super::*;
 
-    #[derive(Debug, Default, Properties)]
+    #[derive(Default, Properties)]
     #[properties(wrapper_type = super::Action)]
     pub(crate) struct Action {
         pub(super) abort_handle: RefCell<Option<stream::AbortHandle>>,
+        pub(super) retry: RefCell<Option<Retry>>,
         #[property(get, nullable)]
         pub(super) artifact: glib::WeakRef<glib::Object>,
         #[property(get, set, construct_only)]
@@ -82,6 +87,10 @@ mod imp {
         pub(super) end_timestamp: OnceCell<i64>,
         #[property(get)]
         pub(super) output: gtk::TextBuffer,
+        #[property(get)]
+        pub(super) cached_steps: Cell<u32>,
+        #[property(get)]
+        pub(super) rebuilt_steps: Cell<u32>,
     }
 
     #[glib::object_subclass]
@@ -116,6 +125,62 @@ impl Action {
             self.set_state(State::Aborted);
         }
     }
+
+    /// Summarizes how many build steps have been taken from the cache and how many have been
+    /// rebuilt, or `None` if no build step has finished.
+    pub(crate) fn build_steps_summary(&self) -> Option<String> {
+        let (cached, rebuilt) = (self.cached_steps(), self.rebuilt_steps());
+        if cached + rebuilt == 0 {
+            return None;
+        }
+
+        Some(gettext!(
+            // Translators: The first "{}" is the number of cached steps, the second is the number
+            // of rebuilt steps.
+            "{}, {}",
+            ngettext!("{} step cached", "{} steps cached", cached, cached),
+            ngettext!("{} rebuilt", "{} rebuilt", rebuilt, rebuilt)
+        ))
+    }
+
+    /// Returns the tag that annotates finished build steps in the output. Coloring it is up to
+    /// the view showing the output.
+    pub(crate) fn build_step_tag(&self, cached: bool) -> gtk::TextTag {
+        let output = self.output();
+        let name = if cached {
+            "build-step-cached"
+        } else {
+            "build-step-rebuilt"
+        };
+
+        output
+            .tag_table()
+            .lookup(name)
+            .unwrap_or_else(|| output.create_tag(Some(name), &[("weight", &700)]).unwrap())
+    }
+
+    pub(crate) fn can_retry(&self) -> bool {
+        self.imp().retry.borrow().is_some()
+    }
+
+    /// Starts the operation again with the same parameters as a new action. Returns `None` if the
+    /// action type can't be retried.
+    pub(crate) async fn retry(&self) -> Option<Self> {
+        let future = self
+            .imp()
+            .retry
+            .borrow()
+            .as_ref()
+            .and_then(|retry| retry())?;
+        Some(future.await)
+    }
+
+    pub(super) fn set_retry<F>(&self, retry: F)
+    where
+        F: Fn() -> Option<future::LocalBoxFuture<'static, Self>> + 'static,
+    {
+        self.imp().retry.replace(Some(Box::new(retry)));
+    }
 }
 
 impl Action {
@@ -166,6 +231,34 @@ impl Action {
         obj
     }
 
+    /// Pulls every tag of a repository. The opts must have `all_tags` set.
+    pub(crate) fn download_all_tags(
+        num: u32,
+        repository: &str,
+        client: model::Client,
+        opts: podman::opts::PullOpts,
+    ) -> Self {
+        Self::new(
+            num,
+            Type::DownloadImage,
+            &gettext!("Pull all tags of <b>{}</b>", repository),
+        )
+        .download_image_(client, opts, |obj, client, report| {
+            let num_images = report.images.map(|images| images.len()).unwrap_or(0);
+            obj.insert_line(&ngettext!(
+                "Pulled {} image",
+                "Pulled {} images",
+                num_images as u32,
+                num_images
+            ));
+
+            // Make sure that all pulled tags are shown, regardless of the events.
+            client.image_list().refresh(|_| {});
+
+            obj.set_state(State::Finished);
+        })
+    }
+
     pub(crate) fn download_image(
         num: u32,
         image: &str,
@@ -297,10 +390,16 @@ impl Action {
             #[weak]
             obj,
             async move {
+                let mut build_steps = BuildSteps::default();
+
                 while let Some(result) = stream.next().await {
                     match result {
                         Ok(stream) => {
                             obj.insert(&stream.stream);
+                            build_steps
+                                .feed(&stream.stream)
+                                .into_iter()
+                                .for_each(|cached| obj.on_build_step_finished(cached));
                             // glib::ControlFlow::Continue
                         }
                         Err(e) => {
@@ -321,6 +420,10 @@ impl Action {
 
                 let image_id = output.text(&start, &end, false).trim().to_owned();
 
+                if let Some(summary) = obj.build_steps_summary() {
+                    obj.insert_line(&summary);
+                }
+
                 match client.image_list().get_image(&image_id) {
                     Some(image) => {
                         obj.set_artifact(image.upcast_ref());
@@ -1090,6 +1193,32 @@ impl Action {
         self.insert(&format!("{text}\n"));
     }
 
+    /// Annotates the output with whether the last build step has been taken from the cache.
+    fn on_build_step_finished(&self, cached: bool) {
+        let imp = self.imp();
+
+        let output = self.output();
+        let mut iter = output.start_iter();
+        output.insert_with_tags(
+            &mut iter,
+            &if cached {
+                gettext("↳ Cached")
+            } else {
+                gettext("↳ Rebuilt")
+            },
+            &[&self.build_step_tag(cached)],
+        );
+        output.insert(&mut iter, "\n");
+
+        if cached {
+            imp.cached_steps.set(imp.cached_steps.get() + 1);
+            self.notify_cached_steps();
+        } else {
+            imp.rebuilt_steps.set(imp.rebuilt_steps.get() + 1);
+            self.notify_rebuilt_steps();
+        }
+    }
+
     fn replace_last_line(&self, text: &str) {
         let output = self.output();
 
@@ -1101,3 +1230,85 @@ impl Action {
         output.insert(&mut start_iter, &format!("{text}\n"));
     }
 }
+
+/// Follows the streamed output of a build to find out which steps have been taken from the cache.
+///
+/// A step starts with a `STEP` line and ends with the first `-->` line that names the resulting
+/// layer. A preceding `--> Using cache` line marks it as cached. For the last step, that line
+/// follows the `COMMIT` line. Steps without a resulting layer (like `FROM`) aren't counted.
+#[derive(Debug, Default)]
+struct BuildSteps {
+    pending: String,
+    in_step: bool,
+    cached: bool,
+}
+
+impl BuildSteps {
+    /// Returns for each step finished by this chunk whether it has been cached.
+    fn feed(&mut self, chunk: &str) -> Vec<bool> {
+        self.pending.push_str(chunk);
+
+        let mut finished = Vec::new();
+        while let Some(pos) = self.pending.find('\n') {
+            let line = self.pending.drain(..=pos).collect::<String>();
+            let line = line.trim();
+
+            if line.starts_with("STEP ") || line.starts_with("Step ") {
+                self.in_step = true;
+                self.cached = false;
+            } else if self.in_step
+                && let Some(rest) = line
+                    .strip_prefix("--")
+                    .and_then(|rest| rest.trim_start_matches('-').strip_prefix('>'))
+            {
+                if rest.trim_start().starts_with("Using cache") {
+                    self.cached = true;
+                } else {
+                    finished.push(self.cached);
+                    self.in_step = false;
+                }
+            }
+        }
+
+        finished
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TRANSCRIPT: &str = "\
+STEP 1/4: FROM docker.io/library/alpine:latest
+STEP 2/4: RUN apk add --no-cache curl
+--> Using cache 6f0a5c2d8e1b4a3c9d7e6f5a4b3c2d1e0f9a8b7c6d5e4f3a2b1c0d9e8f7a6b5c
+--> 6f0a5c2d8e1b
+STEP 3/4: COPY app.sh /usr/local/bin/app.sh
+--> 3b1d9e7c4a2f
+STEP 4/4: CMD [\"/usr/local/bin/app.sh\"]
+COMMIT localhost/app:latest
+--> 9c8e7d6f5a4b
+Successfully tagged localhost/app:latest
+9c8e7d6f5a4b3c2d1e0f9a8b7c6d5e4f3a2b1c0d9e8f7a6b5c4d3e2f1a0b9c8d
+";
+
+    #[test]
+    fn build_steps_counts_the_committed_step() {
+        assert_eq!(
+            BuildSteps::default().feed(TRANSCRIPT),
+            vec![true, false, false]
+        );
+    }
+
+    #[test]
+    fn build_steps_handles_lines_split_across_chunks() {
+        let mut build_steps = BuildSteps::default();
+        let finished = TRANSCRIPT
+            .as_bytes()
+            .chunks(7)
+            .flat_map(|chunk| build_steps.feed(std::str::from_utf8(chunk).unwrap()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(finished, vec![true, false, false]);
+    }
+}
@@ -2,6 +2,8 @@ use std::cell::Cell;
 use std::cell::RefCell;
 use std::sync::OnceLock;
 
+use futures::FutureExt;
+use futures::future;
 use gio::prelude::*;
 use gio::subclass::prelude::*;
 use glib::Properties;
@@ -125,6 +127,13 @@ impl ActionList {
         self.count_state(model::ActionState::Failed)
     }
 
+    /// Whether an action of one of the given types is still running.
+    pub(crate) fn is_ongoing(&self, types: &[model::ActionType]) -> bool {
+        self.imp().list.borrow().values().any(|action| {
+            action.state() == model::ActionState::Ongoing && types.contains(&action.action_type())
+        })
+    }
+
     fn count_state(&self, state: model::ActionState) -> u32 {
         self.imp()
             .list
@@ -170,11 +179,15 @@ impl ActionList {
     }
 
     pub(crate) fn prune_images(&self, opts: podman::opts::ImagePruneOpts) -> model::Action {
-        self.insert_action(model::Action::prune_images(
+        let action = self.insert_action(model::Action::prune_images(
             self.imp().action_counter.get(),
             self.client().unwrap(),
-            opts,
-        ))
+            opts.clone(),
+        ));
+        self.set_retry(&action, move |list| {
+            future::ready(list.prune_images(opts.clone()))
+        });
+        action
     }
 
     pub(crate) fn download_image(
@@ -182,12 +195,35 @@ impl ActionList {
         image: &str,
         opts: podman::opts::PullOpts,
     ) -> model::Action {
-        self.insert_action(model::Action::download_image(
+        let action = self.insert_action(model::Action::download_image(
             self.imp().action_counter.get(),
             image,
             self.client().unwrap(),
-            opts,
-        ))
+            opts.clone(),
+        ));
+        let image = image.to_owned();
+        self.set_retry(&action, move |list| {
+            future::ready(list.download_image(&image, opts.clone()))
+        });
+        action
+    }
+
+    pub(crate) fn download_all_tags(
+        &self,
+        repository: &str,
+        opts: podman::opts::PullOpts,
+    ) -> model::Action {
+        let action = self.insert_action(model::Action::download_all_tags(
+            self.imp().action_counter.get(),
+            repository,
+            self.client().unwrap(),
+            opts.clone(),
+        ));
+        let repository = repository.to_owned();
+        self.set_retry(&action, move |list| {
+            future::ready(list.download_all_tags(&repository, opts.clone()))
+        });
+        action
     }
 
     pub(crate) fn push_image(
@@ -209,23 +245,34 @@ impl ActionList {
         image: &str,
         opts: podman::opts::ImageBuildOpts,
     ) -> model::Action {
-        self.insert_action(
+        let action = self.insert_action(
             model::Action::build_image(
                 self.imp().action_counter.get(),
                 image,
                 self.client().unwrap(),
-                opts,
+                opts.clone(),
             )
             .await,
-        )
+        );
+        let image = image.to_owned();
+        self.set_retry(&action, move |list| {
+            let image = image.clone();
+            let opts = opts.clone();
+            async move { list.build_image(&image, opts).await }
+        });
+        action
     }
 
     pub(crate) fn prune_containers(&self, opts: podman::opts::ContainerPruneOpts) -> model::Action {
-        self.insert_action(model::Action::prune_containers(
+        let action = self.insert_action(model::Action::prune_containers(
             self.imp().action_counter.get(),
             self.client().unwrap(),
-            opts,
-        ))
+            opts.clone(),
+        ));
+        self.set_retry(&action, move |list| {
+            future::ready(list.prune_containers(opts.clone()))
+        });
+        action
     }
 
     pub(crate) fn create_container(
@@ -307,10 +354,12 @@ impl ActionList {
     }
 
     pub(crate) fn prune_pods(&self) -> model::Action {
-        self.insert_action(model::Action::prune_pods(
+        let action = self.insert_action(model::Action::prune_pods(
             self.imp().action_counter.get(),
             self.client().unwrap(),
-        ))
+        ));
+        self.set_retry(&action, |list| future::ready(list.prune_pods()));
+        action
     }
 
     pub(crate) fn create_pod(&self, pod: &str, opts: podman::opts::PodCreateOpts) -> model::Action {
@@ -351,11 +400,15 @@ impl ActionList {
     }
 
     pub(crate) fn prune_volumes(&self, opts: podman::opts::VolumePruneOpts) -> model::Action {
-        self.insert_action(model::Action::prune_volumes(
+        let action = self.insert_action(model::Action::prune_volumes(
             self.imp().action_counter.get(),
             self.client().unwrap(),
-            opts,
-        ))
+            opts.clone(),
+        ));
+        self.set_retry(&action, move |list| {
+            future::ready(list.prune_volumes(opts.clone()))
+        });
+        action
     }
 
     fn insert_action(&self, action: model::Action) -> model::Action {
@@ -381,6 +434,16 @@ impl ActionList {
         action
     }
 
+    /// Lets the action start its operation again with a new entry in this list.
+    fn set_retry<F, Fut>(&self, action: &model::Action, retry: F)
+    where
+        F: Fn(Self) -> Fut + 'static,
+        Fut: Future<Output = model::Action> + 'static,
+    {
+        let list = self.downgrade();
+        action.set_retry(move || list.upgrade().map(|list| retry(list).boxed_local()));
+    }
+
     fn notify_num_states(&self) {
         self.notify("ongoing");
         self.notify("finished");
@@ -0,0 +1,84 @@
+use gettextrs::gettext;
+use gtk::glib;
+
+use crate::monad_boxed_type;
+use crate::podman;
+use crate::utils;
+
+/// The kinds of changes picked up by the event listener that the user can be notified about.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum BackgroundChangeCategory {
+    Lifecycle,
+    Images,
+    Errors,
+}
+
+impl BackgroundChangeCategory {
+    /// The settings key that enables notifications of this category.
+    pub(crate) fn settings_key(&self) -> &'static str {
+        match self {
+            Self::Lifecycle => "notify-lifecycle-changes",
+            Self::Images => "notify-image-changes",
+            Self::Errors => "notify-errors",
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(crate) struct BackgroundChange {
+    pub(crate) category: BackgroundChangeCategory,
+    pub(crate) title: String,
+    /// The changed object in the format of the saved session pages, e.g. `container:<id>`.
+    pub(crate) target: Option<String>,
+}
+
+monad_boxed_type!(pub(crate) BoxedBackgroundChange(BackgroundChange) impls Debug);
+
+impl BackgroundChange {
+    /// Summarizes an event, unless it's too noisy or not interesting on its own.
+    pub(crate) fn from_event(event: &podman::models::Event) -> Option<Self> {
+        use BackgroundChangeCategory::*;
+
+        let id = &event.actor.id;
+        let name = event
+            .actor
+            .attributes
+            .get("name")
+            .cloned()
+            .unwrap_or_else(|| utils::format_id(id));
+
+        // spellchecker:off
+        let (category, title, target) = match (event.typ.as_str(), event.action.as_str()) {
+            // spellchecker:on
+            ("container", "start") => (Lifecycle, gettext!("{} started", name), "container"),
+            ("container", "died") => match event
+                .actor
+                .attributes
+                .get("containerExitCode")
+                .filter(|code| code.as_str() != "0")
+            {
+                Some(code) => (
+                    Errors,
+                    gettext!("{} exited with code {}", name, code),
+                    "container",
+                ),
+                None => (Lifecycle, gettext!("{} exited", name), "container"),
+            },
+            ("container", "oom") => (Errors, gettext!("{} ran out of memory", name), "container"),
+            ("container", "create") => (Lifecycle, gettext!("{} created", name), "container"),
+            ("container", "remove") => (Lifecycle, gettext!("{} removed", name), ""),
+            ("pod", "start") => (Lifecycle, gettext!("Pod {} started", name), "pod"),
+            ("pod", "stop") => (Lifecycle, gettext!("Pod {} stopped", name), "pod"),
+            ("image", "pull") => (Images, gettext!("Image {} pulled", name), "image"),
+            ("image", "build") => (Images, gettext!("Image {} built", name), "image"),
+            ("image", "remove") => (Images, gettext!("Image {} removed", name), ""),
+            _ => return None,
+        };
+
+        Some(Self {
+            category,
+            title,
+            target: (!target.is_empty()).then(|| format!("{target}:{id}")),
+        })
+    }
+}
@@ -1,5 +1,9 @@
+use std::cell::Cell;
 use std::cell::OnceCell;
+use std::cell::RefCell;
+use std::collections::BTreeMap;
 use std::rc::Rc;
+use std::sync::OnceLock;
 use std::sync::atomic::AtomicUsize;
 use std::sync::atomic::Ordering;
 
@@ -7,6 +11,7 @@ use futures::StreamExt;
 use gio::prelude::*;
 use glib::Properties;
 use glib::clone;
+use glib::subclass::Signal;
 use glib::subclass::prelude::*;
 use gtk::gio;
 use gtk::glib;
@@ -16,9 +21,15 @@ use crate::model::AbstractContainerListExt;
 use crate::monad_boxed_type;
 use crate::podman;
 use crate::rt;
+use crate::utils;
 
 /// Sync interval in seconds
 const SYNC_INTERVAL: u32 = 15;
+/// Sync interval in seconds when the low resource mode is enabled
+const LOW_RESOURCE_SYNC_INTERVAL: u32 = 60;
+/// The difference in seconds between the remote and the local clock from which relative times are
+/// considered to be misleading. Events are delivered with a small delay, so this must be generous.
+pub(crate) const CLOCK_SKEW_THRESHOLD: i64 = 120;
 
 monad_boxed_type!(pub(crate) BoxedPodman(podman::Podman) impls Debug);
 
@@ -33,9 +44,16 @@ pub(crate) enum ClientError {
 mod imp {
     use super::*;
 
-    #[derive(Debug, Default, Properties)]
+    #[derive(Default, Properties)]
     #[properties(wrapper_type = super::Client)]
     pub(crate) struct Client {
+        pub(super) settings: utils::PodsSettings,
+        /// Starts the event listener again with the callbacks of the first start.
+        pub(super) event_listener_starter: RefCell<Option<Box<dyn Fn(&super::Client)>>>,
+        pub(super) event_listener: RefCell<Option<rt::PipeFinish>>,
+        pub(super) refresh_source: RefCell<Option<glib::SourceId>>,
+        pub(super) polling_source: RefCell<Option<glib::SourceId>>,
+        pub(super) registry_logins: RefCell<BTreeMap<String, model::RegistryCredentials>>,
         #[property(get, set, construct_only)]
         pub(super) connection: OnceCell<model::Connection>,
         #[property(get, set, construct_only)]
@@ -54,6 +72,14 @@ mod imp {
         pub(super) volume_list: OnceCell<model::VolumeList>,
         #[property(get = Self::action_list)]
         pub(super) action_list: OnceCell<model::ActionList>,
+        #[property(get, set)]
+        pub(super) events_unsupported: Cell<bool>,
+        /// The seconds the remote clock is ahead of the local one, as observed from the last event.
+        #[property(get)]
+        pub(super) clock_skew: Cell<i64>,
+        /// Whether the last check of the service failed, e.g. after waking up from sleep.
+        #[property(get)]
+        pub(super) unreachable: Cell<bool>,
     }
 
     #[glib::object_subclass]
@@ -63,6 +89,18 @@ mod imp {
     }
 
     impl ObjectImpl for Client {
+        fn signals() -> &'static [Signal] {
+            static SIGNALS: OnceLock<Vec<Signal>> = OnceLock::new();
+            SIGNALS.get_or_init(|| {
+                vec![
+                    Signal::builder("background-change")
+                        .param_types([model::BoxedBackgroundChange::static_type()])
+                        .build(),
+                    Signal::builder("registry-logins-changed").build(),
+                ]
+            })
+        }
+
         fn properties() -> &'static [glib::ParamSpec] {
             Self::derived_properties()
         }
@@ -241,6 +279,13 @@ mod imp {
                 }
             ));
         }
+
+        fn dispose(&self) {
+            if let Some(source_id) = self.refresh_source.take() {
+                source_id.remove();
+            }
+            self.obj().stop_polling();
+        }
     }
 
     impl Client {
@@ -315,6 +360,22 @@ impl TryFrom<&model::Connection> for Client {
                 }
             ));
 
+            rt::Promise::new({
+                let connection = obj.connection().uuid();
+                async move { model::load_registry_logins(&connection).await }
+            })
+            .defer(clone!(
+                #[weak]
+                obj,
+                move |result| match result {
+                    Ok(logins) => {
+                        obj.imp().registry_logins.replace(logins);
+                        obj.emit_by_name::<()>("registry-logins-changed", &[]);
+                    }
+                    Err(e) => log::error!("Error on loading registry logins: {e}"),
+                }
+            ));
+
             obj
         })
     }
@@ -331,6 +392,159 @@ impl Client {
         self.notify_cpus();
     }
 
+    /// Lets Podman verify the credentials with the registry and remembers them, so that all pulls
+    /// and pushes to it through this connection are authenticated. Podman doesn't keep a login
+    /// session for API clients, so the credentials are sent along with every request instead.
+    pub(crate) fn registry_login<F>(&self, registry: &str, username: &str, password: &str, op: F)
+    where
+        F: FnOnce(anyhow::Result<()>) + 'static,
+    {
+        let registry = registry.trim().to_owned();
+        let credentials = model::RegistryCredentials {
+            username: username.to_owned(),
+            password: password.to_owned(),
+        };
+
+        glib::spawn_future_local(clone!(
+            #[weak(rename_to = obj)]
+            self,
+            async move {
+                // Don't remember credentials the registry doesn't accept.
+                let result = match model::verify_registry_login(
+                    &obj.connection().url(),
+                    &registry,
+                    &credentials,
+                )
+                .await
+                {
+                    Ok(()) => {
+                        rt::Promise::new({
+                            let connection = obj.connection().uuid();
+                            let registry = registry.clone();
+                            let credentials = credentials.clone();
+                            async move {
+                                model::save_registry_login(&connection, &registry, &credentials)
+                                    .await
+                            }
+                        })
+                        .exec()
+                        .await
+                    }
+                    Err(e) => Err(e),
+                };
+
+                if result.is_ok() {
+                    obj.imp()
+                        .registry_logins
+                        .borrow_mut()
+                        .insert(registry, credentials);
+                    obj.emit_by_name::<()>("registry-logins-changed", &[]);
+                }
+                op(result);
+            }
+        ));
+    }
+
+    pub(crate) fn registry_logout<F>(&self, registry: &str, op: F)
+    where
+        F: FnOnce(anyhow::Result<()>) + 'static,
+    {
+        let registry = registry.to_owned();
+
+        rt::Promise::new({
+            let connection = self.connection().uuid();
+            let registry = registry.clone();
+            async move { model::delete_registry_login(&connection, &registry).await }
+        })
+        .defer(clone!(
+            #[weak(rename_to = obj)]
+            self,
+            move |result| {
+                if result.is_ok() {
+                    obj.imp().registry_logins.borrow_mut().remove(&registry);
+                    obj.emit_by_name::<()>("registry-logins-changed", &[]);
+                }
+                op(result);
+            }
+        ));
+    }
+
+    /// The registries the user is logged in to, sorted by name.
+    pub(crate) fn registry_logins(&self) -> Vec<(String, String)> {
+        self.imp()
+            .registry_logins
+            .borrow()
+            .iter()
+            .map(|(registry, credentials)| (registry.to_owned(), credentials.username.to_owned()))
+            .collect()
+    }
+
+    pub(crate) fn registry_auth(&self, reference: &str) -> Option<podman::opts::RegistryAuth> {
+        self.imp()
+            .registry_logins
+            .borrow()
+            .get(model::registry_of(reference))
+            .map(|credentials| {
+                podman::opts::RegistryAuth::builder()
+                    .username(&credentials.username)
+                    .password(&credentials.password)
+                    .build()
+            })
+    }
+
+    /// Prepares pulling the reference with the stored login of its registry, if there is one.
+    pub(crate) fn pull_opts(&self, reference: &str) -> podman::opts::PullOptsBuilder {
+        let opts = podman::opts::PullOpts::builder().reference(reference);
+        match self.registry_auth(reference) {
+            Some(auth) => opts.auth(auth),
+            None => opts,
+        }
+    }
+
+    pub(crate) fn connect_registry_logins_changed<F: Fn(&Self) + 'static>(
+        &self,
+        f: F,
+    ) -> glib::SignalHandlerId {
+        self.connect_local("registry-logins-changed", true, move |values| {
+            f(&values[0].get::<Self>().unwrap());
+
+            None
+        })
+    }
+
+    /// Connects to changes that have been picked up by the event listener.
+    pub(crate) fn connect_background_change<F: Fn(&Self, &model::BackgroundChange) + 'static>(
+        &self,
+        f: F,
+    ) -> glib::SignalHandlerId {
+        self.connect_local("background-change", true, move |values| {
+            f(
+                &values[0].get::<Self>().unwrap(),
+                &values[1].get::<model::BoxedBackgroundChange>().unwrap(),
+            );
+
+            None
+        })
+    }
+
+    /// Compares the time of an event that has just been emitted by the remote with the local clock.
+    fn update_clock_skew(&self, remote_timestamp: i64) {
+        let skew = remote_timestamp - glib::DateTime::now_utc().unwrap().to_unix();
+        if self.clock_skew() == skew {
+            return;
+        }
+        self.imp().clock_skew.set(skew);
+        self.notify_clock_skew();
+    }
+
+    fn set_unreachable(&self, value: bool) {
+        if self.unreachable() == value {
+            return;
+        }
+        self.imp().unreachable.set(value);
+        self.notify_unreachable();
+    }
+
     pub(crate) fn check_service<T, E, F>(&self, op: T, err_op: E, finish_op: F)
     where
         T: FnOnce() + 'static,
@@ -346,6 +560,8 @@ impl Client {
             self,
             move |result| match result {
                 Ok(_) => {
+                    obj.set_unreachable(false);
+
                     obj.image_list().refresh({
                         let err_op = err_op.clone();
                         |_| err_op(ClientError::Images)
@@ -364,23 +580,52 @@ impl Client {
                     });
 
                     op();
-                    obj.start_event_listener(err_op, finish_op);
-                    obj.start_refresh_interval();
+                    obj.imp()
+                        .event_listener_starter
+                        .replace(Some(Box::new(move |obj: &Self| {
+                            obj.start_event_listener(err_op.clone(), finish_op.clone())
+                        })));
+                    obj.restart_event_listener();
                 }
                 Err(e) => {
                     log::error!("Could not connect to Podman: {e}");
-                    // No need to show a toast. The start service page is enough.
+                    // No need to show a toast. The start service page or the banner is enough.
+                    obj.set_unreachable(true);
                 }
             }
         ));
     }
 
+    /// Tears down the event listener and the refresh interval and starts both again without
+    /// reconnecting. This helps if events silently stall while the connection is otherwise fine.
+    pub(crate) fn restart_event_listener(&self) {
+        let imp = self.imp();
+
+        if let Some(event_listener) = imp.event_listener.take() {
+            log::info!(
+                "Restarting event listener of connection '{}'",
+                self.connection().name()
+            );
+            event_listener.abort();
+        }
+        if let Some(source_id) = imp.refresh_source.take() {
+            source_id.remove();
+        }
+
+        if !self.events_unsupported()
+            && let Some(start) = &*imp.event_listener_starter.borrow()
+        {
+            start(self);
+        }
+        self.start_refresh_interval();
+    }
+
     fn start_event_listener<E, F>(&self, err_op: E, finish_op: F)
     where
         E: FnOnce(ClientError) + Clone + 'static,
         F: FnOnce(podman::Error) + Clone + 'static,
     {
-        rt::Pipe::new(self.podman(), |podman| {
+        let event_listener = rt::Pipe::new(self.podman(), |podman| {
             podman
                 .events(&podman::opts::EventsOpts::builder().build())
                 .boxed()
@@ -394,6 +639,15 @@ impl Client {
                 match result {
                     Ok(event) => {
                         log::debug!("Event: {event:?}");
+                        obj.update_clock_skew(event.time as i64);
+                        if !obj.is_own_change(&event)
+                            && let Some(change) = model::BackgroundChange::from_event(&event)
+                        {
+                            obj.emit_by_name::<()>(
+                                "background-change",
+                                &[&model::BoxedBackgroundChange::from(change)],
+                            );
+                        }
                         match event
                             // spellchecker:off
                             .typ
@@ -421,6 +675,12 @@ impl Client {
                         }
                         glib::ControlFlow::Continue
                     }
+                    Err(e) if is_unsupported(&e) => {
+                        log::warn!("Events are not supported, falling back to polling: {e}");
+                        obj.set_events_unsupported(true);
+                        obj.start_polling();
+                        glib::ControlFlow::Break
+                    }
                     Err(e) => {
                         log::error!("Stopping image event stream due to error: {e}");
                         finish_op.clone()(e);
@@ -429,30 +689,136 @@ impl Client {
                 }
             }
         ));
+        self.imp().event_listener.replace(Some(event_listener));
     }
 
     /// This is needed to keep track of images and containers that are managed by Buildah.
     /// See https://github.com/marhkb/pods/issues/306
+    ///
+    /// In low resource mode, the syncs are less frequent.
     fn start_refresh_interval(&self) {
-        glib::timeout_add_seconds_local(
-            SYNC_INTERVAL,
+        let interval = if self.imp().settings.boolean("low-resource-mode") {
+            LOW_RESOURCE_SYNC_INTERVAL
+        } else {
+            SYNC_INTERVAL
+        };
+
+        let source_id = glib::timeout_add_seconds_local_once(
+            interval,
             clone!(
                 #[weak(rename_to = obj)]
                 self,
-                #[upgrade_or]
-                glib::ControlFlow::Break,
                 move || {
+                    obj.imp().refresh_source.take();
+
                     log::debug!("Syncing images, containers and pods");
 
                     obj.image_list().refresh(|_| {});
                     obj.container_list().refresh(None, |_| {});
                     obj.pod_list().refresh(None, |_| {});
 
-                    log::debug!("Sleeping for {SYNC_INTERVAL} until next sync");
+                    obj.start_refresh_interval();
+                }
+            ),
+        );
+        self.imp().refresh_source.replace(Some(source_id));
+    }
 
-                    glib::ControlFlow::Continue
+    /// Whether the event is most likely the result of an action in Pods. The user doesn't need to
+    /// be notified about those.
+    fn is_own_change(&self, event: &podman::models::Event) -> bool {
+        use model::ActionType::*;
+
+        let id = event.actor.id.as_str();
+        let action_list = self.action_list();
+
+        // spellchecker:off
+        match event.typ.as_str() {
+            // spellchecker:on
+            "container" => {
+                event
+                    .actor
+                    .attributes
+                    .contains_key(model::HELPER_CONTAINER_LABEL)
+                    || self
+                        .container_list()
+                        .get_container(id)
+                        .is_some_and(|container| container.action_ongoing())
+                    || action_list.is_ongoing(&[
+                        CreateContainer,
+                        CreateAndRunContainer,
+                        Commit,
+                        PruneContainers,
+                        Pod,
+                        PrunePods,
+                    ])
+            }
+            "pod" => {
+                self.pod_list()
+                    .get_pod(id)
+                    .is_some_and(|pod| pod.action_ongoing())
+                    || action_list.is_ongoing(&[Pod, PrunePods])
+            }
+            "image" => {
+                self.image_list()
+                    .get_image(id)
+                    .is_some_and(|image| image.to_be_deleted())
+                    || action_list.is_ongoing(&[
+                        DownloadImage,
+                        BuildImage,
+                        PruneImages,
+                        CreateContainer,
+                        CreateAndRunContainer,
+                        Pod,
+                    ])
+            }
+            _ => false,
+        }
+    }
+
+    /// Stops polling a connection that is no longer shown.
+    pub(crate) fn stop_polling(&self) {
+        if let Some(source_id) = self.imp().polling_source.take() {
+            source_id.remove();
+        }
+    }
+
+    /// Replaces the event listener for endpoints that don't provide the events API. The interval
+    /// is read on every run, so changing it in the preferences takes effect immediately.
+    fn start_polling(&self) {
+        self.stop_polling();
+
+        let imp = self.imp();
+        let interval = imp.settings.uint("events-polling-interval").max(1);
+
+        let source_id = glib::timeout_add_seconds_local_once(
+            interval,
+            clone!(
+                #[weak(rename_to = obj)]
+                self,
+                move || {
+                    obj.imp().polling_source.take();
+
+                    log::debug!("Polling images, containers, pods and volumes");
+
+                    obj.image_list().refresh(|_| {});
+                    obj.container_list().refresh(None, |_| {});
+                    obj.pod_list().refresh(None, |_| {});
+                    obj.volume_list().refresh(|_| {});
+
+                    obj.start_polling();
                 }
             ),
         );
+        imp.polling_source.replace(Some(source_id));
     }
 }
+
+/// Whether the endpoint rejected the request because it doesn't know the route, which is the
+/// case for older or proxied Podman endpoints without the events API.
+fn is_unsupported(e: &podman::Error) -> bool {
+    matches!(
+        e,
+        podman::Error::Fault { code, .. } if matches!(code.as_u16(), 404 | 405 | 501)
+    )
+}
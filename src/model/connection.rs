@@ -1,7 +1,9 @@
 use std::cell::Cell;
 use std::cell::OnceCell;
+use std::path::Path;
 use std::sync::OnceLock;
 
+use gettextrs::gettext;
 use glib::Properties;
 use glib::prelude::*;
 use glib::subclass::prelude::*;
@@ -11,6 +13,7 @@ use serde::Deserialize;
 use serde::Serialize;
 
 use crate::model;
+use crate::podman;
 
 #[derive(Debug, Default, Serialize, Deserialize)]
 pub(crate) struct ConnectionInfo {
@@ -18,6 +21,8 @@ pub(crate) struct ConnectionInfo {
     pub(super) name: String,
     pub(super) url: String,
     pub(super) rgb: Option<(f32, f32, f32)>,
+    #[serde(default)]
+    pub(super) read_only: bool,
 }
 
 mod imp {
@@ -40,6 +45,12 @@ mod imp {
         pub(super) url: OnceCell<String>,
         #[property(get, set, construct_only, nullable)]
         pub(super) rgb: Cell<Option<gdk::RGBA>>,
+        /// Whether all actions that modify the state of the Podman host are disabled.
+        #[property(get, set)]
+        pub(super) read_only: Cell<bool>,
+        /// Whether the socket of a local connection exists. Always true for remote connections.
+        #[property(get)]
+        pub(super) socket_available: Cell<bool>,
     }
 
     #[glib::object_subclass]
@@ -78,11 +89,22 @@ mod imp {
         fn constructed(&self) {
             self.parent_constructed();
 
+            self.socket_available.set(true);
+            self.obj().check_socket();
+
             self.obj().connect_connecting_notify(|obj| {
                 if let Some(manager) = obj.manager() {
                     manager.notify("connecting");
                 }
             });
+
+            self.obj().connect_read_only_notify(|obj| {
+                if let Some(manager) = obj.manager() {
+                    glib::spawn_future_local(async move {
+                        _ = manager.sync_to_disk().await;
+                    });
+                }
+            });
         }
     }
 }
@@ -100,7 +122,45 @@ impl From<&Connection> for ConnectionInfo {
             rgb: connection
                 .rgb()
                 .map(|rgb| (rgb.red(), rgb.green(), rgb.blue())),
+            read_only: connection.read_only(),
+        }
+    }
+}
+
+impl ConnectionInfo {
+    /// Checks whether an imported connection info is usable at all.
+    pub(super) fn validate(&self) -> anyhow::Result<()> {
+        if self.uuid.is_empty() {
+            anyhow::bail!(gettext!("The connection '{}' has no UUID", self.name));
         }
+        if self.name.trim().is_empty() {
+            anyhow::bail!(gettext!(
+                "The connection with URL '{}' has no name",
+                self.url
+            ));
+        }
+        podman::Podman::new(&self.url).map_err(|e| {
+            anyhow::anyhow!(gettext!(
+                "The connection '{}' has an invalid URL: {}",
+                self.name,
+                e
+            ))
+        })?;
+
+        Ok(())
+    }
+
+    /// Removes a password from the URL, so that it has to be entered again after importing.
+    pub(super) fn without_password(mut self) -> Self {
+        if let Some((scheme, rest)) = self.url.split_once("://") {
+            let authority = &rest[..rest.find('/').unwrap_or(rest.len())];
+            if let Some(at) = authority.rfind('@')
+                && let Some(colon) = authority[..at].find(':')
+            {
+                self.url = format!("{scheme}://{}{}", &rest[..colon], &rest[at..]);
+            }
+        }
+        self
     }
 }
 
@@ -109,7 +169,7 @@ impl Connection {
         connection_info: &ConnectionInfo,
         manager: &model::ConnectionManager,
     ) -> Self {
-        Self::new(
+        let obj = Self::new(
             &connection_info.uuid,
             &connection_info.name,
             &connection_info.url,
@@ -117,7 +177,9 @@ impl Connection {
                 .rgb
                 .map(|(r, g, b)| gdk::RGBA::new(r, g, b, 1.0)),
             manager,
-        )
+        );
+        obj.imp().read_only.set(connection_info.read_only);
+        obj
     }
 
     pub(crate) fn new(
@@ -144,6 +206,20 @@ impl Connection {
         !self.is_local()
     }
 
+    /// Checks whether the socket of a local connection still exists. With systemd socket
+    /// activation it may come and go while Pods is running.
+    pub(crate) fn check_socket(&self) {
+        let available = self
+            .url()
+            .strip_prefix("unix://")
+            .is_none_or(|path| Path::new(path).exists());
+
+        if self.socket_available() != available {
+            self.imp().socket_available.set(available);
+            self.notify_socket_available();
+        }
+    }
+
     pub(crate) fn is_active(&self) -> bool {
         self.manager()
             .as_ref()
@@ -152,6 +228,11 @@ impl Connection {
             .unwrap_or(false)
     }
 
+    /// The error that is returned for actions that are refused in read-only mode.
+    pub(crate) fn read_only_error() -> podman::Error {
+        podman::Error::StringError(gettext("The connection is in read-only mode"))
+    }
+
     pub(crate) fn position(&self) -> u32 {
         self.manager()
             .map(|manager| manager.position_by_uuid(&self.uuid()))
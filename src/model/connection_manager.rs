@@ -1,6 +1,5 @@
 use std::cell::Cell;
 use std::cell::RefCell;
-use std::io::Read;
 use std::path::PathBuf;
 use std::sync::OnceLock;
 
@@ -14,13 +13,16 @@ use gtk::gdk;
 use gtk::gio;
 use gtk::glib;
 use indexmap::IndexMap;
-use tokio::io::AsyncWriteExt;
 
 use crate::model;
 use crate::podman;
 use crate::rt;
 use crate::utils;
-use crate::utils::config_dir;
+
+/// The interval in seconds in which the sockets of local connections are checked.
+const SOCKET_CHECK_INTERVAL: u32 = 5;
+
+const FILE_NAME: &str = "connections.json";
 
 mod imp {
     use super::*;
@@ -125,6 +127,24 @@ impl ConnectionManager {
             connections_len as u32,
         );
 
+        glib::timeout_add_seconds_local(
+            SOCKET_CHECK_INTERVAL,
+            clone!(
+                #[weak(rename_to = obj)]
+                self,
+                #[upgrade_or]
+                glib::ControlFlow::Break,
+                move || {
+                    obj.imp()
+                        .connections
+                        .borrow()
+                        .values()
+                        .for_each(model::Connection::check_socket);
+                    glib::ControlFlow::Continue
+                }
+            ),
+        );
+
         if self.n_items() > 0 {
             let last_used_connection = imp.settings.string("last-used-connection");
             self.set_client_from(last_used_connection.as_str(), op);
@@ -134,24 +154,7 @@ impl ConnectionManager {
     }
 
     fn load_from_disk(&self) -> anyhow::Result<IndexMap<String, model::ConnectionInfo>> {
-        if utils::config_dir().exists() {
-            let path = path();
-
-            if path.exists() {
-                let mut file = std::fs::OpenOptions::new().read(true).open(path)?;
-
-                let mut buf = vec![];
-                file.read_to_end(&mut buf)?;
-
-                serde_json::from_slice::<IndexMap<String, model::ConnectionInfo>>(&buf)
-                    .map_err(anyhow::Error::from)
-            } else {
-                Ok(IndexMap::default())
-            }
-        } else {
-            std::fs::create_dir_all(config_dir())?;
-            Ok(IndexMap::default())
-        }
+        utils::load_config_json(FILE_NAME)
     }
 
     pub(crate) async fn sync_to_disk(&self) -> anyhow::Result<()> {
@@ -163,25 +166,82 @@ impl ConnectionManager {
             .map(|(key, connection)| (key.to_owned(), model::ConnectionInfo::from(connection)))
             .collect::<IndexMap<_, _>>();
 
-        let buf = serde_json::to_vec_pretty(&value).unwrap();
+        utils::save_config_json(FILE_NAME, &value)
+            .await
+            .inspect_err(|e| log::error!("Failed to sync connections to disk: {e}"))
+    }
+
+    /// Writes all connections to the given file in the format of the connections file. Returns
+    /// the number of exported connections.
+    pub(crate) async fn export_connections(&self, path: PathBuf) -> anyhow::Result<usize> {
+        let value = self
+            .imp()
+            .connections
+            .borrow()
+            .iter()
+            .map(|(key, connection)| {
+                (
+                    key.to_owned(),
+                    model::ConnectionInfo::from(connection).without_password(),
+                )
+            })
+            .collect::<IndexMap<_, _>>();
+
+        let buf = serde_json::to_vec_pretty(&value)?;
 
-        rt::Promise::new(async move {
-            if !utils::config_dir().exists() {
-                tokio::fs::create_dir_all(&config_dir()).await?;
+        rt::Promise::new(async move { tokio::fs::write(path, buf).await })
+            .exec()
+            .await?;
+
+        Ok(value.len())
+    }
+
+    /// Merges the connections of a file that has been written by `export_connections`.
+    /// Connections that already exist are skipped and those with a taken name are renamed.
+    /// Nothing is merged if any of the connections is invalid. Returns the number of imported and
+    /// skipped connections.
+    pub(crate) async fn import_connections(&self, path: PathBuf) -> anyhow::Result<(usize, usize)> {
+        let buf = rt::Promise::new(async move { tokio::fs::read(path).await })
+            .exec()
+            .await?;
+
+        let infos = serde_json::from_slice::<IndexMap<String, model::ConnectionInfo>>(&buf)
+            .map_err(|e| anyhow::anyhow!(gettext!("The file contains no connections: {}", e)))?;
+        infos
+            .values()
+            .try_for_each(model::ConnectionInfo::validate)?;
+
+        let (position, imported, skipped) = {
+            let mut connections = self.imp().connections.borrow_mut();
+            let position = connections.len();
+
+            let mut skipped = 0;
+            for mut info in infos.into_values() {
+                if connections.contains_key(&info.uuid)
+                    || connections
+                        .values()
+                        .any(|c| c.name() == info.name && c.url() == info.url)
+                {
+                    skipped += 1;
+                    continue;
+                }
+
+                info.name = unique_name(&connections, info.name);
+                connections.insert(
+                    info.uuid.clone(),
+                    model::Connection::from_connection_info(&info, self),
+                );
             }
 
-            let mut file = tokio::fs::OpenOptions::new()
-                .write(true)
-                .create(true)
-                .truncate(true)
-                .open(path())
-                .await?;
+            (position, connections.len() - position, skipped)
+        };
 
-            file.write_all(&buf).await.map_err(anyhow::Error::from)
-        })
-        .exec()
-        .await
-        .inspect_err(|e| log::error!("Failed to sync connections to disk: {e}"))
+        if imported > 0 {
+            self.items_changed(position as u32, 0, imported as u32);
+            _ = self.sync_to_disk().await;
+        }
+
+        Ok((imported, skipped))
     }
 
     pub(crate) async fn try_connect(
@@ -266,6 +326,33 @@ impl ConnectionManager {
         _ = self.sync_to_disk().await;
     }
 
+    /// Moves a connection to the given position and persists the new order.
+    pub(crate) async fn move_connection(&self, uuid: &str, position: u32) {
+        let (from, to) = {
+            let mut connections = self.imp().connections.borrow_mut();
+
+            let Some(from) = connections.get_index_of(uuid) else {
+                return;
+            };
+            let to = (position as usize).min(connections.len() - 1);
+            if from == to {
+                return;
+            }
+
+            connections.move_index(from, to);
+
+            (from, to)
+        };
+
+        let n_items = (from.abs_diff(to) + 1) as u32;
+        self.items_changed(from.min(to) as u32, n_items, n_items);
+
+        // The position of the active connection might have changed.
+        self.notify_client();
+
+        _ = self.sync_to_disk().await;
+    }
+
     pub(crate) fn contains_local_connection(&self) -> bool {
         self.imp()
             .connections
@@ -342,6 +429,7 @@ impl ConnectionManager {
 
         if let Some(client) = self.client() {
             client.connection().set_active(false);
+            client.stop_polling();
         }
 
         let imp = self.imp();
@@ -365,6 +453,22 @@ impl ConnectionManager {
         self.set_client(None);
     }
 
+    /// Drops the current client and connects to the same connection again.
+    pub(crate) fn reconnect<F>(&self, op: F)
+    where
+        F: Fn(anyhow::Result<()>) + 'static,
+    {
+        let Some(client) = self.client() else {
+            return;
+        };
+
+        let connection = client.connection();
+        connection.check_socket();
+
+        self.unset_client();
+        self.set_client_from(&connection.uuid(), op);
+    }
+
     pub(crate) fn is_connecting(&self) -> bool {
         let imp = self.imp();
         imp.creating_new_connection.get()
@@ -375,6 +479,16 @@ impl ConnectionManager {
                 .any(model::Connection::connecting)
     }
 
+    /// Returns the connection that is currently being established, if any.
+    pub(crate) fn connecting_connection(&self) -> Option<model::Connection> {
+        self.imp()
+            .connections
+            .borrow()
+            .values()
+            .find(|connection| connection.connecting())
+            .cloned()
+    }
+
     fn set_creating_new_connection(&self, value: bool) {
         let imp = self.imp();
         if imp.creating_new_connection.get() == value {
@@ -413,6 +527,15 @@ impl ConnectionManager {
     }
 }
 
-fn path() -> PathBuf {
-    utils::config_dir().join("connections.json")
+/// Appends a number to the name until no other connection has it.
+fn unique_name(connections: &IndexMap<String, model::Connection>, name: String) -> String {
+    let is_taken = |name: &str| connections.values().any(|c| c.name() == name);
+    if !is_taken(&name) {
+        return name;
+    }
+
+    (2..)
+        .map(|n| format!("{name} ({n})"))
+        .find(|candidate| !is_taken(candidate))
+        .unwrap()
 }
@@ -9,11 +9,12 @@ use std::sync::OnceLock;
 
 use futures::Future;
 use gettextrs::gettext;
+use gio::prelude::*;
 use glib::Properties;
 use glib::clone;
-use glib::prelude::*;
 use glib::subclass::Signal;
 use glib::subclass::prelude::*;
+use gtk::gio;
 use gtk::glib;
 
 use crate::model;
@@ -21,6 +22,11 @@ use crate::monad_boxed_type;
 use crate::podman;
 use crate::rt;
 
+const COMPOSE_PROJECT_LABEL: &str = "com.docker.compose.project";
+/// The exit code of a process that has been terminated with `SIGKILL`, which the kernel sends when
+/// a container exceeds its memory limit.
+const SIGKILL_EXIT_CODE: i32 = 137;
+
 #[derive(Debug, Default, Copy, Clone, PartialEq, Eq, glib::Enum)]
 #[enum_type(name = "ContainerStatus")]
 pub(crate) enum Status {
@@ -169,8 +175,13 @@ mod imp {
         pub(super) container_list: glib::WeakRef<model::ContainerList>,
         #[property(get, set)]
         pub(super) action_ongoing: Cell<bool>,
+        #[property(get, set, construct, nullable)]
+        pub(super) compose_project: RefCell<Option<String>>,
         #[property(get, set, construct_only)]
         pub(super) created: OnceCell<i64>,
+        /// The unix timestamp of when the container last exited, or zero if it never has.
+        #[property(get, set, construct)]
+        pub(super) exited_at: Cell<i64>,
         #[property(get = Self::data, nullable)]
         pub(super) data: OnceCell<Option<model::ContainerData>>,
         #[property(get, set, construct, builder(HealthStatus::default()))]
@@ -187,6 +198,8 @@ mod imp {
         pub(super) is_infra: Cell<bool>,
         #[property(get, set, construct)]
         pub(super) name: RefCell<String>,
+        #[property(get)]
+        pub(super) oom_killed: Cell<bool>,
         #[property(get, set = Self::set_pod, explicit_notify, nullable)]
         pub(super) pod: glib::WeakRef<model::Pod>,
         #[property(get = Self::pod_id, set, construct_only, nullable)]
@@ -203,6 +216,8 @@ mod imp {
         pub(super) volume_list: OnceCell<model::ContainerVolumeList>,
         #[property(get)]
         pub(super) to_be_deleted: Cell<bool>,
+        #[property(get)]
+        pub(super) refreshing: Cell<bool>,
         #[property(get, set)]
         pub(super) selected: Cell<bool>,
     }
@@ -240,6 +255,12 @@ mod imp {
 
         pub(super) fn set_data(&self, data: &podman::models::InspectContainerData) {
             let obj = &*self.obj();
+            self.set_oom_killed(
+                data.state
+                    .as_ref()
+                    .and_then(|state| state.oom_killed)
+                    .unwrap_or(false),
+            );
             if let Some(old) = self.data() {
                 old.update(data);
                 return;
@@ -250,6 +271,15 @@ mod imp {
             obj.notify_data();
         }
 
+        pub(super) fn set_oom_killed(&self, value: bool) {
+            let obj = &*self.obj();
+            if obj.oom_killed() == value {
+                return;
+            }
+            self.oom_killed.set(value);
+            obj.notify_oom_killed();
+        }
+
         pub(super) fn set_pod(&self, value: Option<&model::Pod>) {
             let obj = &*self.obj();
             if obj.pod().as_ref() == value {
@@ -278,6 +308,13 @@ mod imp {
             if let Some(pod) = obj.pod() {
                 pod.inspect_and_update();
             }
+            if value == Status::Running {
+                self.set_oom_killed(false);
+            } else {
+                // The stats stream only delivers values for running containers. Drop the last
+                // sample so that e.g. paused containers don't display stale usage.
+                obj.set_stats(None::<BoxedContainerStats>);
+            }
             self.status.set(value);
             obj.notify_status();
         }
@@ -294,6 +331,15 @@ mod imp {
             self.to_be_deleted.set(value);
             obj.notify_to_be_deleted();
         }
+
+        pub(super) fn set_refreshing(&self, value: bool) {
+            let obj = &*self.obj();
+            if obj.refreshing() == value {
+                return;
+            }
+            self.refreshing.set(value);
+            obj.notify_refreshing();
+        }
     }
 }
 
@@ -306,12 +352,16 @@ impl Container {
         container_list: &model::ContainerList,
         list_container: podman::models::ListContainer,
     ) -> Self {
+        let exit_code = list_container.exit_code;
+
         let obj: Self = glib::Object::builder()
             .property("container-list", container_list)
+            .property("compose-project", compose_project(&list_container))
             .property(
                 "created",
                 list_container.created.map(|dt| dt.timestamp()).unwrap_or(0),
             )
+            .property("exited-at", list_container.exited_at.unwrap_or(0).max(0))
             .property(
                 "health-status",
                 health_status(list_container.status.as_deref()),
@@ -336,6 +386,12 @@ impl Container {
                 list_container.mounts.unwrap_or_default(),
             ))
             .unwrap();
+
+        // Only the inspection data tells whether the container has run out of memory.
+        if obj.status() == Status::Exited && exit_code == Some(SIGKILL_EXIT_CODE) {
+            obj.inspect(|_| {});
+        }
+
         obj
     }
 
@@ -345,6 +401,8 @@ impl Container {
 
     pub(crate) fn update(&self, list_container: podman::models::ListContainer) {
         self.set_action_ongoing(false);
+        self.set_compose_project(compose_project(&list_container));
+        self.set_exited_at(list_container.exited_at.unwrap_or(0).max(0));
         self.set_health_status(health_status(list_container.status.as_deref()));
         self.set_image_name(list_container.image);
         self.set_name(list_container.names.unwrap()[0].clone());
@@ -352,6 +410,40 @@ impl Container {
         self.set_up_since(list_container.started_at.unwrap());
     }
 
+    /// Refreshes only this container without listing all containers. The container is removed
+    /// from its list if it doesn't exist anymore.
+    pub(crate) fn refresh<F>(&self, op: F)
+    where
+        F: FnOnce(podman::Result<()>) + 'static,
+    {
+        let Some(container_list) = self
+            .container_list()
+            .filter(|container_list| container_list.client().is_some())
+        else {
+            return;
+        };
+
+        if self.refreshing() {
+            return;
+        }
+        self.imp().set_refreshing(true);
+
+        container_list.refresh_and_then(
+            Some(self.id()),
+            clone!(
+                #[weak(rename_to = obj)]
+                self,
+                move |result| {
+                    obj.imp().set_refreshing(false);
+                    if result.is_ok() && obj.data().is_some() {
+                        obj.inspect(|_| {});
+                    }
+                    op(result);
+                }
+            ),
+        );
+    }
+
     pub(crate) fn inspect<F>(&self, op: F)
     where
         F: Fn(Result<model::Container, &podman::Error>) + 'static,
@@ -416,6 +508,15 @@ impl Container {
             return;
         }
 
+        if self.is_read_only() {
+            log::warn!(
+                "Container <{}>: Refused {name} in read-only mode",
+                self.id()
+            );
+            res_op(Err(model::Connection::read_only_error()));
+            return;
+        }
+
         // This will be either set back to `false` in `Self::update` or in case of an error.
         self.set_action_ongoing(true);
 
@@ -467,6 +568,18 @@ impl Container {
         );
     }
 
+    /// Sends the signal, given either by name or number, to the main process of the container.
+    pub(crate) fn send_signal<F>(&self, signal: String, op: F)
+    where
+        F: FnOnce(podman::Result<()>) + 'static,
+    {
+        self.action(
+            "sending signal",
+            move |container| async move { container.send_signal(signal).await },
+            op,
+        );
+    }
+
     pub(crate) fn restart<F>(&self, force: bool, op: F)
     where
         F: FnOnce(podman::Result<()>) + 'static,
@@ -510,18 +623,70 @@ impl Container {
         );
     }
 
+    /// Renames the container and updates its name right away, so that its pod and all lists
+    /// show the new name before the next refresh.
     pub(crate) fn rename<F>(&self, new_name: String, op: F)
     where
         F: FnOnce(podman::Result<()>) + 'static,
     {
+        let obj = self.downgrade();
         self.action(
             "renaming",
-            |container| async move { container.rename(new_name).await },
-            op,
+            {
+                let new_name = new_name.clone();
+                move |container| async move { container.rename(new_name).await }
+            },
+            move |result| {
+                if result.is_ok()
+                    && let Some(obj) = obj.upgrade()
+                {
+                    obj.set_name(new_name);
+                }
+                op(result);
+            },
         );
     }
 
-    pub(crate) fn delete<F>(&self, force: bool, op: F)
+    /// Returns why the container can't be renamed to the given name. Podman names containers
+    /// per host and doesn't scope them by pod, but a collision with a container of the own pod
+    /// is pointed out explicitly.
+    pub(crate) fn rename_error(&self, new_name: &str) -> Option<String> {
+        if new_name.is_empty() {
+            return Some(gettext("The name must not be empty"));
+        }
+        if !is_valid_name(new_name) {
+            return Some(gettext(
+                "Only letters, digits, '_', '.' and '-' are allowed and the first character must be a letter or digit",
+            ));
+        }
+
+        let is_other_with_name =
+            |container: &Self| container != self && container.name() == new_name;
+
+        if let Some(pod) = self.pod()
+            && pod
+                .container_list()
+                .iter::<Self>()
+                .filter_map(Result::ok)
+                .any(|container| is_other_with_name(&container))
+        {
+            return Some(gettext!(
+                "Another container of pod '{}' already has this name",
+                pod.name()
+            ));
+        }
+
+        self.container_list()
+            .is_some_and(|container_list| {
+                container_list
+                    .iter::<Self>()
+                    .map(Result::unwrap)
+                    .any(|container| is_other_with_name(&container))
+            })
+            .then(|| gettext("Another container already has this name"))
+    }
+
+    pub(crate) fn delete<F>(&self, force: bool, volumes: bool, op: F)
     where
         F: FnOnce(podman::Result<()>) + 'static,
     {
@@ -535,6 +700,7 @@ impl Container {
                     .delete(
                         &podman::opts::ContainerDeleteOpts::builder()
                             .force(force)
+                            .volumes(volumes)
                             .build(),
                     )
                     .await
@@ -552,6 +718,40 @@ impl Container {
         );
     }
 
+    /// Generates a Quadlet `.container` unit from the current inspection data of this container
+    /// and the image it was created from.
+    pub(crate) fn to_quadlet<F>(&self, op: F)
+    where
+        F: FnOnce(anyhow::Result<String>) + 'static,
+    {
+        let Some(container) = self.api() else {
+            op(Err(anyhow::anyhow!(gettext("No connection to Podman"))));
+            return;
+        };
+        let image = self
+            .container_list()
+            .as_ref()
+            .and_then(model::ContainerList::client)
+            .map(|client| {
+                podman::api::Image::new(client.podman().deref().clone(), self.image_id())
+            });
+
+        rt::Promise::new(async move {
+            let container_data = serde_json::to_value(container.inspect().await?)?;
+            // Without the image, every value is treated as explicitly set on the container.
+            let image_data = match image {
+                Some(image) => serde_json::to_value(image.inspect().await?)?,
+                None => serde_json::Value::Null,
+            };
+
+            anyhow::Ok(model::container_quadlet::container_quadlet(
+                &container_data,
+                &image_data,
+            ))
+        })
+        .defer(op);
+    }
+
     pub(super) fn on_deleted(&self) {
         if let Some(pod) = self.pod() {
             pod.inspect_and_update();
@@ -570,11 +770,32 @@ impl Container {
         self.pod_id().filter(|id| !id.is_empty()).is_some()
     }
 
+    /// Whether the connection of this container is in read-only mode.
+    pub(crate) fn is_read_only(&self) -> bool {
+        self.container_list()
+            .and_then(|list| list.client())
+            .is_some_and(|client| client.connection().read_only())
+    }
+
     pub(crate) fn can_start(&self) -> bool {
+        !self.is_read_only()
+            && matches!(
+                self.status(),
+                Status::Configured
+                    | Status::Created
+                    | Status::Exited
+                    | Status::Initialized
+                    | Status::Stopped
+            )
+    }
+
+    /// Whether the container is neither running nor about to run or stop.
+    pub(crate) fn is_stopped(&self) -> bool {
         matches!(
             self.status(),
             Status::Configured
                 | Status::Created
+                | Status::Dead
                 | Status::Exited
                 | Status::Initialized
                 | Status::Stopped
@@ -582,27 +803,66 @@ impl Container {
     }
 
     pub(crate) fn can_stop(&self) -> bool {
-        matches!(self.status(), Status::Running)
+        !self.is_read_only() && matches!(self.status(), Status::Running)
     }
 
     pub(crate) fn can_kill(&self) -> bool {
-        !self.can_start()
+        !self.is_read_only() && !self.can_start()
     }
 
     pub(crate) fn can_restart(&self) -> bool {
-        matches!(self.status(), Status::Running)
+        !self.is_read_only() && matches!(self.status(), Status::Running)
     }
 
     pub(crate) fn can_pause(&self) -> bool {
-        matches!(self.status(), Status::Running)
+        !self.is_read_only() && matches!(self.status(), Status::Running)
     }
 
     pub(crate) fn can_resume(&self) -> bool {
-        matches!(self.status(), Status::Paused)
+        !self.is_read_only() && matches!(self.status(), Status::Paused)
     }
 
+    /// Running containers can be deleted, too, but need to be forced.
     pub(crate) fn can_delete(&self) -> bool {
-        !matches!(self.status(), Status::Running | Status::Paused)
+        !self.is_read_only() && !matches!(self.status(), Status::Removing)
+    }
+
+    /// Returns the published host ports of this container that another running container already
+    /// binds, together with that container. Containers of the same pod share their ports.
+    pub(crate) fn port_conflicts(&self) -> Vec<(model::PortMapping, model::Container)> {
+        let Some(container_list) = self.container_list() else {
+            return Vec::new();
+        };
+
+        let overlaps = |a: &str, b: &str| {
+            let any = |ip: &str| ip.is_empty() || ip == "0.0.0.0" || ip == "::";
+            any(a) || any(b) || a == b
+        };
+
+        self.ports()
+            .iter::<model::PortMapping>()
+            .map(Result::unwrap)
+            .filter(|port| port.host_port() > 0)
+            .filter_map(|port| {
+                container_list
+                    .iter::<model::Container>()
+                    .map(Result::unwrap)
+                    .filter(|other| other != self && other.status() == Status::Running)
+                    .filter(|other| !self.has_pod() || other.pod_id() != self.pod_id())
+                    .find(|other| {
+                        other
+                            .ports()
+                            .iter::<model::PortMapping>()
+                            .map(Result::unwrap)
+                            .any(|other_port| {
+                                other_port.host_port() == port.host_port()
+                                    && other_port.protocol() == port.protocol()
+                                    && overlaps(&other_port.ip_address(), &port.ip_address())
+                            })
+                    })
+                    .map(|other| (port, other))
+            })
+            .collect()
     }
 
     pub(crate) fn api(&self) -> Option<podman::api::Container> {
@@ -634,3 +894,19 @@ fn health_status(s: Option<&str>) -> HealthStatus {
     })
     .unwrap_or_default()
 }
+
+fn compose_project(list_container: &podman::models::ListContainer) -> Option<String> {
+    list_container
+        .labels
+        .as_ref()
+        .and_then(|labels| labels.get(COMPOSE_PROJECT_LABEL))
+        .filter(|project| !project.is_empty())
+        .cloned()
+}
+
+/// Podman only accepts names matching `[a-zA-Z0-9][a-zA-Z0-9_.-]*`.
+fn is_valid_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next().is_some_and(|c| c.is_ascii_alphanumeric())
+        && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-'))
+}
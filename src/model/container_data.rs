@@ -23,12 +23,28 @@ mod imp {
     pub(crate) struct ContainerData {
         pub(super) health_check_log_list: model::HealthCheckLogList,
         #[property(get, set, construct_only)]
+        pub(super) cgroup_parent: OnceCell<String>,
+        /// The environment variables in the form `KEY=VALUE`.
+        #[property(get, set, construct_only)]
+        pub(super) env: OnceCell<Vec<String>>,
+        #[property(get, set, construct_only)]
+        pub(super) extra_hosts: OnceCell<Vec<String>>,
+        #[property(get, set, construct_only)]
         pub(super) health_config: OnceCell<Option<BoxedSchema2HealthConfig>>,
         #[property(get, set, construct_only)]
         pub(super) health_failing_streak: Cell<u32>,
         #[property(get, set, construct_only)]
+        pub(super) ipc_mode: OnceCell<String>,
+        #[property(get, set, construct_only)]
         pub(super) mounts: OnceCell<BoxedInspectMounts>,
         #[property(get, set, construct_only)]
+        pub(super) network_mode: OnceCell<String>,
+        /// The names of the networks the container is connected to.
+        #[property(get, set, construct_only)]
+        pub(super) networks: OnceCell<Vec<String>>,
+        #[property(get, set, construct_only)]
+        pub(super) pid_mode: OnceCell<String>,
+        #[property(get, set, construct_only)]
         pub(super) port_bindings: OnceCell<Option<BoxedPortBindings>>,
         #[property(get, set, construct_only)]
         pub(super) size: OnceCell<i64>,
@@ -61,7 +77,28 @@ glib::wrapper! {
 
 impl From<&podman::models::InspectContainerData> for ContainerData {
     fn from(data: &podman::models::InspectContainerData) -> Self {
+        let host_config = data.host_config.as_ref();
+
         let obj: Self = glib::Object::builder()
+            .property(
+                "cgroup-parent",
+                host_config
+                    .and_then(|config| config.cgroup_parent.clone())
+                    .unwrap_or_default(),
+            )
+            .property(
+                "env",
+                data.config
+                    .as_ref()
+                    .and_then(|config| config.env.clone())
+                    .unwrap_or_default(),
+            )
+            .property(
+                "extra-hosts",
+                host_config
+                    .and_then(|config| config.extra_hosts.clone())
+                    .unwrap_or_default(),
+            )
             .property(
                 "health-config",
                 data.config
@@ -75,6 +112,12 @@ impl From<&podman::models::InspectContainerData> for ContainerData {
                 "health-failing-streak",
                 health_failing_streak(data.state.as_ref()),
             )
+            .property(
+                "ipc-mode",
+                host_config
+                    .and_then(|config| config.ipc_mode.clone())
+                    .unwrap_or_default(),
+            )
             .property(
                 "mounts",
                 BoxedInspectMounts::from(
@@ -92,9 +135,32 @@ impl From<&podman::models::InspectContainerData> for ContainerData {
                 ),
             )
             .property(
-                "port-bindings",
-                data.host_config
+                "network-mode",
+                host_config
+                    .and_then(|config| config.network_mode.clone())
+                    .unwrap_or_default(),
+            )
+            .property(
+                "networks",
+                data.network_settings
                     .as_ref()
+                    .and_then(|settings| settings.networks.as_ref())
+                    .map(|networks| {
+                        let mut names = networks.keys().cloned().collect::<Vec<_>>();
+                        names.sort();
+                        names
+                    })
+                    .unwrap_or_default(),
+            )
+            .property(
+                "pid-mode",
+                host_config
+                    .and_then(|config| config.pid_mode.clone())
+                    .unwrap_or_default(),
+            )
+            .property(
+                "port-bindings",
+                host_config
                     .and_then(|config| config.port_bindings.clone())
                     .map(BoxedPortBindings::from),
             )
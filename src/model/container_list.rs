@@ -1,10 +1,13 @@
 use std::cell::Cell;
 use std::cell::OnceCell;
 use std::cell::RefCell;
+use std::collections::HashMap;
 use std::sync::OnceLock;
 
 use anyhow::anyhow;
 use futures::StreamExt;
+use futures::future;
+use futures::stream;
 use gio::prelude::*;
 use gio::subclass::prelude::*;
 use glib::Properties;
@@ -17,8 +20,36 @@ use indexmap::map::IndexMap;
 use crate::model;
 use crate::model::AbstractContainerListExt;
 use crate::model::SelectableListExt;
+use crate::monad_boxed_type;
 use crate::podman;
 use crate::rt;
+use crate::utils;
+
+/// The summed up network and block IO throughput of all running containers in bytes per second.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) struct IoRates {
+    pub(crate) net_input: u64,
+    pub(crate) net_output: u64,
+    pub(crate) block_input: u64,
+    pub(crate) block_output: u64,
+}
+
+monad_boxed_type!(pub(crate) BoxedIoRates(IoRates) impls Debug, PartialEq is nullable);
+
+/// The cumulative IO counters of a single container at the time of the last stats sample.
+#[derive(Clone, Copy, Debug, Default)]
+struct IoCounters([u64; 4]);
+
+impl From<&podman::models::ContainerStats> for IoCounters {
+    fn from(stats: &podman::models::ContainerStats) -> Self {
+        Self([
+            stats.net_input.unwrap_or(0),
+            stats.net_output.unwrap_or(0),
+            stats.block_input.unwrap_or(0),
+            stats.block_output.unwrap_or(0),
+        ])
+    }
+}
 
 mod imp {
     use super::*;
@@ -35,6 +66,15 @@ mod imp {
         pub(super) initialized: OnceCell<()>,
         #[property(get, set)]
         pub(super) selection_mode: Cell<bool>,
+        pub(super) io_counters: RefCell<HashMap<String, IoCounters>>,
+        pub(super) io_sampled_at: Cell<Option<i64>>,
+        #[property(get, nullable)]
+        pub(super) io_rates: RefCell<Option<BoxedIoRates>>,
+        pub(super) settings: utils::PodsSettings,
+        pub(super) stats_abort_handle: RefCell<Option<future::AbortHandle>>,
+        /// Whether live stats are received, which is not the case in low resource mode.
+        #[property(get)]
+        pub(super) stats_enabled: Cell<bool>,
     }
 
     #[glib::object_subclass]
@@ -109,52 +149,15 @@ mod imp {
             model::AbstractContainerList::bootstrap(obj.upcast_ref());
             model::SelectableList::bootstrap(obj.upcast_ref());
 
-            rt::Pipe::new(obj.client().unwrap().podman().containers(), |containers| {
-                containers
-                    .stats_stream(
-                        &podman::opts::ContainerStatsOptsBuilder::default()
-                            .interval(1)
-                            .build(),
-                    )
-                    .boxed()
-            })
-            .on_next(clone!(
-                #[weak]
-                obj,
-                #[upgrade_or]
-                glib::ControlFlow::Break,
-                move |result: podman::Result<podman::models::ContainerStats200Response>| {
-                    match result
-                        .map_err(anyhow::Error::from)
-                        .and_then(|mut value| {
-                            value
-                                .as_object_mut()
-                                .and_then(|object| object.remove("Stats"))
-                                .ok_or_else(|| anyhow!("Field 'Stats' is not present"))
-                        })
-                        .and_then(|value| {
-                            serde_json::from_value::<Vec<podman::models::ContainerStats>>(value)
-                                .map_err(anyhow::Error::from)
-                        }) {
-                        Ok(stats) => {
-                            stats.into_iter().for_each(|stat| {
-                                if let Some(container) =
-                                    obj.get_container(stat.container_id.as_ref().unwrap())
-                                    && container.status() == model::ContainerStatus::Running
-                                {
-                                    container
-                                        .set_stats(Some(model::BoxedContainerStats::from(stat)));
-                                }
-                            });
-                        }
-                        Err(e) => {
-                            log::warn!("Error occurred on receiving stats stream element: {e}")
-                        }
-                    }
-
-                    glib::ControlFlow::Continue
-                }
-            ));
+            obj.update_stats_stream();
+            self.settings.connect_changed(
+                Some("low-resource-mode"),
+                clone!(
+                    #[weak]
+                    obj,
+                    move |_, _| obj.update_stats_stream()
+                ),
+            );
         }
     }
 
@@ -266,6 +269,135 @@ impl ContainerList {
             .count() as u32
     }
 
+    /// Subscribes to the stats stream unless the low resource mode is enabled, in which case a
+    /// running subscription is stopped and the last stats are dropped.
+    fn update_stats_stream(&self) {
+        let imp = self.imp();
+
+        let enabled = !imp.settings.boolean("low-resource-mode");
+        if enabled == self.stats_enabled() {
+            return;
+        }
+        imp.stats_enabled.set(enabled);
+        self.notify_stats_enabled();
+
+        if !enabled {
+            if let Some(abort_handle) = imp.stats_abort_handle.take() {
+                abort_handle.abort();
+            }
+
+            imp.io_counters.borrow_mut().clear();
+            imp.io_sampled_at.set(None);
+            imp.io_rates.replace(None);
+            self.notify_io_rates();
+
+            self.iter::<model::Container>()
+                .map(Result::unwrap)
+                .for_each(|container| container.set_stats(None::<model::BoxedContainerStats>));
+
+            return;
+        }
+
+        let (abort_handle, abort_registration) = future::AbortHandle::new_pair();
+        imp.stats_abort_handle.replace(Some(abort_handle));
+
+        rt::Pipe::new(self.client().unwrap().podman().containers(), |containers| {
+            stream::Abortable::new(
+                containers.stats_stream(
+                    &podman::opts::ContainerStatsOptsBuilder::default()
+                        .interval(1)
+                        .build(),
+                ),
+                abort_registration,
+            )
+            .boxed()
+        })
+        .on_next(clone!(
+            #[weak(rename_to = obj)]
+            self,
+            #[upgrade_or]
+            glib::ControlFlow::Break,
+            move |result: podman::Result<podman::models::ContainerStats200Response>| {
+                match result
+                    .map_err(anyhow::Error::from)
+                    .and_then(|mut value| {
+                        value
+                            .as_object_mut()
+                            .and_then(|object| object.remove("Stats"))
+                            .ok_or_else(|| anyhow!("Field 'Stats' is not present"))
+                    })
+                    .and_then(|value| {
+                        serde_json::from_value::<Vec<podman::models::ContainerStats>>(value)
+                            .map_err(anyhow::Error::from)
+                    }) {
+                    Ok(stats) => {
+                        obj.update_io_rates(&stats);
+                        stats.into_iter().for_each(|stat| {
+                            if let Some(container) =
+                                obj.get_container(stat.container_id.as_ref().unwrap())
+                                && container.status() == model::ContainerStatus::Running
+                            {
+                                container.set_stats(Some(model::BoxedContainerStats::from(stat)));
+                            }
+                        });
+                    }
+                    Err(e) => {
+                        log::warn!("Error occurred on receiving stats stream element: {e}")
+                    }
+                }
+
+                glib::ControlFlow::Continue
+            }
+        ));
+    }
+
+    /// Containers only contribute to the rates once they have two samples. This way, containers
+    /// that appear or disappear between two samples don't cause spikes.
+    fn update_io_rates(&self, stats: &[podman::models::ContainerStats]) {
+        let imp = self.imp();
+
+        let now = glib::monotonic_time();
+        let elapsed = imp
+            .io_sampled_at
+            .replace(Some(now))
+            .map(|then| (now - then) as f64 / 1_000_000.0)
+            .filter(|elapsed| *elapsed > 0.0);
+
+        let counters = stats
+            .iter()
+            .filter_map(|stat| {
+                stat.container_id
+                    .clone()
+                    .map(|id| (id, IoCounters::from(stat)))
+            })
+            .collect::<HashMap<_, _>>();
+        let prev_counters = imp.io_counters.replace(counters);
+
+        let Some(elapsed) = elapsed else {
+            return;
+        };
+
+        let mut totals = [0_u64; 4];
+        imp.io_counters.borrow().iter().for_each(|(id, counters)| {
+            if let Some(prev) = prev_counters.get(id) {
+                totals
+                    .iter_mut()
+                    .zip(counters.0.iter().zip(prev.0.iter()))
+                    // Counters are reset when a container restarts.
+                    .for_each(|(total, (current, prev))| *total += current.saturating_sub(*prev));
+            }
+        });
+
+        let rate = |total: u64| (total as f64 / elapsed) as u64;
+        imp.io_rates.replace(Some(BoxedIoRates::from(IoRates {
+            net_input: rate(totals[0]),
+            net_output: rate(totals[1]),
+            block_input: rate(totals[2]),
+            block_output: rate(totals[3]),
+        })));
+        self.notify_io_rates();
+    }
+
     pub(crate) fn get_container(&self, id: &str) -> Option<model::Container> {
         self.imp().list.borrow().get(id).cloned()
     }
@@ -284,6 +416,19 @@ impl ContainerList {
     pub(crate) fn refresh<F>(&self, id: Option<String>, err_op: F)
     where
         F: FnOnce(super::RefreshError) + Clone + 'static,
+    {
+        self.refresh_and_then(id, move |result| {
+            if result.is_err() {
+                err_op(super::RefreshError);
+            }
+        });
+    }
+
+    /// Lists all containers or only the one with the given id and calls `op` when done. With an id,
+    /// the container is removed if it doesn't exist anymore.
+    pub(crate) fn refresh_and_then<F>(&self, id: Option<String>, op: F)
+    where
+        F: FnOnce(podman::Result<()>) + 'static,
     {
         self.imp().set_listing(true);
         rt::Promise::new({
@@ -308,32 +453,37 @@ impl ContainerList {
             #[weak(rename_to = obj)]
             self,
             move |result| {
-                match result {
+                let result = match result {
                     Ok(list_containers) => {
-                        if id.is_none() {
-                            let to_remove = obj
-                                .imp()
-                                .list
-                                .borrow()
-                                .keys()
-                                .filter(|id| {
-                                    !list_containers.iter().any(|list_container| {
-                                        list_container.id.as_ref() == Some(id)
-                                    })
-                                })
-                                .cloned()
-                                .collect::<Vec<_>>();
-                            to_remove.iter().for_each(|id| {
-                                obj.remove_container(id);
-                            });
-                        }
+                        let to_remove = obj
+                            .imp()
+                            .list
+                            .borrow()
+                            .keys()
+                            .filter(|key| id.as_ref().is_none_or(|id| id == *key))
+                            .filter(|key| {
+                                !list_containers
+                                    .iter()
+                                    .any(|list_container| list_container.id.as_ref() == Some(key))
+                            })
+                            .cloned()
+                            .collect::<Vec<_>>();
+                        to_remove.iter().for_each(|id| {
+                            obj.remove_container(id);
+                        });
 
+                        let mut skipped = 0;
                         list_containers.into_iter().for_each(|list_container| {
+                            let Some(id) = list_container.id.clone() else {
+                                skipped += 1;
+                                return;
+                            };
+
                             let index = obj.len();
 
                             let mut list = obj.imp().list.borrow_mut();
 
-                            match list.entry(list_container.id.as_ref().unwrap().to_owned()) {
+                            match list.entry(id) {
                                 Entry::Vacant(e) => {
                                     let container = model::Container::new(&obj, list_container);
                                     e.insert(container.clone());
@@ -350,15 +500,26 @@ impl ContainerList {
                                 }
                             }
                         });
+
+                        if skipped > 0 {
+                            log::warn!(
+                                "Skipped {} malformed container entries without an id",
+                                skipped
+                            );
+                        }
+
+                        Ok(())
                     }
                     Err(e) => {
                         log::error!("Error on retrieving containers: {}", e);
-                        err_op(super::RefreshError);
+                        Err(e)
                     }
-                }
+                };
                 let imp = obj.imp();
                 imp.set_listing(false);
                 imp.set_as_initialized();
+
+                op(result);
             }
         ));
     }
@@ -376,6 +537,16 @@ impl ContainerList {
                     container.inspect(|_| {});
                 }
             }
+            "died" => {
+                if let Some(container) = self.get_container(&container_id) {
+                    // Find out whether the container has been killed by the OOM killer.
+                    container.inspect(|_| {});
+                }
+                self.refresh(
+                    self.get_container(&container_id).map(|_| container_id),
+                    err_op,
+                );
+            }
             _ => self.refresh(
                 self.get_container(&container_id).map(|_| container_id),
                 err_op,
@@ -0,0 +1,225 @@
+use std::fmt::Write;
+
+use serde_json::Value;
+
+/// Generates a Quadlet `.container` unit from the inspection data of a container.
+///
+/// Values that equal the defaults of the image the container was created from are omitted to
+/// keep the unit readable.
+pub(super) fn container_quadlet(container_data: &Value, image_data: &Value) -> String {
+    let container_config = &container_data["Config"];
+    let image_config = &image_data["Config"];
+    let host_config = &container_data["HostConfig"];
+
+    let name = container_data["Name"].as_str().unwrap_or_default();
+
+    let mut unit = String::new();
+
+    writeln!(unit, "[Unit]").unwrap();
+    writeln!(unit, "Description={name} container").unwrap();
+    writeln!(unit).unwrap();
+
+    writeln!(unit, "[Container]").unwrap();
+    writeln!(unit, "ContainerName={name}").unwrap();
+    if let Some(image) = container_data["ImageName"]
+        .as_str()
+        .or_else(|| container_config["Image"].as_str())
+        .filter(|image| !image.is_empty())
+    {
+        writeln!(unit, "Image={image}").unwrap();
+    }
+
+    let entrypoint = args(&container_config["Entrypoint"]);
+    if entrypoint != args(&image_config["Entrypoint"]) && !entrypoint.is_empty() {
+        writeln!(unit, "Entrypoint={}", quote_args(&entrypoint)).unwrap();
+    }
+    let cmd = args(&container_config["Cmd"]);
+    if cmd != args(&image_config["Cmd"]) && !cmd.is_empty() {
+        writeln!(unit, "Exec={}", quote_args(&cmd)).unwrap();
+    }
+
+    if let Some(user) = non_default_str(&container_config["User"], &image_config["User"]) {
+        writeln!(unit, "User={user}").unwrap();
+    }
+    if let Some(working_dir) =
+        non_default_str(&container_config["WorkingDir"], &image_config["WorkingDir"])
+    {
+        writeln!(unit, "WorkingDir={working_dir}").unwrap();
+    }
+
+    if let Some(bindings) = host_config["PortBindings"].as_object() {
+        bindings.iter().for_each(|(port, hosts)| {
+            let port = port.strip_suffix("/tcp").unwrap_or(port);
+            hosts.as_array().into_iter().flatten().for_each(|host| {
+                // Without a host port, Podman chooses a random one.
+                match host["HostPort"].as_str().filter(|port| !port.is_empty()) {
+                    Some(host_port) => match host["HostIp"]
+                        .as_str()
+                        .filter(|ip| !ip.is_empty() && *ip != "0.0.0.0")
+                    {
+                        Some(host_ip) => {
+                            writeln!(unit, "PublishPort={host_ip}:{host_port}:{port}").unwrap()
+                        }
+                        None => writeln!(unit, "PublishPort={host_port}:{port}").unwrap(),
+                    },
+                    None => writeln!(unit, "PublishPort={port}").unwrap(),
+                }
+            });
+        });
+    }
+
+    container_data["Mounts"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .for_each(|mount| {
+            let source = match mount["Type"].as_str() {
+                Some("volume") => mount["Name"].as_str(),
+                Some("bind") => mount["Source"].as_str(),
+                _ => None,
+            };
+            if let Some(source) = source
+                && let Some(destination) = mount["Destination"].as_str()
+            {
+                let read_only = if mount["RW"].as_bool().unwrap_or(true) {
+                    ""
+                } else {
+                    ":ro"
+                };
+                writeln!(unit, "Volume={source}:{destination}{read_only}").unwrap();
+            }
+        });
+
+    let image_env = strings(&image_config["Env"]);
+    strings(&container_config["Env"])
+        .into_iter()
+        .filter(|var| !image_env.contains(var))
+        // Podman sets these itself for every container.
+        .filter(|var| !var.starts_with("HOSTNAME=") && !var.starts_with("container="))
+        .for_each(|var| writeln!(unit, "Environment={}", quote(&var)).unwrap());
+
+    if let Some(labels) = container_config["Labels"].as_object() {
+        labels
+            .iter()
+            .filter(|(key, value)| image_config["Labels"][key.as_str()] != **value)
+            .filter_map(|(key, value)| value.as_str().map(|value| (key, value)))
+            .for_each(|(key, value)| {
+                writeln!(unit, "Label={}", quote(&format!("{key}={value}"))).unwrap()
+            });
+    }
+
+    let health_check = &container_config["Healthcheck"];
+    if health_check != &image_config["Healthcheck"] {
+        let test = strings(&health_check["Test"]);
+        let command = match test.split_first() {
+            Some((kind, args)) if kind == "CMD-SHELL" => Some(args.join(" ")),
+            Some((kind, args)) if kind == "CMD" => Some(quote_args(args)),
+            _ => None,
+        };
+        if let Some(command) = command.filter(|command| !command.is_empty()) {
+            writeln!(unit, "HealthCmd={command}").unwrap();
+            [
+                ("HealthInterval", "Interval"),
+                ("HealthTimeout", "Timeout"),
+                ("HealthStartPeriod", "StartPeriod"),
+            ]
+            .into_iter()
+            .for_each(|(key, field)| {
+                if let Some(nanos) = health_check[field].as_u64().filter(|nanos| *nanos > 0) {
+                    writeln!(unit, "{key}={}s", nanos / 1_000_000_000).unwrap();
+                }
+            });
+            if let Some(retries) = health_check["Retries"].as_u64().filter(|r| *r > 0) {
+                writeln!(unit, "HealthRetries={retries}").unwrap();
+            }
+        }
+    }
+
+    match host_config["NetworkMode"].as_str() {
+        Some(mode @ ("host" | "none")) => writeln!(unit, "Network={mode}").unwrap(),
+        // Custom networks the container is connected to besides Podman's default one.
+        _ => container_data["NetworkSettings"]["Networks"]
+            .as_object()
+            .into_iter()
+            .flat_map(|networks| networks.keys())
+            .filter(|network| *network != "podman")
+            .for_each(|network| writeln!(unit, "Network={network}").unwrap()),
+    }
+
+    let mut podman_args = Vec::new();
+    if host_config["Privileged"].as_bool().unwrap_or(false) {
+        podman_args.push("--privileged".to_owned());
+    }
+    if let Some(memory) = host_config["Memory"].as_u64().filter(|memory| *memory > 0) {
+        podman_args.push(format!("--memory={memory}"));
+    }
+    if container_config["Tty"].as_bool().unwrap_or(false) {
+        podman_args.push("--tty".to_owned());
+    }
+    if !podman_args.is_empty() {
+        writeln!(unit, "PodmanArgs={}", podman_args.join(" ")).unwrap();
+    }
+
+    let restart = match host_config["RestartPolicy"]["Name"].as_str() {
+        Some("always" | "unless-stopped") => Some("always"),
+        Some("on-failure") => Some("on-failure"),
+        _ => None,
+    };
+    if let Some(restart) = restart {
+        writeln!(unit).unwrap();
+        writeln!(unit, "[Service]").unwrap();
+        writeln!(unit, "Restart={restart}").unwrap();
+    }
+
+    writeln!(unit).unwrap();
+    writeln!(unit, "[Install]").unwrap();
+    writeln!(unit, "WantedBy=default.target").unwrap();
+
+    unit
+}
+
+fn strings(value: &Value) -> Vec<String> {
+    value
+        .as_array()
+        .map(|values| {
+            values
+                .iter()
+                .filter_map(Value::as_str)
+                .map(str::to_owned)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn args(value: &Value) -> Vec<String> {
+    match value {
+        Value::String(arg) if !arg.is_empty() => vec![arg.to_owned()],
+        _ => strings(value),
+    }
+}
+
+fn non_default_str<'a>(container: &'a Value, image: &Value) -> Option<&'a str> {
+    container
+        .as_str()
+        .filter(|value| !value.is_empty() && Some(*value) != image.as_str())
+}
+
+fn quote_args(args: &[String]) -> String {
+    args.iter()
+        .map(|arg| quote(arg))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Quotes a value the way systemd splits words if it contains whitespace or quotes.
+fn quote(value: &str) -> String {
+    if value.is_empty()
+        || value
+            .chars()
+            .any(|c| c.is_whitespace() || matches!(c, '"' | '\'' | '\\'))
+    {
+        format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+    } else {
+        value.to_owned()
+    }
+}
@@ -0,0 +1,77 @@
+use indexmap::IndexMap;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::utils;
+
+const FILE_NAME: &str = "container-templates.json";
+
+/// A saved configuration of the container creation form.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub(crate) struct ContainerTemplate {
+    pub(crate) image: Option<String>,
+    pub(crate) pull_latest_image: bool,
+    pub(crate) command: String,
+    pub(crate) command_args: Vec<String>,
+    pub(crate) terminal: bool,
+    pub(crate) privileged: bool,
+    /// The name of the network mode, e.g. `host`. Unknown names fall back to the default mode.
+    pub(crate) network_mode: String,
+    /// The name of the container whose network is shared.
+    pub(crate) network_container: Option<String>,
+    /// The name of the network to connect to.
+    pub(crate) network_name: Option<String>,
+    pub(crate) memory_limit: Option<MemoryLimit>,
+    pub(crate) port_mappings: Vec<PortMappingTemplate>,
+    pub(crate) mounts: Vec<MountTemplate>,
+    pub(crate) env_vars: Vec<(String, String)>,
+    pub(crate) labels: Vec<(String, String)>,
+    pub(crate) hosts: Vec<(String, String)>,
+    pub(crate) health_check: Option<HealthCheckTemplate>,
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub(crate) struct MemoryLimit {
+    pub(crate) value: f64,
+    /// The index of the unit within the unit drop down.
+    pub(crate) unit: u32,
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub(crate) struct PortMappingTemplate {
+    pub(crate) host_port: i32,
+    pub(crate) container_port: i32,
+    pub(crate) protocol: String,
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub(crate) struct MountTemplate {
+    pub(crate) volume: bool,
+    /// Either the host path or the name of the volume.
+    pub(crate) source: String,
+    pub(crate) destination: String,
+    pub(crate) writable: bool,
+    pub(crate) selinux: String,
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub(crate) struct HealthCheckTemplate {
+    pub(crate) command: String,
+    pub(crate) interval: f64,
+    pub(crate) timeout: f64,
+    pub(crate) start_period: f64,
+    pub(crate) retries: f64,
+}
+
+pub(crate) fn load_container_templates() -> anyhow::Result<IndexMap<String, ContainerTemplate>> {
+    utils::load_config_json(FILE_NAME)
+}
+
+pub(crate) async fn save_container_templates(
+    templates: &IndexMap<String, ContainerTemplate>,
+) -> anyhow::Result<()> {
+    utils::save_config_json(FILE_NAME, templates)
+        .await
+        .inspect_err(|e| log::error!("Failed to sync container templates to disk: {e}"))
+}
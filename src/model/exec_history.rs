@@ -0,0 +1,30 @@
+use indexmap::IndexMap;
+
+use crate::utils;
+
+/// The maximum number of commands that are remembered per image.
+const MAX_COMMANDS_PER_IMAGE: usize = 10;
+
+const FILE_NAME: &str = "exec-history.json";
+
+/// The recently used exec commands, keyed by the image of the container they were run in. The
+/// most recent command comes first.
+pub(crate) type ExecHistory = IndexMap<String, Vec<String>>;
+
+pub(crate) fn load_exec_history() -> anyhow::Result<ExecHistory> {
+    utils::load_config_json(FILE_NAME)
+}
+
+pub(crate) async fn save_exec_history(history: &ExecHistory) -> anyhow::Result<()> {
+    utils::save_config_json(FILE_NAME, history)
+        .await
+        .inspect_err(|e| log::error!("Failed to sync exec history to disk: {e}"))
+}
+
+/// Moves the command to the front of the history of the image and forgets the oldest commands.
+pub(crate) fn add_to_exec_history(history: &mut ExecHistory, image: &str, command: &str) {
+    let commands = history.entry(image.to_owned()).or_default();
+    commands.retain(|c| c != command);
+    commands.insert(0, command.to_owned());
+    commands.truncate(MAX_COMMANDS_PER_IMAGE);
+}
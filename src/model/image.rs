@@ -25,6 +25,9 @@ mod imp {
         pub(super) inspection_callbacks: RefCell<
             Option<rt::Callbacks<podman::Result<podman::models::InspectImageResponseLibpod>>>,
         >,
+        pub(super) history_callbacks:
+            RefCell<Option<rt::Callbacks<podman::Result<Vec<podman::models::HistoryResponse>>>>>,
+        pub(super) history: OnceCell<Vec<podman::models::HistoryResponse>>,
         #[property(get, set, construct_only, nullable)]
         pub(super) image_list: glib::WeakRef<model::ImageList>,
         #[property(get = Self::container_list)]
@@ -35,6 +38,9 @@ mod imp {
         pub(super) created: OnceCell<i64>,
         #[property(get)]
         pub(super) dangling: Cell<bool>,
+        /// Whether this is a manifest list that references an image per platform.
+        #[property(get)]
+        pub(super) is_manifest_list: Cell<bool>,
         #[property(get = Self::data, nullable)]
         pub(super) data: OnceCell<Option<model::ImageData>>,
         #[property(get, set, construct_only)]
@@ -49,6 +55,8 @@ mod imp {
         pub(super) virtual_size: Cell<u64>,
         #[property(get)]
         pub(super) to_be_deleted: Cell<bool>,
+        #[property(get)]
+        pub(super) refreshing: Cell<bool>,
         #[property(get, set)]
         pub(super) selected: Cell<bool>,
     }
@@ -114,6 +122,15 @@ mod imp {
             obj.notify_dangling();
         }
 
+        pub(super) fn set_is_manifest_list(&self, value: bool) {
+            let obj = &*self.obj();
+            if obj.is_manifest_list() == value {
+                return;
+            }
+            self.is_manifest_list.set(value);
+            obj.notify_is_manifest_list();
+        }
+
         pub(super) fn repo_tags(&self) -> model::RepoTagList {
             self.repo_tags
                 .get_or_init(|| model::RepoTagList::from(&*self.obj()))
@@ -146,6 +163,15 @@ mod imp {
             self.to_be_deleted.set(value);
             obj.notify_to_be_deleted();
         }
+
+        pub(super) fn set_refreshing(&self, value: bool) {
+            let obj = &*self.obj();
+            if obj.refreshing() == value {
+                return;
+            }
+            self.refreshing.set(value);
+            obj.notify_refreshing();
+        }
     }
 }
 
@@ -177,6 +203,7 @@ impl Image {
 
         imp.set_containers(summary.containers.unwrap_or_default() as u64);
         imp.set_dangling(summary.dangling.unwrap_or_default());
+        imp.set_is_manifest_list(summary.is_manifest_list.unwrap_or_default());
         if self.repo_tags().update(HashSet::from_iter(
             summary.repo_tags.as_deref().unwrap_or_default().iter(),
         )) && notify_repo_tags
@@ -194,6 +221,62 @@ impl Image {
         self.update_internal(summary, true)
     }
 
+    /// Refreshes only this image without listing all images. The image is removed from its list if
+    /// it doesn't exist anymore.
+    pub(crate) fn refresh<F>(&self, op: F)
+    where
+        F: FnOnce(podman::Result<()>) + 'static,
+    {
+        let Some(client) = self
+            .image_list()
+            .as_ref()
+            .and_then(model::ImageList::client)
+        else {
+            return;
+        };
+
+        if self.refreshing() {
+            return;
+        }
+        self.imp().set_refreshing(true);
+
+        rt::Promise::new({
+            let podman = client.podman();
+            let id = podman::Id::from(self.id());
+            async move {
+                podman
+                    .images()
+                    .list(
+                        &podman::opts::ImageListOpts::builder()
+                            .all(true)
+                            .filter([podman::opts::ImageListFilter::Id(id)])
+                            .build(),
+                    )
+                    .await
+            }
+        })
+        .defer(clone!(
+            #[weak(rename_to = obj)]
+            self,
+            move |result| {
+                obj.imp().set_refreshing(false);
+
+                op(result
+                    .inspect_err(|e| log::error!("Error on refreshing image '{}': {e}", obj.id()))
+                    .map(|summaries| match summaries.first() {
+                        Some(summary) => {
+                            obj.update(summary);
+                        }
+                        None => {
+                            if let Some(image_list) = obj.image_list() {
+                                image_list.remove_image(&obj.id());
+                            }
+                        }
+                    }));
+            }
+        ));
+    }
+
     pub(crate) fn inspect<F>(&self, op: F)
     where
         F: Fn(Result<model::Image, &podman::Error>) + 'static,
@@ -241,6 +324,57 @@ impl Image {
 
         self.imp().inspection_callbacks.replace(Some(callbacks));
     }
+
+    /// Retrieves the history once and hands it out to every caller afterwards. The most recent
+    /// entry comes first.
+    pub(crate) fn history<F>(&self, op: F)
+    where
+        F: Fn(Result<&[podman::models::HistoryResponse], &podman::Error>) + 'static,
+    {
+        let imp = self.imp();
+
+        if let Some(history) = imp.history.get() {
+            op(Ok(history));
+            return;
+        }
+
+        if let Some(callbacks) = imp.history_callbacks.borrow().as_ref() {
+            callbacks.add(clone!(
+                #[weak(rename_to = obj)]
+                self,
+                move |result| match result {
+                    Ok(_) => op(Ok(obj.imp().history.get().unwrap())),
+                    Err(e) => op(Err(e)),
+                }
+            ));
+
+            return;
+        }
+
+        let callbacks = rt::Promise::new({
+            let image = self.api().unwrap();
+            async move { image.history().await }
+        })
+        .defer_with_callbacks(clone!(
+            #[weak(rename_to = obj)]
+            self,
+            move |result| {
+                let imp = obj.imp();
+
+                imp.history_callbacks.replace(None);
+
+                match result {
+                    Ok(history) => op(Ok(imp.history.get_or_init(|| history.clone()))),
+                    Err(e) => {
+                        log::error!("Error on retrieving history of image '{}': {e}", obj.id());
+                        op(Err(e));
+                    }
+                }
+            }
+        ));
+
+        imp.history_callbacks.replace(Some(callbacks));
+    }
 }
 
 impl Image {
@@ -254,6 +388,11 @@ impl Image {
             return;
         };
 
+        if self.is_read_only() {
+            op(self, Err(model::Connection::read_only_error()));
+            return;
+        }
+
         self.imp().set_to_be_deleted(true);
 
         rt::Promise::new(async move { image.remove().await }).defer(clone!(
@@ -269,6 +408,36 @@ impl Image {
         ));
     }
 
+    /// Fetches the images that are referenced by this manifest list, one per platform.
+    pub(crate) fn manifest_entries<F>(&self, op: F)
+    where
+        F: FnOnce(anyhow::Result<Vec<ManifestEntry>>) + 'static,
+    {
+        let Some(client) = self
+            .image_list()
+            .as_ref()
+            .and_then(model::ImageList::client)
+        else {
+            return;
+        };
+
+        rt::Promise::new({
+            let manifest = podman::api::Manifest::new(client.podman().deref().clone(), self.id());
+            async move {
+                let list = serde_json::to_value(manifest.inspect().await?)?;
+                Ok::<_, anyhow::Error>(
+                    list["manifests"]
+                        .as_array()
+                        .into_iter()
+                        .flatten()
+                        .map(ManifestEntry::from)
+                        .collect(),
+                )
+            }
+        })
+        .defer(op);
+    }
+
     pub(super) fn emit_deleted(&self) {
         self.emit_by_name::<()>("deleted", &[]);
     }
@@ -281,6 +450,13 @@ impl Image {
         })
     }
 
+    /// Whether the connection of this image is in read-only mode.
+    pub(crate) fn is_read_only(&self) -> bool {
+        self.image_list()
+            .and_then(|list| list.client())
+            .is_some_and(|client| client.connection().read_only())
+    }
+
     pub(crate) fn api(&self) -> Option<podman::api::Image> {
         self.image_list()
             .unwrap()
@@ -288,3 +464,33 @@ impl Image {
             .map(|client| podman::api::Image::new(client.podman().deref().clone(), self.id()))
     }
 }
+
+/// An image within a manifest list.
+#[derive(Clone, Debug)]
+pub(crate) struct ManifestEntry {
+    pub(crate) digest: String,
+    /// The platform in the form `os/architecture[/variant]`.
+    pub(crate) platform: String,
+    pub(crate) size: u64,
+}
+
+impl From<&serde_json::Value> for ManifestEntry {
+    fn from(value: &serde_json::Value) -> Self {
+        let platform = &value["platform"];
+
+        Self {
+            digest: value["digest"].as_str().unwrap_or_default().to_owned(),
+            platform: [
+                &platform["os"],
+                &platform["architecture"],
+                &platform["variant"],
+            ]
+            .into_iter()
+            .filter_map(serde_json::Value::as_str)
+            .filter(|part| !part.is_empty())
+            .collect::<Vec<_>>()
+            .join("/"),
+            size: value["size"].as_u64().unwrap_or_default(),
+        }
+    }
+}
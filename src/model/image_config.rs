@@ -3,8 +3,10 @@ use std::cell::OnceCell;
 use glib::Properties;
 use glib::prelude::*;
 use glib::subclass::prelude::*;
+use gtk::gio;
 use gtk::glib;
 
+use crate::model;
 use crate::podman;
 use crate::utils;
 
@@ -20,6 +22,8 @@ mod imp {
         pub(super) entrypoint: OnceCell<Option<String>>,
         #[property(get, set, construct_only)]
         pub(super) exposed_ports: OnceCell<gtk::StringList>,
+        #[property(get, set, construct_only)]
+        pub(super) labels: OnceCell<gio::ListStore>,
     }
 
     #[glib::object_subclass]
@@ -71,6 +75,26 @@ impl ImageConfig {
                         .unwrap_or_default(),
                 ),
             )
+            .property("labels", labels(config))
             .build()
     }
 }
+
+fn labels(config: &podman::models::ImageConfig) -> gio::ListStore {
+    let mut labels = config
+        .labels
+        .iter()
+        .flatten()
+        .map(|(key, value)| {
+            let label = model::KeyVal::default();
+            label.set_key(key.as_str());
+            label.set_value(value.as_str());
+            label
+        })
+        .collect::<Vec<_>>();
+    labels.sort_by_key(model::KeyVal::key);
+
+    let store = gio::ListStore::new::<model::KeyVal>();
+    store.extend_from_slice(&labels);
+    store
+}
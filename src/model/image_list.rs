@@ -255,12 +255,18 @@ impl ImageList {
                             obj.remove_image(id);
                         });
 
+                        let mut skipped = 0;
                         summaries.iter().for_each(|summary| {
+                            let Some(id) = summary.id.clone() else {
+                                skipped += 1;
+                                return;
+                            };
+
                             let index = obj.len();
 
                             let mut list = obj.imp().list.borrow_mut();
 
-                            match list.entry(summary.id.as_ref().unwrap().to_owned()) {
+                            match list.entry(id) {
                                 Entry::Vacant(e) => {
                                     let image = model::Image::new(&obj, summary);
                                     e.insert(image.clone());
@@ -277,6 +283,10 @@ impl ImageList {
                                 }
                             }
                         });
+
+                        if skipped > 0 {
+                            log::warn!("Skipped {} malformed image entries without an id", skipped);
+                        }
                     }
                     Err(e) => {
                         log::error!("Error on retrieving images: {}", e);
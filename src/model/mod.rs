@@ -1,15 +1,19 @@
 mod abstract_container_list;
 mod action;
 mod action_list;
+mod background_change;
 mod client;
 mod connection;
 mod connection_manager;
 mod container;
 mod container_data;
 mod container_list;
+mod container_quadlet;
+mod container_template;
 mod container_volume;
 mod container_volume_list;
 mod device;
+mod exec_history;
 mod health_check_log;
 mod health_check_log_list;
 mod image;
@@ -26,6 +30,7 @@ mod port_mapping;
 mod port_mapping_list;
 mod process;
 mod process_list;
+mod registry_login;
 mod repo_tag;
 mod repo_tag_list;
 mod selectable;
@@ -48,6 +53,10 @@ pub(crate) use self::action::Action;
 pub(crate) use self::action::State as ActionState;
 pub(crate) use self::action::Type as ActionType;
 pub(crate) use self::action_list::ActionList;
+pub(crate) use self::background_change::BackgroundChange;
+pub(crate) use self::background_change::BackgroundChangeCategory;
+pub(crate) use self::background_change::BoxedBackgroundChange;
+pub(crate) use self::client::CLOCK_SKEW_THRESHOLD;
 pub(crate) use self::client::Client;
 pub(crate) use self::client::ClientError;
 pub(crate) use self::connection::Connection;
@@ -59,13 +68,26 @@ pub(crate) use self::container::HealthStatus as ContainerHealthStatus;
 pub(crate) use self::container::Status as ContainerStatus;
 pub(crate) use self::container_data::BoxedPortBindings;
 pub(crate) use self::container_data::ContainerData;
+pub(crate) use self::container_list::BoxedIoRates;
 pub(crate) use self::container_list::ContainerList;
+pub(crate) use self::container_template::ContainerTemplate;
+pub(crate) use self::container_template::HealthCheckTemplate as ContainerHealthCheckTemplate;
+pub(crate) use self::container_template::MemoryLimit as ContainerMemoryLimit;
+pub(crate) use self::container_template::MountTemplate as ContainerMountTemplate;
+pub(crate) use self::container_template::PortMappingTemplate as ContainerPortMappingTemplate;
+pub(crate) use self::container_template::load_container_templates;
+pub(crate) use self::container_template::save_container_templates;
 pub(crate) use self::container_volume::ContainerVolume;
 pub(crate) use self::container_volume_list::ContainerVolumeList;
 pub(crate) use self::device::Device;
+pub(crate) use self::exec_history::ExecHistory;
+pub(crate) use self::exec_history::add_to_exec_history;
+pub(crate) use self::exec_history::load_exec_history;
+pub(crate) use self::exec_history::save_exec_history;
 pub(crate) use self::health_check_log::HealthCheckLog;
 pub(crate) use self::health_check_log_list::HealthCheckLogList;
 pub(crate) use self::image::Image;
+pub(crate) use self::image::ManifestEntry;
 pub(crate) use self::image_config::ImageConfig;
 pub(crate) use self::image_data::ImageData;
 pub(crate) use self::image_list::ImageList;
@@ -83,6 +105,12 @@ pub(crate) use self::port_mapping::Protocol as PortMappingProtocol;
 pub(crate) use self::port_mapping_list::PortMappingList;
 pub(crate) use self::process::Process;
 pub(crate) use self::process_list::ProcessList;
+pub(crate) use self::registry_login::RegistryCredentials;
+pub(crate) use self::registry_login::delete_registry_login;
+pub(crate) use self::registry_login::load_registry_logins;
+pub(crate) use self::registry_login::registry_of;
+pub(crate) use self::registry_login::save_registry_login;
+pub(crate) use self::registry_login::verify_registry_login;
 pub(crate) use self::repo_tag::RepoTag;
 pub(crate) use self::repo_tag_list::RepoTagList;
 pub(crate) use self::selectable::Selectable;
@@ -92,6 +120,8 @@ pub(crate) use self::selectable_list::SelectableListExt;
 pub(crate) use self::simple_container_list::SimpleContainerList;
 pub(crate) use self::value::Value;
 pub(crate) use self::volume::BoxedVolume;
+pub(crate) use self::volume::HELPER_CONTAINER_LABEL;
+pub(crate) use self::volume::LARGE_ARCHIVE_SIZE;
 pub(crate) use self::volume::Volume;
 pub(crate) use self::volume_list::VolumeList;
 
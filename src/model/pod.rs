@@ -10,12 +10,13 @@ use std::sync::OnceLock;
 use futures::Future;
 use futures::prelude::*;
 use gettextrs::gettext;
+use gio::prelude::*;
 use glib::Properties;
 use glib::clone;
-use glib::prelude::*;
 use glib::property::PropertySet;
 use glib::subclass::Signal;
 use glib::subclass::prelude::*;
+use gtk::gio;
 use gtk::glib;
 
 use crate::model;
@@ -136,8 +137,14 @@ mod imp {
         pub(super) num_containers: Cell<u64>,
         #[property(get, set, construct, builder(Status::default()))]
         pub(super) status: Cell<Status>,
+        /// The status as it follows from the containers of the pod, which can differ from the
+        /// status that Podman reports.
+        #[property(get, builder(Status::default()))]
+        pub(super) derived_status: Cell<Status>,
         #[property(get)]
         pub(super) to_be_deleted: Cell<bool>,
+        #[property(get)]
+        pub(super) refreshing: Cell<bool>,
         #[property(get, set)]
         pub(super) selected: Cell<bool>,
     }
@@ -186,6 +193,16 @@ mod imp {
                 }
             ));
             handler_id_ref.set(Some(handler_id));
+
+            // This is notified whenever containers are added, removed or change their status.
+            obj.container_list().connect_notify_local(
+                Some("containers"),
+                clone!(
+                    #[weak]
+                    obj,
+                    move |_, _| obj.imp().update_derived_status()
+                ),
+            );
         }
     }
 
@@ -206,6 +223,37 @@ mod imp {
             self.obj().notify_data();
         }
 
+        /// Running if all containers are running, degraded if only some are and stopped if none is.
+        /// The infra container is left out, because it runs as long as the pod does.
+        fn update_derived_status(&self) {
+            let obj = &*self.obj();
+
+            let (running, total) = obj
+                .container_list()
+                .iter::<model::Container>()
+                .map(Result::unwrap)
+                .filter(|container| !container.is_infra())
+                .fold((0, 0), |(running, total), container| {
+                    (
+                        running + u32::from(container.status() == model::ContainerStatus::Running),
+                        total + 1,
+                    )
+                });
+
+            let status = match (running, total) {
+                (_, 0) => Status::Unknown,
+                (0, _) => Status::Stopped,
+                (running, total) if running == total => Status::Running,
+                _ => Status::Degraded,
+            };
+
+            if obj.derived_status() == status {
+                return;
+            }
+            self.derived_status.set(status);
+            obj.notify_derived_status();
+        }
+
         pub(super) fn set_to_be_deleted(&self, value: bool) {
             let obj = &*self.obj();
             if obj.to_be_deleted() == value {
@@ -214,6 +262,15 @@ mod imp {
             self.to_be_deleted.set(value);
             obj.notify_to_be_deleted();
         }
+
+        pub(super) fn set_refreshing(&self, value: bool) {
+            let obj = &*self.obj();
+            if obj.refreshing() == value {
+                return;
+            }
+            self.refreshing.set(value);
+            obj.notify_refreshing();
+        }
     }
 }
 
@@ -251,6 +308,37 @@ impl Pod {
         }
     }
 
+    /// Refreshes only this pod without listing all pods. The pod is removed from its list if it
+    /// doesn't exist anymore.
+    pub(crate) fn refresh<F>(&self, op: F)
+    where
+        F: FnOnce(podman::Result<()>) + 'static,
+    {
+        let Some(pod_list) = self
+            .pod_list()
+            .filter(|pod_list| pod_list.client().is_some())
+        else {
+            return;
+        };
+
+        if self.refreshing() {
+            return;
+        }
+        self.imp().set_refreshing(true);
+
+        pod_list.refresh_and_then(
+            Some(self.id()),
+            clone!(
+                #[weak(rename_to = obj)]
+                self,
+                move |result| {
+                    obj.imp().set_refreshing(false);
+                    op(result);
+                }
+            ),
+        );
+    }
+
     pub(crate) fn inspect<F>(&self, op: F)
     where
         F: Fn(Result<model::Pod, &podman::Error>) + 'static,
@@ -329,6 +417,12 @@ impl Pod {
             return;
         }
 
+        if self.is_read_only() {
+            log::warn!("Pod <{}>: Refused {name} in read-only mode", self.id());
+            res_op(Err(model::Connection::read_only_error()));
+            return;
+        }
+
         // This will be either set back to `false` in `Self::update` or in case of an error.
         self.set_action_ongoing(true);
 
@@ -453,35 +547,43 @@ impl Pod {
         );
     }
 
+    /// Whether the connection of this pod is in read-only mode.
+    pub(crate) fn is_read_only(&self) -> bool {
+        self.pod_list()
+            .and_then(|list| list.client())
+            .is_some_and(|client| client.connection().read_only())
+    }
+
     pub(crate) fn can_start(&self) -> bool {
-        matches!(
-            self.status(),
-            Status::Created | Status::Exited | Status::Stopped
-        )
+        !self.is_read_only()
+            && matches!(
+                self.status(),
+                Status::Created | Status::Exited | Status::Stopped
+            )
     }
 
     pub(crate) fn can_stop(&self) -> bool {
-        matches!(self.status(), Status::Running)
+        !self.is_read_only() && matches!(self.status(), Status::Running)
     }
 
     pub(crate) fn can_kill(&self) -> bool {
-        !self.can_start()
+        !self.is_read_only() && !self.can_start()
     }
 
     pub(crate) fn can_restart(&self) -> bool {
-        matches!(self.status(), Status::Running)
+        !self.is_read_only() && matches!(self.status(), Status::Running)
     }
 
     pub(crate) fn can_pause(&self) -> bool {
-        matches!(self.status(), Status::Running)
+        !self.is_read_only() && matches!(self.status(), Status::Running)
     }
 
     pub(crate) fn can_resume(&self) -> bool {
-        matches!(self.status(), Status::Paused)
+        !self.is_read_only() && matches!(self.status(), Status::Paused)
     }
 
     pub(crate) fn can_delete(&self) -> bool {
-        !matches!(self.status(), Status::Running | Status::Paused)
+        !self.is_read_only() && !matches!(self.status(), Status::Running | Status::Paused)
     }
 
     pub(crate) fn api(&self) -> Option<podman::api::Pod> {
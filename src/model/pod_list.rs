@@ -218,6 +218,19 @@ impl PodList {
     pub(crate) fn refresh<F>(&self, id: Option<String>, err_op: F)
     where
         F: FnOnce(super::RefreshError) + Clone + 'static,
+    {
+        self.refresh_and_then(id, move |result| {
+            if result.is_err() {
+                err_op(super::RefreshError);
+            }
+        });
+    }
+
+    /// Lists all pods or only the one with the given id and calls `op` when done. With an id,
+    /// the pod is removed if it doesn't exist anymore.
+    pub(crate) fn refresh_and_then<F>(&self, id: Option<String>, op: F)
+    where
+        F: FnOnce(podman::Result<()>) + 'static,
     {
         self.imp().set_listing(true);
 
@@ -242,32 +255,37 @@ impl PodList {
             #[weak(rename_to = obj)]
             self,
             move |result| {
-                match result {
+                let result = match result {
                     Ok(list_pods) => {
-                        if id.is_none() {
-                            let to_remove = obj
-                                .imp()
-                                .list
-                                .borrow()
-                                .keys()
-                                .filter(|id| {
-                                    !list_pods
-                                        .iter()
-                                        .any(|list_pod| list_pod.id.as_ref() == Some(id))
-                                })
-                                .cloned()
-                                .collect::<Vec<_>>();
-                            to_remove.iter().for_each(|id| {
-                                obj.remove_pod(id);
-                            });
-                        }
+                        let to_remove = obj
+                            .imp()
+                            .list
+                            .borrow()
+                            .keys()
+                            .filter(|key| id.as_ref().is_none_or(|id| id == *key))
+                            .filter(|key| {
+                                !list_pods
+                                    .iter()
+                                    .any(|list_pod| list_pod.id.as_ref() == Some(key))
+                            })
+                            .cloned()
+                            .collect::<Vec<_>>();
+                        to_remove.iter().for_each(|id| {
+                            obj.remove_pod(id);
+                        });
 
+                        let mut skipped = 0;
                         list_pods.into_iter().for_each(|report| {
+                            let Some(id) = report.id.clone() else {
+                                skipped += 1;
+                                return;
+                            };
+
                             let index = obj.len();
 
                             let mut list = obj.imp().list.borrow_mut();
 
-                            match list.entry(report.id.as_ref().unwrap().to_owned()) {
+                            match list.entry(id) {
                                 Entry::Vacant(e) => {
                                     let pod = model::Pod::new(&obj, report);
                                     e.insert(pod.clone());
@@ -284,15 +302,23 @@ impl PodList {
                                 }
                             }
                         });
+
+                        if skipped > 0 {
+                            log::warn!("Skipped {} malformed pod entries without an id", skipped);
+                        }
+
+                        Ok(())
                     }
                     Err(e) => {
                         log::error!("Error on retrieving pods: {}", e);
-                        err_op(super::RefreshError);
+                        Err(e)
                     }
-                }
+                };
                 let imp = obj.imp();
                 imp.set_listing(false);
                 imp.set_as_initialized();
+
+                op(result);
             }
         ));
     }
@@ -0,0 +1,168 @@
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::path::Path;
+
+use gio::prelude::*;
+use gtk::gio;
+use gtk::glib;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// The registry that is used by Podman for references without one.
+const DEFAULT_REGISTRY: &str = "docker.io";
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub(crate) struct RegistryCredentials {
+    pub(crate) username: String,
+    pub(crate) password: String,
+}
+
+/// The registry logins are kept in the secret service, never in the plain config. They belong to
+/// a single connection, so that credentials are never sent to another, possibly remote, Podman.
+pub(crate) async fn load_registry_logins(
+    connection: &str,
+) -> anyhow::Result<BTreeMap<String, RegistryCredentials>> {
+    let Some(keyring) = crate::KEYRING.get() else {
+        return Ok(BTreeMap::new());
+    };
+
+    let mut logins = BTreeMap::new();
+    for item in keyring.search_items(&attributes(connection, None)).await? {
+        let Some(registry) = item.attributes().await?.remove("registry") else {
+            continue;
+        };
+        let credentials = serde_json::from_slice(item.secret().await?.as_bytes())?;
+        logins.insert(registry, credentials);
+    }
+
+    Ok(logins)
+}
+
+pub(crate) async fn save_registry_login(
+    connection: &str,
+    registry: &str,
+    credentials: &RegistryCredentials,
+) -> anyhow::Result<()> {
+    let keyring = crate::KEYRING
+        .get()
+        .ok_or_else(|| anyhow::anyhow!("Secret Service is not available"))?;
+
+    keyring
+        .create_item(
+            &format!("Pods registry login for {registry}"),
+            &attributes(connection, Some(registry)),
+            serde_json::to_vec(credentials)?,
+            true,
+        )
+        .await
+        .map_err(anyhow::Error::from)
+}
+
+pub(crate) async fn delete_registry_login(connection: &str, registry: &str) -> anyhow::Result<()> {
+    match crate::KEYRING.get() {
+        Some(keyring) => keyring
+            .delete(&attributes(connection, Some(registry)))
+            .await
+            .map_err(anyhow::Error::from),
+        None => Ok(()),
+    }
+}
+
+/// Checks the credentials through the compat `/auth` endpoint of the connection. This way Podman
+/// logs in to the registry itself, taking its own registry configuration and network into account.
+pub(crate) async fn verify_registry_login(
+    connection_url: &str,
+    registry: &str,
+    credentials: &RegistryCredentials,
+) -> anyhow::Result<()> {
+    let address = match connection_url.strip_prefix("unix://") {
+        Some(path) => {
+            gio::UnixSocketAddress::new(Path::new(path)).upcast::<gio::SocketConnectable>()
+        }
+        None => {
+            let uri = glib::Uri::parse(connection_url, glib::UriFlags::NONE)?;
+            let host = uri
+                .host()
+                .ok_or_else(|| anyhow::anyhow!("URL without host: {connection_url}"))?;
+            gio::NetworkAddress::new(&host, uri.port().max(0) as u16).upcast()
+        }
+    };
+
+    let body = serde_json::json!({
+        "username": credentials.username,
+        "password": credentials.password,
+        "serveraddress": registry,
+    })
+    .to_string();
+    let request = format!(
+        "POST /auth HTTP/1.1\r\nHost: d\r\nUser-Agent: Pods\r\nContent-Type: application/json\r\n\
+         Content-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    );
+
+    let connection = gio::SocketClient::new().connect_future(&address).await?;
+
+    let (_, _, error) = connection
+        .output_stream()
+        .write_all_future(request.into_bytes(), glib::Priority::default())
+        .await
+        .map_err(|(_, e)| e)?;
+    if let Some(e) = error {
+        return Err(e.into());
+    }
+
+    let input = gio::DataInputStream::new(&connection.input_stream());
+    let status = input
+        .read_line_utf8_future(glib::Priority::default())
+        .await?
+        .and_then(|line| line.split_whitespace().nth(1)?.parse::<u32>().ok())
+        .ok_or_else(|| anyhow::anyhow!("invalid response from Podman"))?;
+
+    // Skip the headers, the connection is closed after the body.
+    while let Some(line) = input
+        .read_line_utf8_future(glib::Priority::default())
+        .await?
+    {
+        if line.trim_end_matches('\r').is_empty() {
+            break;
+        }
+    }
+    let mut response = String::new();
+    while let Some(line) = input
+        .read_line_utf8_future(glib::Priority::default())
+        .await?
+    {
+        response.push_str(&line);
+    }
+
+    _ = connection.close_future(glib::Priority::default()).await;
+
+    if status == 200 {
+        return Ok(());
+    }
+
+    // Podman describes the error in the `message` field of the response.
+    let message = serde_json::from_str::<serde_json::Value>(&response)
+        .ok()
+        .and_then(|response| response["message"].as_str().map(str::to_owned))
+        .unwrap_or_else(|| format!("unexpected response from Podman: {status}"));
+
+    Err(anyhow::anyhow!(message))
+}
+
+/// Returns the registry of an image reference, following the rules Podman uses to tell a
+/// registry from a namespace.
+pub(crate) fn registry_of(reference: &str) -> &str {
+    match reference.split_once('/') {
+        Some((first, _)) if first.contains(['.', ':']) || first == "localhost" => first,
+        _ => DEFAULT_REGISTRY,
+    }
+}
+
+fn attributes<'a>(connection: &'a str, registry: Option<&'a str>) -> HashMap<&'a str, &'a str> {
+    let mut attributes = HashMap::from([("type", "registry-login"), ("connection", connection)]);
+    if let Some(registry) = registry {
+        attributes.insert("registry", registry);
+    }
+    attributes
+}
@@ -7,6 +7,8 @@ use glib::subclass::prelude::*;
 use gtk::glib;
 
 use crate::model;
+use crate::podman;
+use crate::rt;
 
 mod imp {
     use super::*;
@@ -20,12 +22,15 @@ mod imp {
         pub(super) full: OnceCell<String>,
         #[property(get, set)]
         pub(super) to_be_deleted: Cell<bool>,
+        #[property(get, set)]
+        pub(super) selected: Cell<bool>,
     }
 
     #[glib::object_subclass]
     impl ObjectSubclass for RepoTag {
         const NAME: &'static str = "RepoTag";
         type Type = super::RepoTag;
+        type Interfaces = (model::Selectable,);
     }
 
     impl ObjectImpl for RepoTag {
@@ -44,7 +49,7 @@ mod imp {
 }
 
 glib::wrapper! {
-    pub(crate) struct RepoTag(ObjectSubclass<imp::RepoTag>);
+    pub(crate) struct RepoTag(ObjectSubclass<imp::RepoTag>) @implements model::Selectable;
 }
 
 impl RepoTag {
@@ -70,4 +75,44 @@ impl RepoTag {
     pub(crate) fn tag(&self) -> String {
         self.full().split_once(':').unwrap().1.to_owned()
     }
+
+    pub(crate) async fn untag(&self) -> podman::Result<()> {
+        if self
+            .repo_tag_list()
+            .and_then(|list| list.image())
+            .is_some_and(|image| image.is_read_only())
+        {
+            return Err(model::Connection::read_only_error());
+        }
+
+        self.set_to_be_deleted(true);
+
+        rt::Promise::new({
+            let image = self
+                .repo_tag_list()
+                .unwrap()
+                .image()
+                .unwrap()
+                .api()
+                .unwrap();
+            let repo = self.repo();
+            let tag = self.tag();
+            async move {
+                image
+                    .untag(
+                        &podman::opts::ImageTagOpts::builder()
+                            .repo(repo)
+                            .tag(tag)
+                            .build(),
+                    )
+                    .await
+            }
+        })
+        .exec()
+        .await
+        .inspect_err(|e| {
+            self.set_to_be_deleted(false);
+            log::warn!("Error on untagging image: {e}");
+        })
+    }
 }
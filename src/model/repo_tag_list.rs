@@ -1,3 +1,4 @@
+use std::cell::Cell;
 use std::cell::RefCell;
 use std::collections::HashSet;
 use std::sync::OnceLock;
@@ -10,6 +11,7 @@ use gtk::glib;
 use indexmap::map::IndexMap;
 
 use crate::model;
+use crate::model::SelectableListExt;
 
 mod imp {
     use super::*;
@@ -20,13 +22,15 @@ mod imp {
         pub(super) list: RefCell<IndexMap<String, model::RepoTag>>,
         #[property(get, set, construct_only, nullable)]
         pub(crate) image: glib::WeakRef<model::Image>,
+        #[property(get, set)]
+        pub(super) selection_mode: Cell<bool>,
     }
 
     #[glib::object_subclass]
     impl ObjectSubclass for RepoTagList {
         const NAME: &'static str = "RepoTagList";
         type Type = super::RepoTagList;
-        type Interfaces = (gio::ListModel,);
+        type Interfaces = (gio::ListModel, model::SelectableList);
     }
 
     impl ObjectImpl for RepoTagList {
@@ -36,9 +40,12 @@ mod imp {
                 Self::derived_properties()
                     .iter()
                     .cloned()
-                    .chain(Some(
+                    .chain(vec![
                         glib::ParamSpecUInt::builder("len").read_only().build(),
-                    ))
+                        glib::ParamSpecUInt::builder("num-selected")
+                            .read_only()
+                            .build(),
+                    ])
                     .collect::<Vec<_>>()
             })
         }
@@ -50,14 +57,18 @@ mod imp {
         fn property(&self, id: usize, pspec: &glib::ParamSpec) -> glib::Value {
             match pspec.name() {
                 "len" => self.obj().len().to_value(),
+                "num-selected" => self.obj().num_selected().to_value(),
                 _ => self.derived_property(id, pspec),
             }
         }
 
         fn constructed(&self) {
             self.parent_constructed();
-            self.obj()
-                .connect_items_changed(|obj, _, _, _| obj.notify("len"));
+            let obj = &*self.obj();
+
+            model::SelectableList::bootstrap(obj.upcast_ref());
+
+            obj.connect_items_changed(|obj, _, _, _| obj.notify("len"));
         }
     }
 
@@ -78,7 +89,7 @@ mod imp {
 
 glib::wrapper! {
     pub(crate) struct RepoTagList(ObjectSubclass<imp::RepoTagList>)
-        @implements gio::ListModel, model::AbstractContainerList;
+        @implements gio::ListModel, model::AbstractContainerList, model::SelectableList;
 }
 
 impl From<&model::Image> for RepoTagList {
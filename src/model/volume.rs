@@ -1,8 +1,13 @@
 use std::cell::Cell;
 use std::cell::OnceCell;
 use std::ops::Deref;
+use std::path::PathBuf;
+use std::pin::pin;
 use std::sync::OnceLock;
 
+use futures::Future;
+use futures::StreamExt;
+use gettextrs::gettext;
 use gio::prelude::*;
 use glib::Properties;
 use glib::clone;
@@ -10,12 +15,22 @@ use glib::subclass::Signal;
 use glib::subclass::prelude::*;
 use gtk::gio;
 use gtk::glib;
+use tokio::io::AsyncWriteExt;
 
 use crate::model;
 use crate::monad_boxed_type;
 use crate::podman;
 use crate::rt;
 
+/// The image of the helper container that gives access to the data of a volume.
+const HELPER_IMAGE: &str = "docker.io/library/busybox:latest";
+/// Where the volume is mounted in the helper container.
+const HELPER_MOUNT_POINT: &str = "/volume";
+/// The label that marks helper containers, so that they aren't mistaken for changes of the user.
+pub(crate) const HELPER_CONTAINER_LABEL: &str = "com.github.marhkb.Pods.helper";
+/// Archives are read into memory completely when imported, so larger ones are warned about.
+pub(crate) const LARGE_ARCHIVE_SIZE: u64 = 1024 * 1024 * 1024;
+
 monad_boxed_type!(pub(crate) BoxedVolume(podman::models::Volume) impls Debug);
 
 mod imp {
@@ -107,6 +122,10 @@ impl Volume {
     }
 
     pub(crate) async fn delete(&self, force: bool) -> podman::Result<()> {
+        if self.is_read_only() {
+            return Err(model::Connection::read_only_error());
+        }
+
         let imp = self.imp();
 
         imp.set_to_be_deleted(true);
@@ -129,6 +148,139 @@ impl Volume {
         })
     }
 
+    /// Writes the data of the volume as tar archive to a file on the local machine. The archive
+    /// contains a single `volume` directory.
+    pub(crate) fn export<F>(&self, path: PathBuf, op: F)
+    where
+        F: FnOnce(anyhow::Result<()>) + 'static,
+    {
+        self.with_helper_container(
+            |container| async move {
+                let mut file = tokio::fs::File::create(path).await?;
+
+                let mut archive = pin!(container.copy_from(HELPER_MOUNT_POINT));
+                while let Some(chunk) = archive.next().await {
+                    file.write_all(&chunk?).await?;
+                }
+
+                file.flush().await.map_err(anyhow::Error::from)
+            },
+            op,
+        );
+    }
+
+    /// Copies the data of a tar archive that has been written by `Self::export` into the volume.
+    /// Existing files are overwritten, other files are kept.
+    pub(crate) fn import<F>(&self, path: PathBuf, op: F)
+    where
+        F: FnOnce(anyhow::Result<()>) + 'static,
+    {
+        if self.is_read_only() {
+            op(Err(model::Connection::read_only_error().into()));
+            return;
+        }
+
+        self.with_helper_container(
+            |container| async move {
+                let size = tokio::fs::metadata(&path).await?.len();
+                if size > LARGE_ARCHIVE_SIZE {
+                    log::warn!("Reading archive of {size} bytes into memory for restoring volume");
+                }
+
+                let data = tokio::fs::read(path).await?;
+                container
+                    .copy_to("/", data.into())
+                    .await
+                    .map_err(anyhow::Error::from)
+            },
+            op,
+        );
+    }
+
+    /// Podman has no API to access the data of a volume, so a container that mounts the volume
+    /// is created for the time of the operation. It's never started.
+    fn with_helper_container<Fut, FutOp, F>(&self, fut_op: FutOp, op: F)
+    where
+        Fut: Future<Output = anyhow::Result<()>> + Send,
+        FutOp: FnOnce(podman::api::Container) -> Fut + Send + 'static,
+        F: FnOnce(anyhow::Result<()>) + 'static,
+    {
+        let Some(client) = self.volume_list().and_then(|list| list.client()) else {
+            op(Err(anyhow::anyhow!(gettext("No connection to Podman"))));
+            return;
+        };
+
+        if self.action_ongoing() {
+            op(Err(anyhow::anyhow!(gettext(
+                "Another action on this volume is in progress"
+            ))));
+            return;
+        }
+        self.set_action_ongoing(true);
+
+        let podman = client.podman().deref().clone();
+        let pull_opts = client.pull_opts(HELPER_IMAGE).build();
+        let create_opts = podman::opts::ContainerCreateOpts::builder()
+            .image(HELPER_IMAGE)
+            .labels([(HELPER_CONTAINER_LABEL, "true")])
+            .volumes([podman::models::NamedVolume {
+                dest: Some(HELPER_MOUNT_POINT.to_owned()),
+                is_anonymous: None,
+                name: Some(self.inner().name.clone()),
+                options: None,
+            }])
+            .build();
+
+        rt::Promise::new(async move {
+            if !podman.images().get(HELPER_IMAGE).exists().await? {
+                let mut reports = pin!(podman.images().pull(&pull_opts));
+                while let Some(report) = reports.next().await {
+                    if let Some(error) = report?.error {
+                        anyhow::bail!(error);
+                    }
+                }
+            }
+
+            let container = podman
+                .containers()
+                .get(podman.containers().create(&create_opts).await?.id);
+
+            let result = fut_op(container.clone()).await;
+
+            // The helper container must not be left behind, even if the operation failed.
+            if let Err(e) = container
+                .delete(
+                    &podman::opts::ContainerDeleteOpts::builder()
+                        .force(true)
+                        .build(),
+                )
+                .await
+            {
+                log::warn!("Error on removing helper container: {e}");
+            }
+
+            result
+        })
+        .defer(clone!(
+            #[weak(rename_to = obj)]
+            self,
+            move |result| {
+                obj.set_action_ongoing(false);
+                if let Err(ref e) = result {
+                    log::error!("Error on accessing data of volume: {e}");
+                }
+                op(result);
+            }
+        ));
+    }
+
+    /// Whether the connection of this volume is in read-only mode.
+    pub(crate) fn is_read_only(&self) -> bool {
+        self.volume_list()
+            .and_then(|list| list.client())
+            .is_some_and(|client| client.connection().read_only())
+    }
+
     pub(crate) fn api(&self) -> Option<podman::api::Volume> {
         self.volume_list().unwrap().client().map(|client| {
             podman::api::Volume::new(client.podman().deref().clone(), &self.inner().name)
@@ -283,6 +283,17 @@ impl VolumeList {
                 }
             ),
         );
+        // The containers of a volume are only known once the search for them has finished.
+        volume.connect_notify_local(
+            Some("searching-containers"),
+            clone!(
+                #[weak(rename_to=obj)]
+                self,
+                move |volume, _| {
+                    obj.emit_by_name::<()>("containers-of-volume-changed", &[volume]);
+                }
+            ),
+        );
     }
 
     pub(crate) fn connect_volume_added<F: Fn(&Self, &model::Volume) + 'static>(
@@ -115,7 +115,8 @@ where
         let (tx, rx) = tokio::sync::mpsc::channel(10);
 
         Promise::new(async move {
-            let mut stream = (self.producer)(&self.api);
+            // Stop as soon as the receiver is gone, even if the stream has stalled.
+            let mut stream = Box::pin((self.producer)(&self.api).take_until(tx.closed()));
 
             while let Some(item) = stream.next().await {
                 if tx.send(item).await.is_err() {
@@ -153,6 +154,10 @@ impl From<glib::JoinHandle<()>> for PipeFinish {
     }
 }
 impl PipeFinish {
+    pub(crate) fn abort(&self) {
+        self.0.abort();
+    }
+
     pub(crate) fn on_finish<F>(self, mut op: F)
     where
         F: FnMut() + 'static,
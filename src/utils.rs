@@ -1,6 +1,8 @@
+use std::cell::RefCell;
 use std::fmt::Debug;
 use std::ops::Deref;
 use std::path::PathBuf;
+use std::rc::Rc;
 
 use adw::prelude::*;
 use ashpd::desktop::file_chooser::OpenFileRequest;
@@ -8,9 +10,12 @@ use ashpd::desktop::file_chooser::SaveFileRequest;
 use ashpd::desktop::file_chooser::SelectedFiles;
 use gettextrs::gettext;
 use gettextrs::ngettext;
+use gtk::gdk;
 use gtk::gio;
 use gtk::glib;
+use gtk::glib::clone;
 use gtk::glib::clone::Downgrade;
+use tokio::io::AsyncWriteExt;
 
 use crate::APPLICATION_OPTS;
 use crate::config;
@@ -51,6 +56,49 @@ pub(crate) fn config_dir() -> &'static PathBuf {
     &APPLICATION_OPTS.get().unwrap().config_dir
 }
 
+/// Reads the JSON file with the given name from the config directory. A missing file yields the
+/// default value.
+pub(crate) fn load_config_json<T>(file_name: &str) -> anyhow::Result<T>
+where
+    T: serde::de::DeserializeOwned + Default,
+{
+    let path = config_dir().join(file_name);
+
+    if path.exists() {
+        let buf = std::fs::read(path)?;
+        serde_json::from_slice::<T>(&buf).map_err(anyhow::Error::from)
+    } else {
+        Ok(T::default())
+    }
+}
+
+/// Writes the value to the JSON file with the given name in the config directory, which is
+/// created if needed.
+pub(crate) async fn save_config_json<T>(file_name: &str, value: &T) -> anyhow::Result<()>
+where
+    T: serde::Serialize,
+{
+    let buf = serde_json::to_vec_pretty(value)?;
+    let path = config_dir().join(file_name);
+
+    rt::Promise::new(async move {
+        if !config_dir().exists() {
+            tokio::fs::create_dir_all(config_dir()).await?;
+        }
+
+        let mut file = tokio::fs::OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(path)
+            .await?;
+
+        file.write_all(&buf).await.map_err(anyhow::Error::from)
+    })
+    .exec()
+    .await
+}
+
 pub(crate) fn unix_socket_url() -> String {
     format!(
         "unix://{}",
@@ -97,6 +145,11 @@ impl Deref for PodsSettings {
     }
 }
 
+/// Whether destructive actions like deletions are to be confirmed, as set in the preferences.
+pub(crate) fn confirm_destructive_actions() -> bool {
+    PodsSettings::default().boolean("confirm-destructive-actions")
+}
+
 pub(crate) fn human_friendly_duration(mut seconds: i64) -> String {
     let hours = seconds / (60 * 60);
     if hours > 0 {
@@ -290,6 +343,15 @@ pub(crate) fn css_classes<W: IsA<gtk::Widget>>(widget: &W) -> Vec<String> {
         .collect::<Vec<_>>()
 }
 
+/// Replaces the icon of an object's menu button with a spinner while the object is busy.
+pub(crate) fn set_menu_button_busy(menu_button: &gtk::MenuButton, busy: bool) {
+    if busy {
+        menu_button.set_child(Some(&adw::Spinner::new()));
+    } else {
+        menu_button.set_icon_name("view-more-symbolic");
+    }
+}
+
 pub(crate) struct ChildIter(Option<gtk::Widget>);
 impl<W: IsA<gtk::Widget>> From<&W> for ChildIter {
     fn from(widget: &W) -> Self {
@@ -310,6 +372,78 @@ pub(crate) fn unparent_children<W: IsA<gtk::Widget>>(widget: &W) {
     ChildIter::from(widget).for_each(|child| child.unparent());
 }
 
+const TYPE_AHEAD_TIMEOUT_MS: u64 = 1000;
+
+/// Moves the focus to the first row with a name starting with what has just been typed into the
+/// list box, like file managers do. The typed prefix is forgotten after a short pause.
+pub(crate) fn add_type_ahead_find<F>(list_box: &gtk::ListBox, row_names: F)
+where
+    F: Fn(&gtk::ListBoxRow) -> Vec<String> + 'static,
+{
+    let prefix = Rc::new(RefCell::new(String::new()));
+    let reset_source = Rc::new(RefCell::new(None::<glib::SourceId>));
+
+    let controller = gtk::EventControllerKey::new();
+    controller.connect_key_pressed(clone!(
+        #[weak]
+        list_box,
+        #[upgrade_or]
+        glib::Propagation::Proceed,
+        move |_, key, _, modifier| {
+            if modifier.intersects(
+                gdk::ModifierType::CONTROL_MASK
+                    | gdk::ModifierType::ALT_MASK
+                    | gdk::ModifierType::SUPER_MASK,
+            ) {
+                return glib::Propagation::Proceed;
+            }
+
+            // A leading space must still activate the focused row.
+            let Some(c) = key
+                .to_unicode()
+                .filter(|c| !c.is_control() && (!c.is_whitespace() || !prefix.borrow().is_empty()))
+            else {
+                return glib::Propagation::Proceed;
+            };
+
+            prefix.borrow_mut().extend(c.to_lowercase());
+
+            if let Some(source) = reset_source.take() {
+                source.remove();
+            }
+            reset_source.replace(Some(glib::timeout_add_local_once(
+                std::time::Duration::from_millis(TYPE_AHEAD_TIMEOUT_MS),
+                clone!(
+                    #[strong]
+                    prefix,
+                    #[strong]
+                    reset_source,
+                    move || {
+                        reset_source.take();
+                        prefix.borrow_mut().clear();
+                    }
+                ),
+            )));
+
+            let prefix = prefix.borrow();
+            if let Some(row) = (0..)
+                .map_while(|pos| list_box.row_at_index(pos))
+                .filter(|row| row.is_child_visible() && row.is_visible())
+                .find(|row| {
+                    row_names(row)
+                        .iter()
+                        .any(|name| name.to_lowercase().starts_with(&*prefix))
+                })
+            {
+                row.grab_focus();
+            }
+
+            glib::Propagation::Stop
+        }
+    ));
+    list_box.add_controller(controller);
+}
+
 pub(crate) async fn show_open_file_dialog<W, F>(request: OpenFileRequest, widget: &W, op: F)
 where
     W: IsA<gtk::Widget> + Downgrade<Weak = glib::WeakRef<W>>,
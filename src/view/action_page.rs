@@ -6,6 +6,7 @@ use gettextrs::gettext;
 use glib::Properties;
 use glib::clone;
 use gtk::CompositeTemplate;
+use gtk::gdk;
 use gtk::gio;
 use gtk::glib;
 
@@ -46,7 +47,9 @@ mod imp {
             klass.install_action(ACTION_VIEW_ARTIFACT, None, |widget, _, _| {
                 widget.view_artifact();
             });
-            klass.install_action(ACTION_RETRY, None, |widget, _, _| widget.retry());
+            klass.install_action_async(ACTION_RETRY, None, |widget, _, _| async move {
+                widget.retry().await;
+            });
         }
 
         fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
@@ -82,7 +85,10 @@ mod imp {
                 clone!(
                     #[weak]
                     obj,
-                    move |action, _| obj.update_state(action)
+                    move |action, _| {
+                        obj.update_state(action);
+                        obj.show_log_after_start(action);
+                    }
                 ),
             );
 
@@ -100,6 +106,25 @@ mod imp {
                     _ => unimplemented!(),
                 }));
 
+            if action.action_type() == BuildImage {
+                adw::StyleManager::default().connect_dark_notify(clone!(
+                    #[weak]
+                    obj,
+                    #[weak]
+                    action,
+                    move |_| {
+                        glib::idle_add_local_once(clone!(
+                            #[weak]
+                            obj,
+                            #[weak]
+                            action,
+                            move || obj.update_build_step_colors(&action)
+                        ));
+                    }
+                ));
+                obj.update_build_step_colors(&action);
+            }
+
             obj.set_description(&action);
             glib::timeout_add_seconds_local(
                 1,
@@ -255,7 +280,7 @@ impl ActionPage {
         self.action_set_enabled(
             ACTION_RETRY,
             matches!(action.state(), Aborted | Failed)
-                && self.ancestor(gtk::Stack::static_type()).is_some(),
+                && (action.can_retry() || self.ancestor(gtk::Stack::static_type()).is_some()),
         );
     }
 
@@ -271,18 +296,55 @@ impl ActionPage {
                 glib::ControlFlow::Continue
             }
             _ => {
-                state_label.set_description(Some(&gettext!(
+                let duration = gettext!(
                     "After {}",
                     utils::human_friendly_duration(
                         action.end_timestamp() - action.start_timestamp(),
                     )
-                )));
+                );
+
+                state_label.set_description(Some(&match action.build_steps_summary() {
+                    // Translators: The first "{}" is the duration, the second is the summary of
+                    // the build steps.
+                    Some(summary) => gettext!("{} – {}", duration, summary),
+                    None => duration,
+                }));
 
                 glib::ControlFlow::Break
             }
         }
     }
 
+    /// Colors the build step annotations in the output like the `success` and `warning` style
+    /// classes.
+    fn update_build_step_colors(&self, action: &model::Action) {
+        let style_manager = adw::StyleManager::default();
+        let style_context = self.style_context();
+
+        action.build_step_tag(true).set_foreground_rgba(Some(
+            &style_context
+                .lookup_color("success_color")
+                .unwrap_or_else(|| {
+                    if style_manager.is_dark() {
+                        gdk::RGBA::new(0.471, 0.914, 0.671, 1.0)
+                    } else {
+                        gdk::RGBA::new(0.106, 0.522, 0.325, 1.0)
+                    }
+                }),
+        ));
+        action.build_step_tag(false).set_foreground_rgba(Some(
+            &style_context
+                .lookup_color("warning_color")
+                .unwrap_or_else(|| {
+                    if style_manager.is_dark() {
+                        gdk::RGBA::new(0.973, 0.894, 0.361, 1.0)
+                    } else {
+                        gdk::RGBA::new(0.612, 0.431, 0.012, 1.0)
+                    }
+                }),
+        ));
+    }
+
     fn cancel(&self) {
         if let Some(action) = self.action() {
             action.cancel();
@@ -327,7 +389,44 @@ impl ActionPage {
         }
     }
 
-    fn retry(&self) {
+    fn show_log_after_start(&self, action: &model::Action) {
+        if !self.show_view_artifact()
+            || action.state() != model::ActionState::Finished
+            || action.action_type() != model::ActionType::CreateAndRunContainer
+        {
+            return;
+        }
+
+        if let Some(container) = action
+            .artifact()
+            .and_then(|artifact| artifact.downcast::<model::Container>().ok())
+            && view::container::show_log_after_start(&container)
+        {
+            self.activate_action("win.close", None).unwrap();
+        }
+    }
+
+    /// Starts the operation again in place of this page if the action supports it. Otherwise, the
+    /// user is brought back to the form the action has been started from.
+    async fn retry(&self) {
+        if let Some(action) = self.action()
+            && let Some(new_action) = action.retry().await
+        {
+            let page = Self::new(&new_action, self.show_view_artifact());
+            match self.parent() {
+                Some(parent) if parent.is::<adw::NavigationPage>() => parent
+                    .downcast::<adw::NavigationPage>()
+                    .unwrap()
+                    .set_child(Some(&page)),
+                Some(parent) if parent.is::<adw::ToastOverlay>() => parent
+                    .downcast::<adw::ToastOverlay>()
+                    .unwrap()
+                    .set_child(Some(&page)),
+                _ => log::warn!("Don't know how to show the retried action"),
+            }
+            return;
+        }
+
         if let Some(stack) = self
             .ancestor(gtk::Stack::static_type())
             .and_then(|ancestor| ancestor.downcast::<gtk::Stack>().ok())
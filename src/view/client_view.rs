@@ -1,7 +1,13 @@
+use std::cell::Cell;
+use std::cell::RefCell;
+use std::collections::HashMap;
+
 use adw::prelude::*;
 use adw::subclass::prelude::*;
 use gettextrs::gettext;
 use glib::Properties;
+use glib::clone;
+use glib::closure;
 use gtk::CompositeTemplate;
 use gtk::gdk;
 use gtk::glib;
@@ -14,6 +20,7 @@ const ACTION_SHOW_CONNECTIONS: &str = "client-view.show-connections";
 const ACTION_SHOW_ACTIONS: &str = "client-view.show-actions";
 const ACTION_CANCEL_OR_DELETE_ACTION: &str = "client-view.cancel-or-delete-action";
 const ACTION_CREATE_ENTITY: &str = "client-view.create-entity";
+const ACTION_TOGGLE_READ_ONLY: &str = "client-view.toggle-read-only";
 
 mod imp {
     use super::*;
@@ -24,8 +31,22 @@ mod imp {
     pub(crate) struct ClientView {
         pub(super) settings: utils::PodsSettings,
         pub(super) css_provider: gtk::CssProvider,
+        pub(super) clock_skew_dismissed: Cell<bool>,
+        /// The uuid of the connection whose pages are currently shown.
+        pub(super) session_connection: RefCell<Option<String>>,
+        /// The saved pages that wait for the lists of the client to be initialized.
+        pub(super) pending_session: RefCell<Vec<String>>,
+        /// The handlers waiting for the lists of the client to be initialized.
+        pub(super) pending_session_handlers:
+            RefCell<Vec<(glib::WeakRef<glib::Object>, glib::SignalHandlerId)>>,
+        pub(super) read_only_binding: RefCell<Option<glib::Binding>>,
+        pub(super) background_change_handler:
+            RefCell<Option<(glib::WeakRef<model::Client>, glib::SignalHandlerId)>>,
         #[property(get, set)]
         pub(super) client: glib::WeakRef<model::Client>,
+        /// Mirrors the read-only mode of the connection of the current client.
+        #[property(get, set)]
+        pub(super) read_only: Cell<bool>,
         #[template_child]
         pub(super) navigation_split_view: TemplateChild<adw::NavigationSplitView>,
         #[template_child]
@@ -54,6 +75,10 @@ mod imp {
         pub(super) search_navigation_view: TemplateChild<adw::NavigationView>,
         #[template_child]
         pub(super) color_bin: TemplateChild<adw::Bin>,
+        #[template_child]
+        pub(super) socket_banner: TemplateChild<adw::Banner>,
+        #[template_child]
+        pub(super) clock_skew_banner: TemplateChild<adw::Banner>,
     }
 
     #[glib::object_subclass]
@@ -90,6 +115,8 @@ mod imp {
             klass.install_action(ACTION_CREATE_ENTITY, None, |widget, _, _| {
                 widget.create_entity();
             });
+
+            klass.install_property_action(ACTION_TOGGLE_READ_ONLY, "read-only");
         }
 
         fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
@@ -135,6 +162,47 @@ mod imp {
                 );
             });
 
+            let obj = &*self.obj();
+
+            let client_expr = Self::Type::this_expression("client");
+            [
+                client_expr
+                    .chain_property::<model::Client>("connection")
+                    .chain_property::<model::Connection>("socket-available"),
+                client_expr.chain_property::<model::Client>("unreachable"),
+            ]
+            .iter()
+            .for_each(|expr| {
+                expr.watch(
+                    Some(obj),
+                    clone!(
+                        #[weak]
+                        obj,
+                        move || obj.imp().update_socket_banner()
+                    ),
+                );
+            });
+
+            let clock_skew_expr =
+                Self::Type::this_expression("client").chain_property::<model::Client>("clock-skew");
+            clock_skew_expr
+                .chain_closure::<String>(closure!(|_: Self::Type, skew: i64| {
+                    gettext!(
+                        // Translators: The "{}" is a placeholder for a timespan like "3 hours".
+                        "The clock of the Podman host differs by {} from the local one. Relative times may be wrong.",
+                        utils::human_friendly_timespan(glib::TimeSpan::from_seconds(skew.abs())),
+                    )
+                }))
+                .bind(&*self.clock_skew_banner, "title", Some(obj));
+            clock_skew_expr.watch(
+                Some(obj),
+                clone!(
+                    #[weak]
+                    obj,
+                    move || obj.imp().update_clock_skew_banner()
+                ),
+            );
+
             self.color_bin
                 .style_context()
                 .add_provider(&self.css_provider, gtk::STYLE_PROVIDER_PRIORITY_APPLICATION);
@@ -143,6 +211,7 @@ mod imp {
         }
 
         fn dispose(&self) {
+            self.disconnect_pending_session_handlers();
             utils::unparent_children(&*self.obj());
         }
     }
@@ -165,14 +234,69 @@ mod imp {
             glib::Propagation::Proceed
         }
 
+        #[template_callback]
+        fn on_clock_skew_banner_button_clicked(&self) {
+            self.clock_skew_dismissed.set(true);
+            self.update_clock_skew_banner();
+        }
+
+        #[template_callback]
+        fn on_read_only_banner_button_clicked(&self) {
+            self.obj().set_read_only(false);
+        }
+
         #[template_callback]
         fn on_notify_client(&self) {
+            let obj = &*self.obj();
+
+            if let Some(binding) = self.read_only_binding.take() {
+                binding.unbind();
+            }
+            if let Some((client, handler)) = self.background_change_handler.take()
+                && let Some(client) = client.upgrade()
+            {
+                client.disconnect(handler);
+            }
+
+            obj.save_session();
+
             self.exit_panel_search_mode();
             self.sidebar_navigation_view.pop_to_tag("home");
             self.panels_navigation_view.pop_to_tag("home");
 
-            if let Some(client) = self.obj().client() {
-                self.set_background(client.connection().rgb());
+            self.clock_skew_dismissed.set(false);
+            self.update_clock_skew_banner();
+
+            let client = obj.client();
+            self.session_connection
+                .replace(client.as_ref().map(|client| client.connection().uuid()));
+
+            match client {
+                Some(client) => {
+                    self.set_background(client.connection().rgb());
+                    self.restore_session(&client);
+
+                    self.read_only_binding.replace(Some(
+                        client
+                            .connection()
+                            .bind_property("read-only", obj, "read-only")
+                            .bidirectional()
+                            .sync_create()
+                            .build(),
+                    ));
+
+                    self.background_change_handler.replace(Some((
+                        client.downgrade(),
+                        client.connect_background_change(clone!(
+                            #[weak]
+                            obj,
+                            move |client, change| {
+                                obj.imp().show_background_change(client, change);
+                            }
+                        )),
+                    )));
+                }
+                None => obj.set_read_only(false),
             }
         }
 
@@ -280,6 +404,180 @@ mod imp {
             }
         }
 
+        fn restore_session(&self, client: &model::Client) {
+            self.disconnect_pending_session_handlers();
+
+            let uuid = client.connection().uuid();
+            let mut sessions = self
+                .settings
+                .value("open-pages")
+                .get::<HashMap<String, Vec<String>>>()
+                .unwrap_or_default();
+
+            self.pending_session
+                .replace(sessions.remove(&uuid).unwrap_or_default());
+            if self.pending_session.borrow().is_empty() {
+                return;
+            }
+
+            let obj = &*self.obj();
+            let on_initialized = clone!(
+                #[weak]
+                obj,
+                #[weak]
+                client,
+                move || obj.imp().push_pending_session(&client)
+            );
+
+            self.pending_session_handlers.replace(vec![
+                (
+                    client
+                        .container_list()
+                        .upcast_ref::<glib::Object>()
+                        .downgrade(),
+                    client.container_list().connect_initialized_notify({
+                        let on_initialized = on_initialized.clone();
+                        move |_| on_initialized()
+                    }),
+                ),
+                (
+                    client.pod_list().upcast_ref::<glib::Object>().downgrade(),
+                    client.pod_list().connect_initialized_notify({
+                        let on_initialized = on_initialized.clone();
+                        move |_| on_initialized()
+                    }),
+                ),
+                (
+                    client.image_list().upcast_ref::<glib::Object>().downgrade(),
+                    client.image_list().connect_initialized_notify({
+                        let on_initialized = on_initialized.clone();
+                        move |_| on_initialized()
+                    }),
+                ),
+                (
+                    client
+                        .volume_list()
+                        .upcast_ref::<glib::Object>()
+                        .downgrade(),
+                    client.volume_list().connect_initialized_notify({
+                        let on_initialized = on_initialized.clone();
+                        move |_| on_initialized()
+                    }),
+                ),
+            ]);
+
+            on_initialized();
+        }
+
+        fn disconnect_pending_session_handlers(&self) {
+            self.pending_session_handlers
+                .take()
+                .into_iter()
+                .for_each(|(object, handler)| {
+                    if let Some(object) = object.upgrade() {
+                        object.disconnect(handler);
+                    }
+                });
+        }
+
+        /// Pushes the saved pages once all lists are initialized. Pages whose object no longer
+        /// exists are skipped.
+        fn push_pending_session(&self, client: &model::Client) {
+            if self.obj().client().as_ref() != Some(client)
+                || !client.container_list().initialized()
+                || !client.pod_list().initialized()
+                || !client.image_list().initialized()
+                || !client.volume_list().initialized()
+            {
+                return;
+            }
+
+            self.disconnect_pending_session_handlers();
+
+            self.pending_session
+                .take()
+                .iter()
+                .filter_map(|entry| session_page(client, entry))
+                .for_each(|page| {
+                    self.panels_navigation_view
+                        .push(&adw::NavigationPage::builder().child(&page).build());
+                });
+        }
+
+        /// Shows a toast for the change if the user opted in to its category. The toast links to
+        /// the changed object unless it's gone.
+        fn show_background_change(&self, client: &model::Client, change: &model::BackgroundChange) {
+            if !self.settings.boolean(change.category.settings_key()) {
+                return;
+            }
+
+            let toast = adw::Toast::builder()
+                .title(glib::markup_escape_text(&change.title))
+                .timeout(3)
+                .build();
+
+            if let Some(target) = change.target.clone() {
+                toast.set_button_label(Some(&gettext("_Show")));
+                toast.connect_button_clicked(clone!(
+                    #[weak(rename_to = obj)]
+                    self.obj(),
+                    #[weak]
+                    client,
+                    move |_| {
+                        if obj.client().as_ref() != Some(&client) {
+                            return;
+                        }
+                        match session_page(&client, &target) {
+                            Some(page) => obj
+                                .imp()
+                                .panels_navigation_view
+                                .push(&adw::NavigationPage::builder().child(&page).build()),
+                            None => utils::show_toast(&obj, gettext("The object no longer exists")),
+                        }
+                    }
+                ));
+            }
+
+            if let Some(overlay) = self
+                .obj()
+                .ancestor(adw::ToastOverlay::static_type())
+                .and_downcast::<adw::ToastOverlay>()
+            {
+                overlay.add_toast(toast);
+            }
+        }
+
+        /// Offers to reconnect if the socket is gone or Podman could not be reached anymore.
+        fn update_socket_banner(&self) {
+            let Some(client) = self.obj().client() else {
+                self.socket_banner.set_revealed(false);
+                return;
+            };
+
+            if !client.connection().socket_available() {
+                self.socket_banner.set_title(&gettext(
+                    "The Podman socket is not available. Make sure the Podman service or socket is running.",
+                ));
+                self.socket_banner.set_revealed(true);
+            } else if client.unreachable() {
+                self.socket_banner.set_title(&gettext(
+                    "Podman is not reachable. The connection may have been lost while the computer was asleep.",
+                ));
+                self.socket_banner.set_revealed(true);
+            } else {
+                self.socket_banner.set_revealed(false);
+            }
+        }
+
+        fn update_clock_skew_banner(&self) {
+            self.clock_skew_banner.set_revealed(
+                !self.clock_skew_dismissed.get()
+                    && self.obj().client().is_some_and(|client| {
+                        client.clock_skew().abs() >= model::CLOCK_SKEW_THRESHOLD
+                    }),
+            );
+        }
+
         fn exit_panel_search_mode(&self) {
             self.containers_panel.set_search_mode(false);
             self.pods_panel.set_search_mode(false);
@@ -320,6 +618,41 @@ impl ClientView {
         &self.imp().panels_navigation_view
     }
 
+    /// Remembers the open detail pages of the current connection so that they can be restored on
+    /// the next launch.
+    pub(crate) fn save_session(&self) {
+        let imp = self.imp();
+
+        let Some(uuid) = imp.session_connection.borrow().clone() else {
+            return;
+        };
+
+        let entries = imp
+            .panels_navigation_view
+            .navigation_stack()
+            .iter::<adw::NavigationPage>()
+            .map_while(Result::ok)
+            .filter_map(|page| page.child())
+            .filter_map(|child| session_entry(&child))
+            .collect::<Vec<_>>();
+
+        let mut sessions = imp
+            .settings
+            .value("open-pages")
+            .get::<HashMap<String, Vec<String>>>()
+            .unwrap_or_default();
+
+        if entries.is_empty() {
+            sessions.remove(&uuid);
+        } else {
+            sessions.insert(uuid, entries);
+        }
+
+        if let Err(e) = imp.settings.set_value("open-pages", &sessions.to_variant()) {
+            log::warn!("Failed to save open pages: {e}");
+        }
+    }
+
     pub(crate) fn toggle_global_search(&self) {
         let imp = self.imp();
         if imp.client.upgrade().is_some() {
@@ -328,15 +661,16 @@ impl ClientView {
         }
     }
 
-    pub(crate) fn toggle_panel_search(&self) {
+    /// Focuses the search entry of the panel that is currently visible.
+    pub(crate) fn focus_panel_search(&self) {
         let imp = self.imp();
 
         if imp.stack.visible_child_name().as_deref() == Some("panels") {
             match imp.panels_stack.visible_child_name().unwrap().as_str() {
-                "containers" => imp.containers_panel.toggle_search_mode(),
-                "pods" => imp.pods_panel.toggle_search_mode(),
-                "images" => imp.images_panel.toggle_search_mode(),
-                "volumes" => imp.volumes_panel.toggle_search_mode(),
+                "containers" => imp.containers_panel.focus_search(),
+                "pods" => imp.pods_panel.focus_search(),
+                "images" => imp.images_panel.focus_search(),
+                "volumes" => imp.volumes_panel.focus_search(),
                 _ => {}
             }
         }
@@ -353,7 +687,7 @@ impl ClientView {
     }
 
     pub(crate) fn create_entity(&self) {
-        if self.client().is_some() {
+        if self.client().is_some() && !self.read_only() {
             let imp = self.imp();
 
             if imp.containers_panel.is_mapped() {
@@ -382,3 +716,50 @@ impl ClientView {
         }
     }
 }
+
+fn session_entry(page: &gtk::Widget) -> Option<String> {
+    if let Some(page) = page.downcast_ref::<view::ContainerDetailsPage>() {
+        page.container()
+            .map(|container| format!("container:{}", container.id()))
+    } else if let Some(page) = page.downcast_ref::<view::ContainerLogPage>() {
+        page.container()
+            .map(|container| format!("container-log:{}", container.id()))
+    } else if let Some(page) = page.downcast_ref::<view::PodDetailsPage>() {
+        page.pod().map(|pod| format!("pod:{}", pod.id()))
+    } else if let Some(page) = page.downcast_ref::<view::ImageDetailsPage>() {
+        page.image().map(|image| format!("image:{}", image.id()))
+    } else if let Some(page) = page.downcast_ref::<view::VolumeDetailsPage>() {
+        page.volume()
+            .map(|volume| format!("volume:{}", volume.inner().name))
+    } else {
+        None
+    }
+}
+
+fn session_page(client: &model::Client, entry: &str) -> Option<gtk::Widget> {
+    let (kind, id) = entry.split_once(':')?;
+
+    match kind {
+        "container" => client
+            .container_list()
+            .get_container(id)
+            .map(|container| view::ContainerDetailsPage::from(&container).upcast()),
+        "container-log" => client
+            .container_list()
+            .get_container(id)
+            .map(|container| view::ContainerLogPage::from(&container).upcast()),
+        "pod" => client
+            .pod_list()
+            .get_pod(id)
+            .map(|pod| view::PodDetailsPage::from(&pod).upcast()),
+        "image" => client
+            .image_list()
+            .get_image(id)
+            .map(|image| view::ImageDetailsPage::from(&image).upcast()),
+        "volume" => client
+            .volume_list()
+            .get_volume(id)
+            .map(|volume| view::VolumeDetailsPage::from(&volume).upcast()),
+        _ => None,
+    }
+}
@@ -103,6 +103,8 @@ mod imp {
         ) -> glib::Propagation {
             if key == gdk::Key::Escape {
                 self.obj().enable_filter_mode(false);
+                self.connection_list_box
+                    .child_focus(gtk::DirectionType::TabForward);
             }
             // else if key == gdk::Key::KP_Enter {
             //     self.obj().activate_action(ACTION_SELECT, None).unwrap();
@@ -202,6 +204,11 @@ impl ConnectionChooserPage {
         self.enable_filter_mode(!self.imp().filter_button.is_active());
     }
 
+    pub(crate) fn focus_filter(&self) {
+        self.enable_filter_mode(true);
+        self.imp().filter_entry.grab_focus();
+    }
+
     pub(crate) fn enable_filter_mode(&self, enable: bool) {
         let imp = self.imp();
 
@@ -1,13 +1,19 @@
 use std::cell::OnceCell;
+use std::io;
+use std::os::unix::fs::FileTypeExt;
+use std::path::Path;
 
 use adw::prelude::*;
 use adw::subclass::prelude::*;
+use ashpd::WindowIdentifier;
+use ashpd::desktop::file_chooser::OpenFileRequest;
 use gettextrs::gettext;
 use glib::Properties;
 use glib::clone;
 use glib::closure;
 use gtk::CompositeTemplate;
 use gtk::gdk;
+use gtk::gio;
 use gtk::glib;
 
 use crate::model;
@@ -17,6 +23,7 @@ use crate::view;
 const ACTION_COPY_SOCKET_ACTIVATION_COMMAND: &str =
     "connection-creator-page.copy-socket-activation-command";
 const ACTION_SHOW_CUSTOM_INFO_DIALOG: &str = "connection-creation-page.show-custom-info-dialog";
+const ACTION_CHOOSE_UNIX_SOCKET: &str = "connection-creation-page.choose-unix-socket";
 const ACTION_TRY_CONNECT: &str = "connection-creation-page.try-connect";
 
 const ACTION_ABORT: &str = "connection-creation-page.abort";
@@ -76,6 +83,9 @@ mod imp {
             klass.install_action(ACTION_SHOW_CUSTOM_INFO_DIALOG, None, |widget, _, _| {
                 widget.show_custom_info_dialog();
             });
+            klass.install_action_async(ACTION_CHOOSE_UNIX_SOCKET, None, async |widget, _, _| {
+                widget.choose_unix_socket().await;
+            });
             klass.install_action_async(ACTION_TRY_CONNECT, None, async |widget, _, _| {
                 widget.try_connect().await;
             });
@@ -243,7 +253,54 @@ impl ConnectionCreationPage {
             .push_by_tag("custom-connection-info");
     }
 
+    pub(crate) async fn choose_unix_socket(&self) {
+        let request = OpenFileRequest::default()
+            .identifier(WindowIdentifier::from_native(&self.native().unwrap()).await)
+            .title(gettext("Select Unix Socket").as_str())
+            .modal(true);
+
+        utils::show_open_file_dialog(
+            request,
+            self,
+            clone!(
+                #[weak(rename_to = obj)]
+                self,
+                move |files| {
+                    let file = gio::File::for_uri(files.uris()[0].as_str());
+
+                    if let Some(path) = file.path() {
+                        glib::spawn_future_local(clone!(
+                            #[weak]
+                            obj,
+                            async move {
+                                match check_unix_socket(&path).await {
+                                    Ok(_) => {
+                                        obj.imp()
+                                            .custom_url_entry_row
+                                            .set_text(&format!("unix://{}", path.display()));
+                                    }
+                                    Err(e) => obj.on_error(&e),
+                                }
+                            }
+                        ));
+                    }
+                }
+            ),
+        )
+        .await;
+    }
+
     pub(crate) async fn try_connect(&self) {
+        let imp = self.imp();
+
+        if imp.custom_url_radio_button.is_active()
+            && let Some(path) = imp.custom_url_entry_row.text().strip_prefix("unix://")
+            && let Err(e) = check_unix_socket(Path::new(path)).await
+        {
+            self.on_error(&e);
+            return;
+        }
+
         if !view::show_ongoing_actions_warning_dialog(
             self,
             &self.connection_manager(),
@@ -252,8 +309,6 @@ impl ConnectionCreationPage {
             return;
         }
 
-        let imp = self.imp();
-
         let result = self
             .connection_manager()
             .try_connect(
@@ -302,3 +357,28 @@ impl ConnectionCreationPage {
         utils::show_error_toast(self, &gettext("Error"), msg);
     }
 }
+
+async fn check_unix_socket(path: &Path) -> Result<(), String> {
+    let metadata = std::fs::metadata(path).map_err(|e| match e.kind() {
+        io::ErrorKind::NotFound => gettext!("'{}' does not exist", path.display()),
+        io::ErrorKind::PermissionDenied => {
+            gettext!("Permission denied to access '{}'", path.display())
+        }
+        _ => e.to_string(),
+    })?;
+
+    if !metadata.file_type().is_socket() {
+        return Err(gettext!("'{}' is not a Unix socket", path.display()));
+    }
+
+    match gio::SocketClient::new()
+        .connect_future(&gio::UnixSocketAddress::new(path))
+        .await
+    {
+        Err(e) if e.matches(gio::IOErrorEnum::PermissionDenied) => Err(gettext!(
+            "Permission denied to connect to '{}'",
+            path.display()
+        )),
+        _ => Ok(()),
+    }
+}
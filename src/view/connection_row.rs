@@ -2,9 +2,11 @@ use adw::prelude::*;
 use adw::subclass::prelude::*;
 use gettextrs::gettext;
 use glib::Properties;
+use glib::clone;
 use glib::closure;
 use glib::subclass::InitializingObject;
 use gtk::CompositeTemplate;
+use gtk::gdk;
 use gtk::glib;
 
 use crate::model;
@@ -90,14 +92,19 @@ mod imp {
                 [
                     &is_remote_expr,
                     &connection_expr.chain_property::<model::Connection>("url"),
+                    &connection_expr.chain_property::<model::Connection>("socket-available"),
                 ],
-                closure!(|_: Self::Type, is_remote: bool, url: String| {
-                    if is_remote {
-                        url
-                    } else {
-                        gettext("Local connection")
+                closure!(
+                    |_: Self::Type, is_remote: bool, url: String, socket_available: bool| {
+                        if is_remote {
+                            url
+                        } else if socket_available {
+                            gettext("Local connection")
+                        } else {
+                            gettext("Local connection, socket not available")
+                        }
                     }
-                }),
+                ),
             )
             .bind(&*self.url_label, "label", Some(obj));
 
@@ -139,6 +146,8 @@ mod imp {
             self.color_bin
                 .style_context()
                 .add_provider(&self.css_provider, gtk::STYLE_PROVIDER_PRIORITY_APPLICATION);
+
+            obj.setup_reordering();
         }
 
         fn dispose(&self) {
@@ -177,6 +186,65 @@ glib::wrapper! {
         @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget;
 }
 
+impl ConnectionRow {
+    /// Allows reordering connections by dragging one row onto another.
+    fn setup_reordering(&self) {
+        let drag_source = gtk::DragSource::builder()
+            .actions(gdk::DragAction::MOVE)
+            .build();
+        drag_source.connect_prepare(clone!(
+            #[weak(rename_to = obj)]
+            self,
+            #[upgrade_or]
+            None,
+            move |_, _, _| {
+                obj.connection().map(|connection| {
+                    gdk::ContentProvider::for_value(&connection.uuid().to_value())
+                })
+            }
+        ));
+        drag_source.connect_drag_begin(clone!(
+            #[weak(rename_to = obj)]
+            self,
+            move |drag_source, _| {
+                drag_source.set_icon(Some(&gtk::WidgetPaintable::new(Some(&obj))), 0, 0);
+            }
+        ));
+        self.add_controller(drag_source);
+
+        let drop_target = gtk::DropTarget::new(String::static_type(), gdk::DragAction::MOVE);
+        drop_target.connect_drop(clone!(
+            #[weak(rename_to = obj)]
+            self,
+            #[upgrade_or]
+            false,
+            move |_, value, _, _| {
+                let Ok(uuid) = value.get::<String>() else {
+                    return false;
+                };
+                let Some(connection) = obj.connection() else {
+                    return false;
+                };
+                let Some(manager) = connection.manager() else {
+                    return false;
+                };
+
+                if uuid == connection.uuid() || manager.connection_by_uuid(&uuid).is_none() {
+                    return false;
+                }
+
+                let position = manager.position_by_uuid(&connection.uuid());
+                glib::spawn_future_local(async move {
+                    manager.move_connection(&uuid, position).await;
+                });
+
+                true
+            }
+        ));
+        self.add_controller(drop_target);
+    }
+}
+
 impl From<&model::Connection> for ConnectionRow {
     fn from(connection: &model::Connection) -> Self {
         glib::Object::builder()
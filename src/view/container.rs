@@ -2,6 +2,7 @@ use adw::prelude::*;
 use gettextrs::gettext;
 use glib::clone;
 use glib::closure;
+use gtk::gio;
 use gtk::glib;
 use gtk::glib::clone::Downgrade;
 
@@ -99,6 +100,22 @@ where
         dialog.set_default_response(Some("rename"));
         dialog.set_response_appearance("rename", adw::ResponseAppearance::Destructive);
 
+        let update_rename_enabled = clone!(
+            #[weak]
+            dialog,
+            #[weak]
+            container,
+            move |container_renamer: &view::ContainerRenamer| {
+                dialog.set_response_enabled(
+                    "rename",
+                    container_renamer.error().is_none()
+                        && container_renamer.new_name() != container.name(),
+                );
+            }
+        );
+        update_rename_enabled(&container_renamer);
+        container_renamer.connect_error_notify(update_rename_enabled);
+
         dialog.connect_response(
             Some("rename"),
             clone!(
@@ -131,6 +148,369 @@ where
     }
 }
 
+/// Signals that can be sent to a process by name, without the `SIG` prefix.
+const SIGNAL_NAMES: &[&str] = &[
+    "ABRT", "ALRM", "BUS", "CHLD", "CONT", "FPE", "HUP", "ILL", "INT", "IO", "IOT", "KILL", "PIPE",
+    "POLL", "PROF", "PWR", "QUIT", "SEGV", "STKFLT", "STOP", "SYS", "TERM", "TRAP", "TSTP", "TTIN",
+    "TTOU", "URG", "USR1", "USR2", "VTALRM", "WINCH", "XCPU", "XFSZ",
+];
+
+/// Normalizes a signal given by name (with or without the `SIG` prefix) or by number. Returns
+/// `None` if it is neither a known signal name nor a positive integer.
+fn parse_signal(signal: &str) -> Option<String> {
+    let signal = signal.trim();
+
+    if let Ok(number) = signal.parse::<u32>() {
+        return Some(number)
+            .filter(|number| *number > 0)
+            .map(|number| number.to_string());
+    }
+
+    let name = signal.to_uppercase();
+    let name = name.strip_prefix("SIG").unwrap_or(&name);
+
+    SIGNAL_NAMES.contains(&name).then(|| format!("SIG{name}"))
+}
+
+/// Asks for a signal and sends it to the container.
+pub(crate) fn send_signal_show_dialog<W>(widget: &W, container: Option<model::Container>)
+where
+    W: IsA<gtk::Widget> + Downgrade<Weak = glib::WeakRef<W>>,
+{
+    let Some(container) = container else {
+        return;
+    };
+
+    let entry_row = adw::EntryRow::builder()
+        .activates_default(true)
+        .title(gettext("Signal Name or Number"))
+        .text("SIGHUP")
+        .build();
+
+    let list_box = gtk::ListBox::builder()
+        .css_classes(vec!["boxed-list".to_string()])
+        .selection_mode(gtk::SelectionMode::None)
+        .build();
+    list_box.append(&entry_row);
+
+    let dialog = adw::AlertDialog::builder()
+        .heading(gettext("Send Signal"))
+        .body(gettext!(
+            // Translators: The "{}" is a placeholder for the container name.
+            "The signal is sent to the main process of container {}",
+            container.name(),
+        ))
+        .extra_child(&list_box)
+        .build();
+
+    dialog.add_responses(&[("cancel", &gettext("_Cancel")), ("send", &gettext("_Send"))]);
+    dialog.set_default_response(Some("send"));
+    dialog.set_response_appearance("send", adw::ResponseAppearance::Suggested);
+
+    entry_row.connect_changed(clone!(
+        #[weak]
+        dialog,
+        move |entry_row| {
+            let valid = parse_signal(&entry_row.text()).is_some();
+
+            dialog.set_response_enabled("send", valid);
+            if valid {
+                entry_row.remove_css_class("error");
+            } else {
+                entry_row.add_css_class("error");
+            }
+        }
+    ));
+
+    dialog.connect_response(
+        Some("send"),
+        clone!(
+            #[weak]
+            widget,
+            #[weak]
+            container,
+            #[weak]
+            entry_row,
+            move |_, _| {
+                let Some(signal) = parse_signal(&entry_row.text()) else {
+                    return;
+                };
+
+                container.send_signal(
+                    signal.clone(),
+                    clone!(
+                        #[weak]
+                        widget,
+                        #[weak]
+                        container,
+                        move |result| match result {
+                            Ok(_) => utils::show_toast(
+                                &widget,
+                                // Translators: The first "{}" is a signal, the second one is a container name.
+                                gettext!("Sent {} to container '{}'", signal, container.name()),
+                            ),
+                            Err(e) => utils::show_error_toast(
+                                &widget,
+                                &gettext("Error on sending signal"),
+                                &e.to_string(),
+                            ),
+                        }
+                    ),
+                );
+            }
+        ),
+    );
+
+    dialog.present(Some(widget));
+}
+
+/// Deletes the container after asking for confirmation, unless this is disabled in the
+/// preferences. Running containers are force deleted, which the dialog points out explicitly.
+pub(crate) fn delete_show_confirmation<W>(widget: &W, container: Option<model::Container>)
+where
+    W: IsA<gtk::Widget> + Downgrade<Weak = glib::WeakRef<W>>,
+{
+    let Some(container) = container else {
+        return;
+    };
+
+    let force = matches!(
+        container.status(),
+        model::ContainerStatus::Running
+            | model::ContainerStatus::Paused
+            | model::ContainerStatus::Restarting
+    );
+
+    if !utils::confirm_destructive_actions() {
+        delete(widget, &container, force, false);
+        return;
+    }
+
+    let volumes_row = adw::SwitchRow::builder()
+        .title(gettext("Remove Anonymous Volumes"))
+        .subtitle(gettext(
+            "Named volumes are kept, even if no other container uses them",
+        ))
+        .build();
+
+    let list_box = gtk::ListBox::builder()
+        .css_classes(vec!["boxed-list".to_string()])
+        .selection_mode(gtk::SelectionMode::None)
+        .build();
+    list_box.append(&volumes_row);
+
+    let dialog = adw::AlertDialog::builder()
+        .heading(gettext("Delete Container?"))
+        .body_use_markup(true)
+        .body(if force {
+            gettext!(
+                // Translators: The "{}" is a placeholder for the container name.
+                "Container <b>{}</b> is running and will be force-stopped and removed. All settings and all changes made within the container will be irreversibly lost.",
+                utils::escape(&container.name()),
+            )
+        } else {
+            gettext(
+                "All settings and all changes made within the container will be irreversibly lost",
+            )
+        })
+        .extra_child(&list_box)
+        .build();
+
+    dialog.add_responses(&[
+        ("cancel", &gettext("_Cancel")),
+        ("delete", &gettext("_Delete")),
+    ]);
+    dialog.set_default_response(Some("cancel"));
+    dialog.set_response_appearance("delete", adw::ResponseAppearance::Destructive);
+
+    dialog.choose(
+        widget,
+        gio::Cancellable::NONE,
+        clone!(
+            #[weak]
+            widget,
+            #[weak]
+            container,
+            move |response| {
+                if response == "delete" {
+                    delete(&widget, &container, force, volumes_row.is_active());
+                }
+            }
+        ),
+    );
+}
+
+fn delete<W>(widget: &W, container: &model::Container, force: bool, volumes: bool)
+where
+    W: IsA<gtk::Widget> + Downgrade<Weak = glib::WeakRef<W>>,
+{
+    let client = container
+        .container_list()
+        .and_then(|container_list| container_list.client());
+    let name = container.name();
+
+    container.delete(
+        force,
+        volumes,
+        clone!(
+            #[weak]
+            widget,
+            move |result| match result {
+                Ok(_) => {
+                    if volumes {
+                        // Let the removed anonymous volumes disappear.
+                        if let Some(client) = client {
+                            client.volume_list().refresh(|_| {});
+                        }
+                        utils::show_toast(
+                            &widget,
+                            gettext!("Deleted container '{}' and its anonymous volumes", name),
+                        );
+                    }
+                }
+                Err(e) => utils::show_error_toast(
+                    &widget,
+                    &gettext("Error on deleting container"),
+                    &e.to_string(),
+                ),
+            }
+        ),
+    );
+}
+
+/// Starts the container and opens its log afterwards if this is enabled in the preferences.
+pub(crate) fn start_and_show_log<W>(widget: &W, container: Option<model::Container>)
+where
+    W: IsA<gtk::Widget> + Downgrade<Weak = glib::WeakRef<W>>,
+{
+    if let Some(container) = container {
+        confirm_start(
+            widget,
+            &container,
+            clone!(
+                #[weak]
+                widget,
+                #[weak]
+                container,
+                move || container.start(clone!(
+                    #[weak]
+                    widget,
+                    #[weak]
+                    container,
+                    move |result| match result {
+                        Ok(_) => {
+                            show_log_after_start(&container);
+                        }
+                        Err(e) => utils::show_error_toast(
+                            &widget,
+                            &gettext("Error on starting container"),
+                            &e.to_string(),
+                        ),
+                    }
+                ))
+            ),
+        );
+    }
+}
+
+pub(crate) fn start<W>(widget: &W, container: Option<model::Container>)
+where
+    W: IsA<gtk::Widget> + Downgrade<Weak = glib::WeakRef<W>>,
+{
+    if let Some(container) = container {
+        confirm_start(
+            widget,
+            &container,
+            clone!(
+                #[weak]
+                widget,
+                #[weak]
+                container,
+                move || container.start(clone!(
+                    #[weak]
+                    widget,
+                    move |result| if let Err(e) = result {
+                        utils::show_error_toast(
+                            &widget,
+                            &gettext("Error on starting container"),
+                            &e.to_string(),
+                        );
+                    }
+                ))
+            ),
+        );
+    }
+}
+
+/// Calls `start` right away unless host ports of the container are already bound by other
+/// running containers. Podman would then fail with a cryptic error, so the conflicting
+/// containers are named first and the user can still decide to start anyway.
+pub(crate) fn confirm_start<W, F>(widget: &W, container: &model::Container, start: F)
+where
+    W: IsA<gtk::Widget>,
+    F: FnOnce() + 'static,
+{
+    let conflicts = container.port_conflicts();
+    if conflicts.is_empty() {
+        start();
+        return;
+    }
+
+    let dialog = adw::AlertDialog::builder()
+        .heading(gettext("Ports Already in Use"))
+        .body_use_markup(true)
+        .body(
+            conflicts
+                .iter()
+                .map(|(port, other)| {
+                    gettext!(
+                        // Translators: The placeholders are a port like "8080/tcp" and a container name.
+                        "Port <b>{}</b> is used by container <b>{}</b>",
+                        format!("{}/{}", port.host_port(), port.protocol()),
+                        utils::escape(&other.name()),
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join("\n"),
+        )
+        .build();
+
+    dialog.add_responses(&[
+        ("cancel", &gettext("_Cancel")),
+        ("start", &gettext("_Start Anyway")),
+    ]);
+    dialog.set_default_response(Some("cancel"));
+    dialog.set_response_appearance("start", adw::ResponseAppearance::Destructive);
+
+    dialog.choose(widget, gio::Cancellable::NONE, move |response| {
+        if response == "start" {
+            start();
+        }
+    });
+}
+
+/// Opens the log of a freshly started container in the main window if the user asked for it in
+/// the preferences. Returns whether the log has been opened.
+pub(crate) fn show_log_after_start(container: &model::Container) -> bool {
+    if !utils::PodsSettings::default().boolean("open-logs-after-start") {
+        return false;
+    }
+
+    gio::Application::default()
+        .unwrap()
+        .downcast::<crate::Application>()
+        .unwrap()
+        .main_window()
+        .navigation_view()
+        .push(
+            &adw::NavigationPage::builder()
+                .child(&view::ContainerLogPage::from(container))
+                .build(),
+        );
+
+    true
+}
+
 macro_rules! container_action {
     (fn $name:ident => $action:ident($($param:literal),*) => $error:tt) => {
         pub(crate) fn $name<W>(widget: &W, container: Option<crate::model::Container>)
@@ -153,10 +533,9 @@ macro_rules! container_action {
     };
 }
 
-container_action!(fn start => start() => { gettextrs::gettext("Error on starting container") });
 container_action!(fn stop => stop(false) => { gettextrs::gettext("Error on stopping container") });
 container_action!(fn kill => stop(true) => { gettextrs::gettext("Error on killing container") });
 container_action!(fn restart => restart(false) => { gettextrs::gettext("Error on restarting container") });
 container_action!(fn pause => pause() => { gettextrs::gettext("Error on pausing container") });
 container_action!(fn resume => resume() => { gettextrs::gettext("Error on resuming container") });
-container_action!(fn delete => delete(false) => { gettextrs::gettext("Error on deleting container") });
+container_action!(fn refresh => refresh() => { gettextrs::gettext("Error on refreshing container") });
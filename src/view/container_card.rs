@@ -111,7 +111,7 @@ mod imp {
 
             klass.install_action(ACTION_START_OR_RESUME, None, |widget, _, _| {
                 if widget.container().map(|c| c.can_start()).unwrap_or(false) {
-                    view::container::start(widget, widget.container());
+                    view::container::start_and_show_log(widget, widget.container());
                 } else {
                     view::container::resume(widget, widget.container());
                 }
@@ -196,6 +196,19 @@ mod imp {
                     move || obj.update_actions()
                 ),
             );
+            container_expr
+                .chain_property::<model::Container>("container-list")
+                .chain_property::<model::ContainerList>("client")
+                .chain_property::<model::Client>("connection")
+                .chain_property::<model::Connection>("read-only")
+                .watch(
+                    Some(obj),
+                    clone!(
+                        #[weak]
+                        obj,
+                        move || obj.update_actions()
+                    ),
+                );
             container_expr
                 .chain_property::<model::Container>("action-ongoing")
                 .watch(
@@ -289,20 +302,27 @@ mod imp {
                 }))
                 .bind(&*self.repo_label, "label", Some(obj));
 
-            status_expr
-                .chain_closure::<String>(closure!(
-                    |_: Self::Type, status: model::ContainerStatus| {
+            gtk::ClosureExpression::new::<String>(
+                [
+                    status_expr.upcast_ref(),
+                    container_list_expr
+                        .chain_property::<model::ContainerList>("stats-enabled")
+                        .upcast_ref(),
+                ],
+                closure!(
+                    |_: Self::Type, status: model::ContainerStatus, stats_enabled: bool| {
                         match status {
-                            model::ContainerStatus::Running => "running",
+                            model::ContainerStatus::Running if stats_enabled => "running",
                             _ => "not-running",
                         }
                     }
-                ))
-                .bind(
-                    &*self.resources_status_stack,
-                    "visible-child-name",
-                    Some(obj),
-                );
+                ),
+            )
+            .bind(
+                &*self.resources_status_stack,
+                "visible-child-name",
+                Some(obj),
+            );
 
             #[rustfmt::skip]
             obj.bind_stats_fraction(
@@ -438,7 +458,9 @@ mod imp {
             _: &gtk::EventControllerKey,
         ) -> glib::ControlFlow {
             match key {
-                gdk::Key::Return => {
+                gdk::Key::Return | gdk::Key::KP_Enter | gdk::Key::space | gdk::Key::KP_Space
+                    if self.obj().has_focus() =>
+                {
                     self.obj().activate();
                     glib::ControlFlow::Continue
                 }
@@ -554,24 +576,7 @@ impl ContainerCard {
     }
 
     pub(crate) fn delete(&self) {
-        let dialog = adw::AlertDialog::builder()
-            .heading(gettext("Delete Container?"))
-            .body_use_markup(true)
-            .body(gettext(
-                "All settings and all changes made within the container will be irreversibly lost",
-            ))
-            .build();
-
-        dialog.add_responses(&[
-            ("cancel", &gettext("_Cancel")),
-            ("confirm", &gettext("_Confirm")),
-        ]);
-        dialog.set_default_response(Some("cancel"));
-        dialog.set_response_appearance("confirm", adw::ResponseAppearance::Destructive);
-
-        if glib::MainContext::default().block_on(dialog.choose_future(self)) == "confirm" {
-            view::container::delete(self, self.container())
-        }
+        view::container::delete_show_confirmation(self, self.container());
     }
 
     fn bind_stats_fraction(&self, stats_expr: &gtk::Expression, progress_bar: &gtk::ProgressBar) {
@@ -690,7 +695,9 @@ impl ContainerCard {
                 .set_visible(!container.action_ongoing() && can_stop);
             imp.spinning_button.set_visible(
                 container.action_ongoing()
-                    || (!imp.start_or_resume_button.is_visible() && !imp.stop_button.is_visible()),
+                    || (!container.is_read_only()
+                        && !imp.start_or_resume_button.is_visible()
+                        && !imp.stop_button.is_visible()),
             );
 
             self.action_set_enabled(ACTION_START_OR_RESUME, can_start_or_resume);
@@ -699,6 +706,7 @@ impl ContainerCard {
             self.action_set_enabled(ACTION_RESTART, container.can_restart());
             self.action_set_enabled(ACTION_PAUSE, container.can_pause());
             self.action_set_enabled(ACTION_DELETE, container.can_delete());
+            self.action_set_enabled(ACTION_RENAME, !container.is_read_only());
         }
     }
 }
@@ -0,0 +1,307 @@
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+
+use adw::prelude::*;
+use adw::subclass::prelude::*;
+use gettextrs::gettext;
+use gettextrs::ngettext;
+use glib::Properties;
+use glib::clone;
+use gtk::CompositeTemplate;
+use gtk::glib;
+use serde_json::Value;
+
+use crate::model;
+use crate::podman;
+use crate::rt;
+use crate::utils;
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default, Properties, CompositeTemplate)]
+    #[properties(wrapper_type = super::ContainerChangesPage)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/view/container_changes_page.ui")]
+    pub(crate) struct ContainerChangesPage {
+        pub(super) changes: RefCell<Vec<Change>>,
+        pub(super) groups: RefCell<Vec<adw::PreferencesGroup>>,
+        #[property(get, set, construct_only, nullable)]
+        pub(super) container: glib::WeakRef<model::Container>,
+        /// Either "all" or the name of the kind of changes that are shown.
+        #[property(get, set = Self::set_kind_filter)]
+        pub(super) kind_filter: RefCell<String>,
+        #[template_child]
+        pub(super) kind_toggle_group: TemplateChild<adw::ToggleGroup>,
+        #[template_child]
+        pub(super) stack: TemplateChild<gtk::Stack>,
+        #[template_child]
+        pub(super) preferences_page: TemplateChild<adw::PreferencesPage>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for ContainerChangesPage {
+        const NAME: &'static str = "PdsContainerChangesPage";
+        type Type = super::ContainerChangesPage;
+        type ParentType = gtk::Widget;
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.bind_template();
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for ContainerChangesPage {
+        fn properties() -> &'static [glib::ParamSpec] {
+            Self::derived_properties()
+        }
+
+        fn set_property(&self, id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+            self.derived_set_property(id, value, pspec);
+        }
+
+        fn property(&self, id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            self.derived_property(id, pspec)
+        }
+
+        fn constructed(&self) {
+            self.parent_constructed();
+
+            let obj = &*self.obj();
+
+            self.kind_filter.replace("all".to_owned());
+            obj.bind_property("kind-filter", &*self.kind_toggle_group, "active-name")
+                .bidirectional()
+                .sync_create()
+                .build();
+
+            obj.load();
+        }
+
+        fn dispose(&self) {
+            utils::unparent_children(&*self.obj());
+        }
+    }
+
+    impl WidgetImpl for ContainerChangesPage {}
+
+    impl ContainerChangesPage {
+        pub(super) fn set_kind_filter(&self, value: String) {
+            let obj = &*self.obj();
+            if obj.kind_filter() == value {
+                return;
+            }
+
+            self.kind_filter.replace(value);
+            obj.update_groups();
+        }
+    }
+}
+
+glib::wrapper! {
+    pub(crate) struct ContainerChangesPage(ObjectSubclass<imp::ContainerChangesPage>)
+        @extends gtk::Widget,
+        @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget;
+}
+
+impl From<&model::Container> for ContainerChangesPage {
+    fn from(container: &model::Container) -> Self {
+        glib::Object::builder()
+            .property("container", container)
+            .build()
+    }
+}
+
+impl ContainerChangesPage {
+    fn load(&self) {
+        let Some(container) = self.container().as_ref().and_then(model::Container::api) else {
+            return;
+        };
+
+        rt::Promise::new(async move {
+            let changes = container
+                .changes(&podman::opts::ChangesOpts::default())
+                .await?;
+            serde_json::to_value(changes).map_err(anyhow::Error::from)
+        })
+        .defer(clone!(
+            #[weak(rename_to = obj)]
+            self,
+            move |result| match result {
+                Ok(changes) => {
+                    let mut changes = changes
+                        .as_array()
+                        .into_iter()
+                        .flatten()
+                        .filter_map(Change::from_value)
+                        .collect::<Vec<_>>();
+                    changes.sort_by(|a, b| a.path.cmp(&b.path));
+
+                    obj.imp().changes.replace(changes);
+                    obj.update_groups();
+                }
+                Err(e) => {
+                    log::error!("Error on retrieving file system changes: {e}");
+                    utils::show_error_toast(
+                        &obj,
+                        &gettext("Error on retrieving file system changes"),
+                        &e.to_string(),
+                    );
+                    utils::navigation_view(&obj).pop();
+                }
+            }
+        ));
+    }
+
+    /// Rebuilds one group per top level directory with the changes that match the kind filter.
+    fn update_groups(&self) {
+        let imp = self.imp();
+
+        imp.groups
+            .take()
+            .iter()
+            .for_each(|group| imp.preferences_page.remove(group));
+
+        let kind_filter = self.kind_filter();
+        let changes = imp.changes.borrow();
+
+        let mut by_directory = BTreeMap::<&str, Vec<&Change>>::new();
+        changes
+            .iter()
+            .filter(|change| kind_filter == "all" || change.kind.name() == kind_filter)
+            .for_each(|change| {
+                by_directory
+                    .entry(top_level_directory(&change.path))
+                    .or_default()
+                    .push(change);
+            });
+
+        let groups = by_directory
+            .into_iter()
+            .map(|(directory, changes)| {
+                let list_box = gtk::ListBox::builder()
+                    .css_classes(["boxed-list"])
+                    .selection_mode(gtk::SelectionMode::None)
+                    .build();
+                changes
+                    .iter()
+                    .for_each(|change| list_box.append(&row(change)));
+
+                let group = adw::PreferencesGroup::builder()
+                    .title(directory)
+                    .description(ngettext!(
+                        "{} change",
+                        "{} changes",
+                        changes.len() as u32,
+                        changes.len()
+                    ))
+                    .build();
+                group.add(&list_box);
+                imp.preferences_page.add(&group);
+
+                group
+            })
+            .collect::<Vec<_>>();
+
+        imp.stack.set_visible_child_name(if changes.is_empty() {
+            "empty"
+        } else if groups.is_empty() {
+            "no-matches"
+        } else {
+            "changes"
+        });
+
+        imp.groups.replace(groups);
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Kind {
+    Changed,
+    Added,
+    Deleted,
+}
+
+impl Kind {
+    /// The kinds as they are encoded by Podman.
+    fn from_podman(kind: u64) -> Option<Self> {
+        match kind {
+            0 => Some(Self::Changed),
+            1 => Some(Self::Added),
+            2 => Some(Self::Deleted),
+            _ => None,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Changed => "changed",
+            Self::Added => "added",
+            Self::Deleted => "deleted",
+        }
+    }
+
+    fn icon_name(&self) -> &'static str {
+        match self {
+            Self::Changed => "document-edit-symbolic",
+            Self::Added => "list-add-symbolic",
+            Self::Deleted => "list-remove-symbolic",
+        }
+    }
+
+    fn css_class(&self) -> &'static str {
+        match self {
+            Self::Changed => "warning",
+            Self::Added => "success",
+            Self::Deleted => "error",
+        }
+    }
+
+    fn tooltip(&self) -> String {
+        match self {
+            Self::Changed => gettext("Changed"),
+            Self::Added => gettext("Added"),
+            Self::Deleted => gettext("Deleted"),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Change {
+    kind: Kind,
+    path: String,
+}
+
+impl Change {
+    fn from_value(value: &Value) -> Option<Self> {
+        Some(Self {
+            kind: Kind::from_podman(value["Kind"].as_u64()?)?,
+            path: value["Path"].as_str()?.to_owned(),
+        })
+    }
+}
+
+/// Paths are absolute, so "/tmp/foo/bar" belongs to "/tmp".
+fn top_level_directory(path: &str) -> &str {
+    path.get(1..)
+        .and_then(|rest| rest.find('/'))
+        .map_or(path, |index| &path[..index + 1])
+}
+
+fn row(change: &Change) -> adw::ActionRow {
+    let row = adw::ActionRow::builder()
+        .title(glib::markup_escape_text(&change.path))
+        .title_selectable(true)
+        .build();
+    row.add_prefix(
+        &gtk::Image::builder()
+            .icon_name(change.kind.icon_name())
+            .css_classes([change.kind.css_class()])
+            .tooltip_text(change.kind.tooltip())
+            .build(),
+    );
+
+    row
+}
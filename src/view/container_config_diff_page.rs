@@ -0,0 +1,386 @@
+use std::ops::Deref;
+
+use adw::prelude::*;
+use adw::subclass::prelude::*;
+use futures::future;
+use gettextrs::gettext;
+use gettextrs::ngettext;
+use glib::Properties;
+use glib::clone;
+use gtk::CompositeTemplate;
+use gtk::glib;
+use gtk::pango;
+use indexmap::IndexMap;
+use serde_json::Value;
+
+use crate::model;
+use crate::podman;
+use crate::rt;
+use crate::utils;
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default, Properties, CompositeTemplate)]
+    #[properties(wrapper_type = super::ContainerConfigDiffPage)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/view/container_config_diff_page.ui")]
+    pub(crate) struct ContainerConfigDiffPage {
+        #[property(get, set, construct_only, nullable)]
+        pub(super) container: glib::WeakRef<model::Container>,
+        #[template_child]
+        pub(super) stack: TemplateChild<gtk::Stack>,
+        #[template_child]
+        pub(super) command_group: TemplateChild<adw::PreferencesGroup>,
+        #[template_child]
+        pub(super) command_list_box: TemplateChild<gtk::ListBox>,
+        #[template_child]
+        pub(super) env_group: TemplateChild<adw::PreferencesGroup>,
+        #[template_child]
+        pub(super) env_list_box: TemplateChild<gtk::ListBox>,
+        #[template_child]
+        pub(super) ports_group: TemplateChild<adw::PreferencesGroup>,
+        #[template_child]
+        pub(super) ports_list_box: TemplateChild<gtk::ListBox>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for ContainerConfigDiffPage {
+        const NAME: &'static str = "PdsContainerConfigDiffPage";
+        type Type = super::ContainerConfigDiffPage;
+        type ParentType = gtk::Widget;
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.bind_template();
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for ContainerConfigDiffPage {
+        fn properties() -> &'static [glib::ParamSpec] {
+            Self::derived_properties()
+        }
+
+        fn set_property(&self, id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+            self.derived_set_property(id, value, pspec);
+        }
+
+        fn property(&self, id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            self.derived_property(id, pspec)
+        }
+
+        fn constructed(&self) {
+            self.parent_constructed();
+            self.obj().load();
+        }
+
+        fn dispose(&self) {
+            utils::unparent_children(&*self.obj());
+        }
+    }
+
+    impl WidgetImpl for ContainerConfigDiffPage {}
+}
+
+glib::wrapper! {
+    pub(crate) struct ContainerConfigDiffPage(ObjectSubclass<imp::ContainerConfigDiffPage>)
+        @extends gtk::Widget,
+        @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget;
+}
+
+impl From<&model::Container> for ContainerConfigDiffPage {
+    fn from(container: &model::Container) -> Self {
+        glib::Object::builder()
+            .property("container", container)
+            .build()
+    }
+}
+
+impl ContainerConfigDiffPage {
+    fn load(&self) {
+        let Some(container) = self.container() else {
+            return;
+        };
+        let Some(client) = container
+            .container_list()
+            .as_ref()
+            .and_then(model::ContainerList::client)
+        else {
+            return;
+        };
+
+        let container_api = container.api().unwrap();
+        let image_api =
+            podman::api::Image::new(client.podman().deref().clone(), container.image_id());
+
+        rt::Promise::new(async move {
+            let (container_data, image_data) =
+                future::try_join(container_api.inspect(), image_api.inspect()).await?;
+
+            Ok::<_, anyhow::Error>((
+                serde_json::to_value(container_data)?,
+                serde_json::to_value(image_data)?,
+            ))
+        })
+        .defer(clone!(
+            #[weak(rename_to = obj)]
+            self,
+            move |result| match result {
+                Ok((container_data, image_data)) => {
+                    obj.init(&container_data, &image_data);
+                }
+                Err(e) => {
+                    log::error!("Error on inspecting container and image: {e}");
+                    utils::show_error_toast(
+                        &obj,
+                        &gettext("Error on comparing container and image configuration"),
+                        &e.to_string(),
+                    );
+                    utils::navigation_view(&obj).pop();
+                }
+            }
+        ));
+    }
+
+    fn init(&self, container_data: &Value, image_data: &Value) {
+        let imp = self.imp();
+
+        let container_config = &container_data["Config"];
+        let image_config = &image_data["Config"];
+
+        fill_group(
+            &imp.command_group,
+            &imp.command_list_box,
+            [
+                Entry {
+                    key: gettext("Entrypoint"),
+                    image: command(&image_config["Entrypoint"]),
+                    container: command(&container_config["Entrypoint"]),
+                },
+                Entry {
+                    key: gettext("Command"),
+                    image: command(&image_config["Cmd"]),
+                    container: command(&container_config["Cmd"]),
+                },
+            ],
+        );
+
+        let image_env = env(&image_config["Env"]);
+        let container_env = env(&container_config["Env"]);
+        fill_group(
+            &imp.env_group,
+            &imp.env_list_box,
+            image_env
+                .keys()
+                .chain(
+                    container_env
+                        .keys()
+                        .filter(|key| !image_env.contains_key(*key)),
+                )
+                .map(|key| Entry {
+                    key: key.to_owned(),
+                    image: image_env.get(key).cloned(),
+                    container: container_env.get(key).cloned(),
+                }),
+        );
+
+        let exposed_ports = image_config["ExposedPorts"]
+            .as_object()
+            .map(|ports| ports.keys().cloned().collect::<Vec<_>>())
+            .unwrap_or_default();
+        let port_bindings = port_bindings(&container_data["HostConfig"]["PortBindings"]);
+        fill_group(
+            &imp.ports_group,
+            &imp.ports_list_box,
+            exposed_ports
+                .iter()
+                .chain(
+                    port_bindings
+                        .keys()
+                        .filter(|port| !exposed_ports.contains(*port)),
+                )
+                .map(|port| Entry {
+                    key: port.to_owned(),
+                    // Translators: The image declares this port as exposed.
+                    image: exposed_ports.contains(port).then(|| gettext("Exposed")),
+                    container: port_bindings.get(port).cloned(),
+                }),
+        );
+
+        imp.stack.set_visible_child_name("loaded");
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Drift {
+    Unchanged,
+    Overridden,
+    Added,
+    Removed,
+}
+
+impl Drift {
+    fn css_class(&self) -> &'static str {
+        match self {
+            Self::Unchanged => "dim-label",
+            Self::Overridden => "warning",
+            Self::Added => "success",
+            Self::Removed => "error",
+        }
+    }
+}
+
+struct Entry {
+    key: String,
+    image: Option<String>,
+    container: Option<String>,
+}
+
+impl Entry {
+    fn drift(&self) -> Drift {
+        match (&self.image, &self.container) {
+            (Some(image), Some(container)) if image != container => Drift::Overridden,
+            (None, Some(_)) => Drift::Added,
+            (Some(_), None) => Drift::Removed,
+            _ => Drift::Unchanged,
+        }
+    }
+}
+
+fn fill_group(
+    group: &adw::PreferencesGroup,
+    list_box: &gtk::ListBox,
+    entries: impl IntoIterator<Item = Entry>,
+) {
+    let mut total = 0;
+    let mut drifted = 0_u32;
+
+    entries.into_iter().for_each(|entry| {
+        let drift = entry.drift();
+
+        total += 1;
+        if drift != Drift::Unchanged {
+            drifted += 1;
+        }
+
+        list_box.append(&row(&entry, drift));
+    });
+
+    group.set_visible(total > 0);
+    group.set_description(Some(&if drifted > 0 {
+        ngettext!(
+            "{} value differs from the image",
+            "{} values differ from the image",
+            drifted,
+            drifted
+        )
+    } else {
+        gettext("Matches the image")
+    }));
+}
+
+fn row(entry: &Entry, drift: Drift) -> gtk::ListBoxRow {
+    let value_label = |value: Option<&str>, css_class: &str| {
+        gtk::Label::builder()
+            .label(utils::format_option(value))
+            .css_classes(vec![css_class.to_string()])
+            .hexpand(true)
+            .selectable(true)
+            .wrap(true)
+            .wrap_mode(pango::WrapMode::WordChar)
+            .xalign(0.0)
+            .build()
+    };
+
+    let values_box = gtk::Box::builder().homogeneous(true).spacing(12).build();
+    values_box.append(&value_label(entry.image.as_deref(), "dim-label"));
+    values_box.append(&value_label(entry.container.as_deref(), drift.css_class()));
+
+    let box_ = gtk::Box::builder()
+        .orientation(gtk::Orientation::Vertical)
+        .spacing(3)
+        .margin_top(9)
+        .margin_end(12)
+        .margin_bottom(9)
+        .margin_start(12)
+        .build();
+    box_.append(
+        &gtk::Label::builder()
+            .label(&entry.key)
+            .css_classes(vec!["heading".to_string()])
+            .selectable(true)
+            .wrap(true)
+            .wrap_mode(pango::WrapMode::WordChar)
+            .xalign(0.0)
+            .build(),
+    );
+    box_.append(&values_box);
+
+    gtk::ListBoxRow::builder()
+        .activatable(false)
+        .child(&box_)
+        .build()
+}
+
+fn command(value: &Value) -> Option<String> {
+    match value {
+        Value::Array(args) => {
+            utils::format_iter_or_none(args.iter().filter_map(Value::as_str), " ")
+        }
+        Value::String(command) if !command.is_empty() => Some(command.to_owned()),
+        _ => None,
+    }
+}
+
+fn env(value: &Value) -> IndexMap<String, String> {
+    value
+        .as_array()
+        .map(|vars| {
+            vars.iter()
+                .filter_map(Value::as_str)
+                .map(|var| match var.split_once('=') {
+                    Some((key, value)) => (key.to_owned(), value.to_owned()),
+                    None => (var.to_owned(), String::new()),
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn port_bindings(value: &Value) -> IndexMap<String, String> {
+    value
+        .as_object()
+        .map(|bindings| {
+            bindings
+                .iter()
+                .map(|(port, hosts)| {
+                    let hosts = hosts
+                        .as_array()
+                        .map(|hosts| {
+                            hosts
+                                .iter()
+                                .map(|host| {
+                                    format!(
+                                        "{}:{}",
+                                        host["HostIp"]
+                                            .as_str()
+                                            .filter(|ip| !ip.is_empty())
+                                            .unwrap_or("0.0.0.0"),
+                                        host["HostPort"].as_str().unwrap_or("0")
+                                    )
+                                })
+                                .collect::<Vec<_>>()
+                        })
+                        .unwrap_or_default();
+
+                    (
+                        port.to_owned(),
+                        utils::format_iter(hosts.iter().map(String::as_str), ", "),
+                    )
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
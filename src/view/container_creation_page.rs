@@ -13,6 +13,7 @@ use gtk::glib;
 
 use crate::model;
 use crate::podman;
+use crate::rt;
 use crate::utils;
 use crate::view;
 use crate::widget;
@@ -24,8 +25,71 @@ const ACTION_ADD_PORT_MAPPING: &str = "container-creation-page.add-port-mapping"
 const ACTION_ADD_VOLUME: &str = "container-creation-page.add-volume";
 const ACTION_ADD_ENV_VAR: &str = "container-creation-page.add-env-var";
 const ACTION_ADD_LABEL: &str = "container-creation-page.add-label";
+const ACTION_ADD_HOST: &str = "container-creation-page.add-host";
 const ACTION_CREATE_AND_RUN: &str = "container-creation-page.create-and-run";
 const ACTION_CREATE: &str = "container-creation-page.create";
+const ACTION_SAVE_TEMPLATE: &str = "container-creation-page.save-template";
+const ACTION_SHOW_TEMPLATES: &str = "container-creation-page.show-templates";
+
+/// The network modes in the order of the network mode combo row.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum NetworkMode {
+    Default,
+    Bridge,
+    Host,
+    None,
+    Container,
+    Network,
+}
+
+impl From<u32> for NetworkMode {
+    fn from(position: u32) -> Self {
+        match position {
+            1 => Self::Bridge,
+            2 => Self::Host,
+            3 => Self::None,
+            4 => Self::Container,
+            5 => Self::Network,
+            _ => Self::Default,
+        }
+    }
+}
+
+impl From<NetworkMode> for u32 {
+    fn from(mode: NetworkMode) -> Self {
+        mode as u32
+    }
+}
+
+impl NetworkMode {
+    /// The name under which the mode is stored in templates.
+    fn name(self) -> &'static str {
+        match self {
+            Self::Default => "default",
+            Self::Bridge => "bridge",
+            Self::Host => "host",
+            Self::None => "none",
+            Self::Container => "container",
+            Self::Network => "network",
+        }
+    }
+
+    fn from_name(name: &str) -> Self {
+        match name {
+            "bridge" => Self::Bridge,
+            "host" => Self::Host,
+            "none" => Self::None,
+            "container" => Self::Container,
+            "network" => Self::Network,
+            _ => Self::Default,
+        }
+    }
+
+    /// Whether ports can be published, which requires the container to have its own network.
+    fn publishes_ports(self) -> bool {
+        matches!(self, Self::Default | Self::Bridge | Self::Network)
+    }
+}
 
 mod imp {
     use super::*;
@@ -39,6 +103,7 @@ mod imp {
         pub(super) volumes: OnceCell<gio::ListStore>,
         pub(super) env_vars: OnceCell<gio::ListStore>,
         pub(super) labels: OnceCell<gio::ListStore>,
+        pub(super) hosts: OnceCell<gio::ListStore>,
         pub(super) command_row_handler:
             RefCell<Option<(glib::SignalHandlerId, glib::WeakRef<model::Image>)>>,
         #[property(get = Self::client, set, construct)]
@@ -76,6 +141,16 @@ mod imp {
         #[template_child]
         pub(super) mem_drop_down: TemplateChild<gtk::DropDown>,
         #[template_child]
+        pub(super) network_preferences_group: TemplateChild<adw::PreferencesGroup>,
+        #[template_child]
+        pub(super) network_mode_combo_row: TemplateChild<adw::ComboRow>,
+        #[template_child]
+        pub(super) network_container_combo_row: TemplateChild<adw::ComboRow>,
+        #[template_child]
+        pub(super) network_name_combo_row: TemplateChild<adw::ComboRow>,
+        #[template_child]
+        pub(super) network_names: TemplateChild<gtk::StringList>,
+        #[template_child]
         pub(super) port_mapping_preferences_group: TemplateChild<adw::PreferencesGroup>,
         #[template_child]
         pub(super) port_mapping_list_box: TemplateChild<gtk::ListBox>,
@@ -86,6 +161,10 @@ mod imp {
         #[template_child]
         pub(super) labels_list_box: TemplateChild<gtk::ListBox>,
         #[template_child]
+        pub(super) hosts_preferences_group: TemplateChild<adw::PreferencesGroup>,
+        #[template_child]
+        pub(super) hosts_list_box: TemplateChild<gtk::ListBox>,
+        #[template_child]
         pub(super) health_check_command_entry_row: TemplateChild<adw::EntryRow>,
         #[template_child]
         pub(super) health_check_interval_value: TemplateChild<gtk::Adjustment>,
@@ -128,12 +207,21 @@ mod imp {
             klass.install_action(ACTION_ADD_LABEL, None, |widget, _, _| {
                 widget.add_label();
             });
+            klass.install_action(ACTION_ADD_HOST, None, |widget, _, _| {
+                widget.add_host();
+            });
             klass.install_action(ACTION_CREATE_AND_RUN, None, |widget, _, _| {
                 widget.finish(true);
             });
             klass.install_action(ACTION_CREATE, None, |widget, _, _| {
                 widget.finish(false);
             });
+            klass.install_action_async(ACTION_SAVE_TEMPLATE, None, async |widget, _, _| {
+                widget.save_template().await;
+            });
+            klass.install_action(ACTION_SHOW_TEMPLATES, None, |widget, _, _| {
+                widget.show_templates();
+            });
         }
 
         fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
@@ -162,6 +250,22 @@ mod imp {
             self.image_selection_combo_row
                 .set_client(obj.client().as_ref());
 
+            self.name_entry_row.set_name_taken_func(clone!(
+                #[weak]
+                obj,
+                #[upgrade_or]
+                false,
+                move |name| {
+                    obj.client().is_some_and(|client| {
+                        client
+                            .container_list()
+                            .iter::<model::Container>()
+                            .map(Result::unwrap)
+                            .any(|container| container.name() == name)
+                    })
+                }
+            ));
+
             let pod_expr = Self::Type::this_expression("pod");
 
             pod_expr
@@ -170,15 +274,23 @@ mod imp {
                     .unwrap_or_default()))
                 .bind(&self.pod_row.get(), "subtitle", Some(obj));
 
-            pod_expr
-                .chain_closure::<bool>(closure!(|_: Self::Type, pod: Option<&model::Pod>| {
-                    pod.is_none()
-                }))
-                .bind(
-                    &self.port_mapping_preferences_group.get(),
-                    "visible",
-                    Some(obj),
-                );
+            let no_pod_expr =
+                pod_expr.chain_closure::<bool>(closure!(
+                    |_: Self::Type, pod: Option<&model::Pod>| { pod.is_none() }
+                ));
+            // Containers of a pod always share the network of the pod.
+            no_pod_expr.bind(&self.network_preferences_group.get(), "visible", Some(obj));
+            // The hosts file of a pod is shared by all its containers.
+            no_pod_expr.bind(&self.hosts_preferences_group.get(), "visible", Some(obj));
+
+            if let Some(client) = obj.client() {
+                self.network_container_combo_row
+                    .set_expression(Some(model::Container::this_expression("name")));
+                self.network_container_combo_row
+                    .set_model(Some(&client.container_list()));
+            }
+            obj.load_networks();
+            obj.update_network_rows();
 
             if let Some(image) = obj.image() {
                 self.image_selection_combo_row.set_image(Some(image));
@@ -238,6 +350,32 @@ mod imp {
                 ACTION_ADD_LABEL,
                 &gettext("Add Label"),
             );
+
+            bind_model(
+                &self.hosts_list_box,
+                self.hosts(),
+                |item| {
+                    let host = item.downcast_ref::<model::KeyVal>().unwrap();
+                    let row =
+                        view::KeyValRow::new(&gettext("Hostname"), &gettext("IP Address"), host);
+
+                    row.set_value_error(!is_valid_host(host));
+                    host.connect_value_notify(clone!(
+                        #[weak]
+                        row,
+                        move |host| row.set_value_error(!is_valid_host(host))
+                    ));
+
+                    row.upcast()
+                },
+                ACTION_ADD_HOST,
+                &gettext("Add Host"),
+            );
+            self.hosts().connect_items_changed(clone!(
+                #[weak]
+                obj,
+                move |_, _, _, _| obj.update_create_actions()
+            ));
         }
 
         fn dispose(&self) {
@@ -274,11 +412,33 @@ mod imp {
     impl ContainerCreationPage {
         #[template_callback]
         fn on_name_entry_row_notify_text(&self) {
-            let enabled = !self.name_entry_row.text().is_empty();
+            self.obj().update_create_actions();
+        }
 
-            let obj = &*self.obj();
-            obj.action_set_enabled(ACTION_CREATE_AND_RUN, enabled);
-            obj.action_set_enabled(ACTION_CREATE, enabled);
+        #[template_callback]
+        fn on_health_check_command_entry_row_notify_text(&self) {
+            if self.health_check_test().is_some() {
+                self.health_check_command_entry_row
+                    .remove_css_class("error");
+            } else {
+                self.health_check_command_entry_row.add_css_class("error");
+            }
+            self.obj().update_create_actions();
+        }
+
+        /// Returns the arguments of the health check command, which may be empty if no health
+        /// check is defined, or `None` if the command can't be parsed.
+        pub(super) fn health_check_test(&self) -> Option<Vec<String>> {
+            let command = self.health_check_command_entry_row.text();
+            if command.trim().is_empty() {
+                return Some(Vec::new());
+            }
+
+            glib::shell_parse_argv(command.as_str()).ok().map(|argv| {
+                argv.into_iter()
+                    .map(|arg| arg.to_string_lossy().into_owned())
+                    .collect()
+            })
         }
 
         #[template_callback]
@@ -286,6 +446,29 @@ mod imp {
             self.obj().update_data();
         }
 
+        #[template_callback]
+        fn on_network_mode_combo_row_notify_selected(&self) {
+            let obj = &*self.obj();
+            obj.update_network_rows();
+            obj.update_create_actions();
+        }
+
+        pub(super) fn network_mode(&self) -> NetworkMode {
+            NetworkMode::from(self.network_mode_combo_row.selected())
+        }
+
+        pub(super) fn network_container(&self) -> Option<model::Container> {
+            self.network_container_combo_row
+                .selected_item()
+                .and_downcast::<model::Container>()
+        }
+
+        pub(super) fn network_name(&self) -> Option<String> {
+            self.network_names
+                .string(self.network_name_combo_row.selected())
+                .map(String::from)
+        }
+
         pub(super) fn cmd_args(&self) -> &gio::ListStore {
             self.cmd_args
                 .get_or_init(gio::ListStore::new::<model::Value>)
@@ -311,6 +494,10 @@ mod imp {
                 .get_or_init(gio::ListStore::new::<model::KeyVal>)
         }
 
+        pub(super) fn hosts(&self) -> &gio::ListStore {
+            self.hosts.get_or_init(gio::ListStore::new::<model::KeyVal>)
+        }
+
         pub(super) fn client(&self) -> Option<model::Client> {
             self.client
                 .upgrade()
@@ -349,6 +536,8 @@ mod imp {
 
             self.pod.set(value);
             obj.notify_pod();
+            obj.update_network_rows();
+            obj.update_create_actions();
         }
     }
 }
@@ -475,6 +664,358 @@ impl ContainerCreationPage {
         self.set_pod(Option::<model::Pod>::None);
     }
 
+    fn load_networks(&self) {
+        let Some(client) = self.client() else {
+            return;
+        };
+
+        rt::Promise::new({
+            let podman = client.podman();
+            async move {
+                podman
+                    .networks()
+                    .list(&podman::opts::NetworkListOpts::default())
+                    .await
+            }
+        })
+        .defer(clone!(
+            #[weak(rename_to = obj)]
+            self,
+            move |result| match result {
+                Ok(networks) => {
+                    let mut names = networks
+                        .into_iter()
+                        .filter_map(|network| network.name)
+                        .collect::<Vec<_>>();
+                    names.sort();
+
+                    let network_names = &obj.imp().network_names;
+                    network_names.splice(
+                        0,
+                        network_names.n_items(),
+                        &names.iter().map(String::as_str).collect::<Vec<_>>(),
+                    );
+                    obj.update_create_actions();
+                }
+                Err(e) => log::warn!("Error on retrieving networks: {e}"),
+            }
+        ));
+    }
+
+    fn update_network_rows(&self) {
+        let imp = self.imp();
+        let mode = imp.network_mode();
+
+        imp.network_container_combo_row
+            .set_visible(mode == NetworkMode::Container);
+        imp.network_name_combo_row
+            .set_visible(mode == NetworkMode::Network);
+        imp.port_mapping_preferences_group
+            .set_visible(self.pod().is_none() && mode.publishes_ports());
+    }
+
+    async fn save_template(&self) {
+        let Some(name) = view::ask_template_name(self, &gettext("Save as Template"), "").await
+        else {
+            return;
+        };
+
+        let mut templates = match model::load_container_templates() {
+            Ok(templates) => templates,
+            Err(e) => {
+                log::error!("Error on loading container templates: {e}");
+                utils::show_error_toast(self, &gettext("Error on saving template"), &e.to_string());
+                return;
+            }
+        };
+
+        if templates.contains_key(&name) {
+            let dialog = adw::AlertDialog::builder()
+                .heading(gettext("Replace Template?"))
+                .body(gettext!(
+                    "A template named '{}' already exists. Replacing it will overwrite its configuration.",
+                    name
+                ))
+                .build();
+
+            dialog.add_responses(&[
+                ("cancel", &gettext("_Cancel")),
+                ("replace", &gettext("_Replace")),
+            ]);
+            dialog.set_default_response(Some("cancel"));
+            dialog.set_response_appearance("replace", adw::ResponseAppearance::Destructive);
+
+            if dialog.choose_future(self).await != "replace" {
+                return;
+            }
+        }
+
+        templates.insert(name.clone(), self.template());
+
+        match model::save_container_templates(&templates).await {
+            Ok(_) => utils::show_toast(self, gettext!("Template '{}' saved", name)),
+            Err(e) => {
+                utils::show_error_toast(self, &gettext("Error on saving template"), &e.to_string())
+            }
+        }
+    }
+
+    fn show_templates(&self) {
+        let templates = match model::load_container_templates() {
+            Ok(templates) => templates,
+            Err(e) => {
+                log::error!("Error on loading container templates: {e}");
+                utils::show_error_toast(
+                    self,
+                    &gettext("Error on loading templates"),
+                    &e.to_string(),
+                );
+                return;
+            }
+        };
+
+        let page = view::ContainerTemplatesPage::from(templates);
+        page.connect_template_selected(clone!(
+            #[weak(rename_to = obj)]
+            self,
+            move |page, name| {
+                if let Some(template) = page.template(&name) {
+                    obj.apply_template(&template);
+                }
+                obj.imp().navigation_view.pop();
+            }
+        ));
+
+        self.imp()
+            .navigation_view
+            .push(&adw::NavigationPage::builder().child(&page).build());
+    }
+
+    fn template(&self) -> model::ContainerTemplate {
+        let imp = self.imp();
+
+        let healthcheck_cmd = imp.health_check_command_entry_row.text();
+
+        model::ContainerTemplate {
+            image: match imp.image_selection_combo_row.mode() {
+                view::ImageSelectionMode::Unset => None,
+                _ => imp
+                    .image_selection_combo_row
+                    .subtitle()
+                    .map(|subtitle| subtitle.to_string()),
+            },
+            pull_latest_image: imp.pull_latest_image_switch_row.is_active(),
+            command: imp.command_entry_row.text().to_string(),
+            command_args: imp
+                .cmd_args()
+                .iter::<model::Value>()
+                .map(Result::unwrap)
+                .map(|value| value.value())
+                .collect(),
+            terminal: imp.terminal_switch_row.is_active(),
+            privileged: imp.privileged_switch_row.is_active(),
+            network_mode: imp.network_mode().name().to_owned(),
+            network_container: match imp.network_mode() {
+                NetworkMode::Container => imp.network_container().map(|container| container.name()),
+                _ => None,
+            },
+            network_name: match imp.network_mode() {
+                NetworkMode::Network => imp.network_name(),
+                _ => None,
+            },
+            memory_limit: imp
+                .memory_switch
+                .is_active()
+                .then(|| model::ContainerMemoryLimit {
+                    value: imp.mem_value.value(),
+                    unit: imp.mem_drop_down.selected(),
+                }),
+            port_mappings: imp
+                .port_mappings()
+                .iter::<model::PortMapping>()
+                .map(Result::unwrap)
+                .map(|port_mapping| model::ContainerPortMappingTemplate {
+                    host_port: port_mapping.host_port(),
+                    container_port: port_mapping.container_port(),
+                    protocol: port_mapping.protocol().to_string(),
+                })
+                .collect(),
+            mounts: imp
+                .volumes()
+                .iter::<model::Mount>()
+                .map(Result::unwrap)
+                .map(|mount| {
+                    let volume = mount.mount_type() == model::MountType::Volume;
+                    model::ContainerMountTemplate {
+                        volume,
+                        source: if volume {
+                            mount
+                                .volume()
+                                .map(|volume| volume.inner().name.clone())
+                                .unwrap_or_default()
+                        } else {
+                            mount.host_path()
+                        },
+                        destination: mount.container_path(),
+                        writable: mount.writable(),
+                        selinux: mount.selinux().to_string(),
+                    }
+                })
+                .collect(),
+            env_vars: imp
+                .env_vars()
+                .iter::<model::KeyVal>()
+                .map(Result::unwrap)
+                .map(|entry| (entry.key(), entry.value()))
+                .collect(),
+            labels: imp
+                .labels()
+                .iter::<model::KeyVal>()
+                .map(Result::unwrap)
+                .map(|entry| (entry.key(), entry.value()))
+                .collect(),
+            hosts: imp
+                .hosts()
+                .iter::<model::KeyVal>()
+                .map(Result::unwrap)
+                .map(|entry| (entry.key(), entry.value()))
+                .collect(),
+            health_check: (!healthcheck_cmd.is_empty()).then(|| {
+                model::ContainerHealthCheckTemplate {
+                    command: healthcheck_cmd.to_string(),
+                    interval: imp.health_check_interval_value.value(),
+                    timeout: imp.health_check_timeout_value.value(),
+                    start_period: imp.health_check_start_period_value.value(),
+                    retries: imp.health_check_retries_value.value(),
+                }
+            }),
+        }
+    }
+
+    fn apply_template(&self, template: &model::ContainerTemplate) {
+        let imp = self.imp();
+
+        // Selecting an image resets the command and port mappings to the image defaults. So this
+        // has to happen first and a pending reset must not override the template afterwards.
+        if let Some(image) = template.image.as_deref() {
+            imp.image_selection_combo_row.select_reference(image);
+        }
+        if let Some((handler, image)) = imp.command_row_handler.take()
+            && let Some(image) = image.upgrade()
+        {
+            image.disconnect(handler);
+        }
+        imp.pull_latest_image_switch_row
+            .set_active(template.pull_latest_image);
+
+        imp.command_entry_row.set_text(&template.command);
+        imp.cmd_args().remove_all();
+        template.command_args.iter().for_each(|arg| {
+            add_value(imp.cmd_args()).set_value(arg.as_str());
+        });
+
+        imp.terminal_switch_row.set_active(template.terminal);
+        imp.privileged_switch_row.set_active(template.privileged);
+
+        imp.network_mode_combo_row
+            .set_selected(NetworkMode::from_name(&template.network_mode).into());
+        if let Some(name) = template.network_container.as_deref() {
+            match imp.network_container_combo_row.model().and_then(|list| {
+                list.iter::<model::Container>()
+                    .position(|container| container.is_ok_and(|container| container.name() == name))
+            }) {
+                Some(position) => imp
+                    .network_container_combo_row
+                    .set_selected(position as u32),
+                None => log::warn!("Container '{name}' does not exist"),
+            }
+        }
+        if let Some(name) = template.network_name.as_deref() {
+            let network_names = &imp.network_names;
+            match (0..network_names.n_items())
+                .find(|position| network_names.string(*position).as_deref() == Some(name))
+            {
+                Some(position) => imp.network_name_combo_row.set_selected(position),
+                None => log::warn!("Network '{name}' does not exist"),
+            }
+        }
+
+        imp.memory_switch
+            .set_active(template.memory_limit.is_some());
+        if let Some(memory_limit) = &template.memory_limit {
+            imp.mem_value.set_value(memory_limit.value);
+            imp.mem_drop_down.set_selected(memory_limit.unit);
+        }
+
+        imp.port_mappings().remove_all();
+        template.port_mappings.iter().for_each(|template| {
+            let port_mapping = add_port_mapping(imp.port_mappings());
+            port_mapping.set_host_port(template.host_port);
+            port_mapping.set_container_port(template.container_port);
+            port_mapping.set_protocol(template.protocol.parse().unwrap_or_default());
+        });
+
+        imp.volumes().remove_all();
+        if let Some(client) = self.client() {
+            template.mounts.iter().for_each(|template| {
+                let mount = add_mount(imp.volumes(), &client);
+                if template.volume {
+                    mount.set_mount_type(model::MountType::Volume);
+                    match client.volume_list().get_volume(&template.source) {
+                        Some(volume) => mount.set_volume(Some(&volume)),
+                        None => log::warn!("Volume '{}' does not exist", template.source),
+                    }
+                } else {
+                    mount.set_host_path(template.source.as_str());
+                }
+                mount.set_container_path(template.destination.as_str());
+                mount.set_writable(template.writable);
+                mount.set_selinux(match template.selinux.as_str() {
+                    "z" => model::MountSELinux::Shared,
+                    "Z" => model::MountSELinux::Private,
+                    _ => model::MountSELinux::NoLabel,
+                });
+            });
+        }
+
+        imp.env_vars().remove_all();
+        template.env_vars.iter().for_each(|(key, value)| {
+            let entry = add_key_val(imp.env_vars());
+            entry.set_key(key.as_str());
+            entry.set_value(value.as_str());
+        });
+
+        imp.labels().remove_all();
+        template.labels.iter().for_each(|(key, value)| {
+            let entry = add_key_val(imp.labels());
+            entry.set_key(key.as_str());
+            entry.set_value(value.as_str());
+        });
+
+        imp.hosts().remove_all();
+        template.hosts.iter().for_each(|(hostname, ip)| {
+            let entry = self.add_host();
+            entry.set_key(hostname.as_str());
+            entry.set_value(ip.as_str());
+        });
+
+        match &template.health_check {
+            Some(health_check) => {
+                imp.health_check_command_entry_row
+                    .set_text(&health_check.command);
+                imp.health_check_interval_value
+                    .set_value(health_check.interval);
+                imp.health_check_timeout_value
+                    .set_value(health_check.timeout);
+                imp.health_check_start_period_value
+                    .set_value(health_check.start_period);
+                imp.health_check_retries_value
+                    .set_value(health_check.retries);
+            }
+            None => imp.health_check_command_entry_row.set_text(""),
+        }
+    }
+
     fn add_cmd_arg(&self) {
         add_value(self.imp().cmd_args());
     }
@@ -496,6 +1037,19 @@ impl ContainerCreationPage {
         add_key_val(self.imp().labels());
     }
 
+    fn add_host(&self) -> model::KeyVal {
+        let host = add_key_val(self.imp().hosts());
+        host.connect_notify_local(
+            None,
+            clone!(
+                #[weak(rename_to = obj)]
+                self,
+                move |_, _| obj.update_create_actions()
+            ),
+        );
+        host
+    }
+
     fn finish(&self, run: bool) {
         let imp = self.imp();
 
@@ -542,8 +1096,9 @@ impl ContainerCreationPage {
     fn pull_and_create(&self, reference: &str, remote: bool, run: bool) {
         let imp = self.imp();
 
-        let pull_opts = podman::opts::PullOpts::builder()
-            .reference(reference)
+        let client = self.client().unwrap();
+        let pull_opts = client
+            .pull_opts(reference)
             .policy(if remote {
                 podman::opts::PullPolicy::Always
             } else {
@@ -551,32 +1106,49 @@ impl ContainerCreationPage {
             })
             .build();
 
-        let page = view::ActionPage::from(
-            &self
-                .client()
-                .unwrap()
-                .action_list()
-                .create_container_download_image(
-                    imp.name_entry_row.text().as_str(),
-                    pull_opts,
-                    self.create(),
-                    run,
-                ),
-        );
+        let page = view::ActionPage::from(&client.action_list().create_container_download_image(
+            imp.name_entry_row.text().as_str(),
+            pull_opts,
+            self.create(),
+            run,
+        ));
 
         imp.navigation_view
             .push(&adw::NavigationPage::builder().child(&page).build());
     }
 
+    fn update_create_actions(&self) {
+        let imp = self.imp();
+
+        let enabled = !imp.name_entry_row.text().is_empty()
+            && imp.health_check_test().is_some()
+            && (self.pod().is_some()
+                || (imp
+                    .hosts()
+                    .iter::<model::KeyVal>()
+                    .map(Result::unwrap)
+                    .all(|host| is_valid_host(&host))
+                    && match imp.network_mode() {
+                        NetworkMode::Container => imp.network_container().is_some(),
+                        NetworkMode::Network => imp.network_name().is_some(),
+                        _ => true,
+                    }));
+
+        self.action_set_enabled(ACTION_CREATE_AND_RUN, enabled);
+        self.action_set_enabled(ACTION_CREATE, enabled);
+    }
+
     fn create(&self) -> podman::opts::ContainerCreateOptsBuilder {
         let imp = self.imp();
 
+        let publishes_ports = self.pod().is_none() && imp.network_mode().publishes_ports();
+
         let create_opts = podman::opts::ContainerCreateOpts::builder()
             .name(imp.name_entry_row.text().as_str())
             .pod(self.pod().as_ref().map(model::Pod::name))
             .terminal(imp.terminal_switch_row.is_active())
             .privileged(imp.privileged_switch_row.is_active())
-            .portmappings(if self.pod().is_none() {
+            .portmappings(if publishes_ports {
                 Box::new(
                     imp.port_mappings()
                         .iter::<model::PortMapping>()
@@ -631,6 +1203,52 @@ impl ContainerCreationPage {
                     .map(|entry| (entry.key(), entry.value())),
             );
 
+        let create_opts = if self.pod().is_none() {
+            create_opts.hostadd(
+                imp.hosts()
+                    .iter::<model::KeyVal>()
+                    .map(Result::unwrap)
+                    .filter(|host| !host.key().trim().is_empty())
+                    .map(|host| format!("{}:{}", host.key().trim(), host.value().trim())),
+            )
+        } else {
+            create_opts
+        };
+
+        let create_opts =
+            if self.pod().is_none() {
+                let namespace = |nsmode: &str, value: Option<String>| podman::models::Namespace {
+                    nsmode: Some(nsmode.to_owned()),
+                    value,
+                };
+
+                match imp.network_mode() {
+                    NetworkMode::Default => create_opts,
+                    NetworkMode::Bridge => create_opts.netns(namespace("bridge", None)),
+                    NetworkMode::Host => create_opts.netns(namespace("host", None)),
+                    NetworkMode::None => create_opts.netns(namespace("none", None)),
+                    NetworkMode::Container => create_opts.netns(namespace(
+                        "container",
+                        imp.network_container().map(|container| container.id()),
+                    )),
+                    NetworkMode::Network => create_opts.netns(namespace("bridge", None)).networks(
+                        imp.network_name().map(|name| {
+                            (
+                                name,
+                                podman::models::PerNetworkOptions {
+                                    aliases: None,
+                                    interface_name: None,
+                                    static_ips: None,
+                                    static_mac: None,
+                                },
+                            )
+                        }),
+                    ),
+                }
+            } else {
+                create_opts
+            };
+
         let create_opts = if imp.memory_switch.is_active() {
             create_opts.resource_limits(podman::models::LinuxResources {
                 block_io: None,
@@ -673,9 +1291,9 @@ impl ContainerCreationPage {
             create_opts.command(&cmd)
         };
 
-        let healthcheck_cmd = imp.health_check_command_entry_row.text();
+        let healthcheck_test = imp.health_check_test().unwrap_or_default();
 
-        if healthcheck_cmd.is_empty() {
+        if healthcheck_test.is_empty() {
             create_opts
         } else {
             create_opts.health_config(podman::models::Schema2HealthConfig {
@@ -684,12 +1302,7 @@ impl ContainerCreationPage {
                 start_period: Some(
                     imp.health_check_start_period_value.value() as i64 * 1_000_000_000,
                 ),
-                test: Some(
-                    healthcheck_cmd
-                        .split(' ')
-                        .map(str::to_string)
-                        .collect::<Vec<_>>(),
-                ),
+                test: Some(healthcheck_test),
                 timeout: Some(imp.health_check_timeout_value.value() as i64 * 1_000_000_000),
             })
         }
@@ -721,6 +1334,12 @@ fn bind_model<F>(
     );
 }
 
+/// Whether the entry maps a hostname to a valid IP address. Entries without a hostname are
+/// ignored.
+fn is_valid_host(host: &model::KeyVal) -> bool {
+    host.key().trim().is_empty() || host.value().trim().parse::<std::net::IpAddr>().is_ok()
+}
+
 fn add_port_mapping(model: &gio::ListStore) -> model::PortMapping {
     let port_mapping = model::PortMapping::default();
 
@@ -756,7 +1375,7 @@ fn add_mount(model: &gio::ListStore, client: &model::Client) -> model::Mount {
     mount
 }
 
-fn add_value(model: &gio::ListStore) {
+fn add_value(model: &gio::ListStore) -> model::Value {
     let value = model::Value::default();
 
     value.connect_remove_request(clone!(
@@ -770,9 +1389,11 @@ fn add_value(model: &gio::ListStore) {
     ));
 
     model.append(&value);
+
+    value
 }
 
-fn add_key_val(model: &gio::ListStore) {
+fn add_key_val(model: &gio::ListStore) -> model::KeyVal {
     let entry = model::KeyVal::default();
 
     entry.connect_remove_request(clone!(
@@ -786,6 +1407,8 @@ fn add_key_val(model: &gio::ListStore) {
     ));
 
     model.append(&entry);
+
+    entry
 }
 
 fn mount_options(mount: &model::Mount) -> Option<Vec<String>> {
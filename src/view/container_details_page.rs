@@ -24,6 +24,7 @@ const ACTION_SHOW_POD_DETAILS: &str = "container-details-page.show-pod-details";
 const ACTION_START_OR_RESUME: &str = "container-details-page.start";
 const ACTION_STOP: &str = "container-details-page.stop";
 const ACTION_KILL: &str = "container-details-page.kill";
+const ACTION_SEND_SIGNAL: &str = "container-details-page.send-signal";
 const ACTION_RESTART: &str = "container-details-page.restart";
 const ACTION_PAUSE: &str = "container-details-page.pause";
 const ACTION_RESUME: &str = "container-details-page.resume";
@@ -31,6 +32,11 @@ const ACTION_DELETE: &str = "container-details-page.delete";
 
 const ACTION_INSPECT: &str = "container-details-page.inspect";
 const ACTION_GENERATE_KUBE: &str = "container-details-page.generate-kube";
+const ACTION_GENERATE_QUADLET: &str = "container-details-page.generate-quadlet";
+const ACTION_SHOW_CONFIG_DIFF: &str = "container-details-page.show-config-diff";
+const ACTION_SHOW_CHANGES: &str = "container-details-page.show-changes";
+const ACTION_SHOW_PRIVILEGES: &str = "container-details-page.show-privileges";
+const ACTION_COPY_ENV: &str = "container-details-page.copy-env";
 const ACTION_SHOW_TTY: &str = "container-details-page.show-tty";
 const ACTION_SHOW_LOG: &str = "container-details-page.show-log";
 const ACTION_SHOW_PROCESSES: &str = "container-details-page.show-processes";
@@ -93,7 +99,7 @@ mod imp {
             });
             klass.install_action(ACTION_START_OR_RESUME, None, |widget, _, _| {
                 if widget.container().map(|c| c.can_start()).unwrap_or(false) {
-                    view::container::start(widget, widget.container());
+                    view::container::start_and_show_log(widget, widget.container());
                 } else {
                     view::container::resume(widget, widget.container());
                 }
@@ -104,6 +110,9 @@ mod imp {
             klass.install_action(ACTION_KILL, None, |widget, _, _| {
                 view::container::kill(widget, widget.container());
             });
+            klass.install_action(ACTION_SEND_SIGNAL, None, |widget, _, _| {
+                view::container::send_signal_show_dialog(widget, widget.container());
+            });
             klass.install_action(ACTION_RESTART, None, |widget, _, _| {
                 view::container::restart(widget, widget.container());
             });
@@ -114,7 +123,7 @@ mod imp {
                 view::container::resume(widget, widget.container());
             });
             klass.install_action(ACTION_DELETE, None, |widget, _, _| {
-                view::container::delete(widget, widget.container());
+                view::container::delete_show_confirmation(widget, widget.container());
             });
             klass.install_action(ACTION_INSPECT, None, |widget, _, _| {
                 widget.show_inspection();
@@ -122,6 +131,21 @@ mod imp {
             klass.install_action(ACTION_GENERATE_KUBE, None, |widget, _, _| {
                 widget.show_kube();
             });
+            klass.install_action(ACTION_GENERATE_QUADLET, None, |widget, _, _| {
+                widget.show_quadlet();
+            });
+            klass.install_action(ACTION_SHOW_CONFIG_DIFF, None, |widget, _, _| {
+                widget.show_config_diff();
+            });
+            klass.install_action(ACTION_SHOW_CHANGES, None, |widget, _, _| {
+                widget.show_changes();
+            });
+            klass.install_action(ACTION_SHOW_PRIVILEGES, None, |widget, _, _| {
+                widget.show_privileges();
+            });
+            klass.install_action_async(ACTION_COPY_ENV, None, async |widget, _, _| {
+                widget.copy_env().await;
+            });
             klass.install_action(ACTION_SHOW_TTY, None, |widget, _, _| {
                 widget.show_tty();
             });
@@ -172,11 +196,25 @@ mod imp {
             let container_expr = Self::Type::this_expression("container");
             let status_expr = container_expr.chain_property::<model::Container>("status");
 
-            status_expr
-                .chain_closure::<bool>(closure!(|_: Self::Type, status: model::ContainerStatus| {
-                    status == model::ContainerStatus::Running
-                }))
-                .bind(&*self.resources, "visible", Some(obj));
+            gtk::ClosureExpression::new::<bool>(
+                [
+                    status_expr.upcast_ref(),
+                    container_expr
+                        .chain_property::<model::Container>("container-list")
+                        .chain_property::<model::ContainerList>("stats-enabled")
+                        .upcast_ref(),
+                ],
+                closure!(
+                    |_: Self::Type, status: model::ContainerStatus, stats_enabled: bool| {
+                        stats_enabled
+                            && matches!(
+                                status,
+                                model::ContainerStatus::Running | model::ContainerStatus::Paused
+                            )
+                    }
+                ),
+            )
+            .bind(&*self.resources, "visible", Some(obj));
 
             status_expr.watch(
                 Some(obj),
@@ -186,6 +224,19 @@ mod imp {
                     move || obj.update_actions()
                 ),
             );
+            container_expr
+                .chain_property::<model::Container>("container-list")
+                .chain_property::<model::ContainerList>("client")
+                .chain_property::<model::Client>("connection")
+                .chain_property::<model::Connection>("read-only")
+                .watch(
+                    Some(obj),
+                    clone!(
+                        #[weak]
+                        obj,
+                        move || obj.update_actions()
+                    ),
+                );
             container_expr
                 .chain_property::<model::Container>("action-ongoing")
                 .watch(
@@ -347,15 +398,23 @@ impl ContainerDetailsPage {
                 .set_visible(!container.action_ongoing() && can_stop);
             imp.spinning_button.set_visible(
                 container.action_ongoing()
-                    || (!imp.start_or_resume_button.is_visible() && !imp.stop_button.is_visible()),
+                    || (!container.is_read_only()
+                        && !imp.start_or_resume_button.is_visible()
+                        && !imp.stop_button.is_visible()),
             );
 
             self.action_set_enabled(ACTION_START_OR_RESUME, can_start_or_resume);
             self.action_set_enabled(ACTION_STOP, can_stop);
             self.action_set_enabled(ACTION_KILL, container.can_kill());
+            self.action_set_enabled(ACTION_SEND_SIGNAL, container.can_kill());
             self.action_set_enabled(ACTION_RESTART, container.can_restart());
             self.action_set_enabled(ACTION_PAUSE, container.can_pause());
             self.action_set_enabled(ACTION_DELETE, container.can_delete());
+            let read_only = container.is_read_only();
+            self.action_set_enabled(ACTION_RENAME, !read_only);
+            self.action_set_enabled(ACTION_COMMIT, !read_only);
+            self.action_set_enabled(ACTION_PUT_FILES, !read_only);
+            self.action_set_enabled(ACTION_SHOW_TTY, !read_only);
         }
     }
 
@@ -427,6 +486,20 @@ impl ContainerDetailsPage {
         self.show_kube_inspection_or_kube(view::ScalableTextViewMode::Kube);
     }
 
+    pub(crate) fn show_quadlet(&self) {
+        self.exec_action(|| {
+            if let Some(container) = self.container() {
+                utils::navigation_view(self).push(
+                    &adw::NavigationPage::builder()
+                        .child(&view::ScalableTextViewPage::from(
+                            view::Entity::ContainerQuadlet(container.downgrade()),
+                        ))
+                        .build(),
+                );
+            }
+        });
+    }
+
     pub(crate) fn show_kube_inspection_or_kube(&self, mode: view::ScalableTextViewMode) {
         self.exec_action(|| {
             if let Some(container) = self.container() {
@@ -445,6 +518,83 @@ impl ContainerDetailsPage {
         });
     }
 
+    pub(crate) fn show_config_diff(&self) {
+        self.exec_action(|| {
+            if let Some(container) = self.container() {
+                utils::navigation_view(self).push(
+                    &adw::NavigationPage::builder()
+                        .child(&view::ContainerConfigDiffPage::from(&container))
+                        .build(),
+                );
+            }
+        });
+    }
+
+    pub(crate) fn show_changes(&self) {
+        self.exec_action(|| {
+            if let Some(container) = self.container() {
+                utils::navigation_view(self).push(
+                    &adw::NavigationPage::builder()
+                        .child(&view::ContainerChangesPage::from(&container))
+                        .build(),
+                );
+            }
+        });
+    }
+
+    pub(crate) fn show_privileges(&self) {
+        self.exec_action(|| {
+            if let Some(container) = self.container() {
+                utils::navigation_view(self).push(
+                    &adw::NavigationPage::builder()
+                        .child(&view::ContainerPrivilegesPage::from(&container))
+                        .build(),
+                );
+            }
+        });
+    }
+
+    pub(crate) async fn copy_env(&self) {
+        let Some(data) = self.container().as_ref().and_then(model::Container::data) else {
+            return;
+        };
+
+        let env = data.env();
+        if env.is_empty() {
+            utils::show_toast(self, gettext("The container has no environment variables"));
+            return;
+        }
+
+        let secret_keys = env
+            .iter()
+            .filter_map(|var| var.split_once('=').map(|(key, _)| key))
+            .filter(|key| looks_secret(key))
+            .collect::<Vec<_>>();
+
+        if !secret_keys.is_empty() {
+            let dialog = adw::AlertDialog::builder()
+                .heading(gettext("Copy Possible Secrets?"))
+                .body_use_markup(true)
+                .body(gettext!(
+                    // Translators: The "{}" is a placeholder for a list of variable names.
+                    "Some variables look like they contain secrets: <b>{}</b>. They will be copied in plain text.",
+                    utils::escape(&secret_keys.join(", ")),
+                ))
+                .build();
+
+            dialog.add_responses(&[("cancel", &gettext("_Cancel")), ("copy", &gettext("C_opy"))]);
+            dialog.set_default_response(Some("cancel"));
+            dialog.set_response_appearance("copy", adw::ResponseAppearance::Destructive);
+
+            if "copy" != dialog.choose_future(self).await {
+                return;
+            }
+        }
+
+        self.clipboard().set_text(&export_statements(&env));
+        utils::show_toast(self, gettext("Copied environment to clipboard"));
+    }
+
     pub(crate) fn show_log(&self) {
         self.exec_action(|| {
             if let Some(container) = self.container() {
@@ -491,3 +641,31 @@ impl ContainerDetailsPage {
         }
     }
 }
+
+/// Turns `KEY=VALUE` pairs into `export KEY='VALUE'` lines that can be pasted into a shell.
+fn export_statements(env: &[String]) -> String {
+    env.iter()
+        .filter_map(|var| var.split_once('='))
+        .map(|(key, value)| {
+            format!(
+                "export {key}={}\n",
+                glib::shell_quote(value).to_string_lossy()
+            )
+        })
+        .collect()
+}
+
+fn looks_secret(key: &str) -> bool {
+    let key = key.to_uppercase();
+    [
+        "PASSWORD",
+        "PASSWD",
+        "SECRET",
+        "TOKEN",
+        "API_KEY",
+        "PRIVATE",
+        "CREDENTIAL",
+    ]
+    .iter()
+    .any(|word| key.contains(word))
+}
@@ -1,6 +1,5 @@
 use std::borrow::Cow;
 use std::cell::Cell;
-use std::cell::OnceCell;
 use std::cell::RefCell;
 use std::collections::VecDeque;
 use std::io::BufWriter;
@@ -30,12 +29,15 @@ use crate::model;
 use crate::podman;
 use crate::rt;
 use crate::utils;
+use crate::view;
 use crate::widget;
 
 const ACTION_TOGGLE_SEARCH: &str = "container-log-page.toggle-search";
 const ACTION_EXIT_SEARCH: &str = "container-log-page.exit-search";
 const ACTION_SAVE_TO_FILE: &str = "container-log-page.save-to-file";
+const ACTION_SAVE_AS_JSON_LINES: &str = "container-log-page.save-as-json-lines";
 const ACTION_SHOW_TIMESTAMPS: &str = "container-log-page.show-timestamps";
+const ACTION_SINCE_LAST_START: &str = "container-log-page.since-last-start";
 const ACTION_SCROLL_DOWN: &str = "container-log-page.scroll-down";
 const ACTION_START_CONTAINER: &str = "container-log-page.start-container";
 const ACTION_ZOOM_OUT: &str = "container-log-page.zoom-out";
@@ -59,7 +61,11 @@ mod imp {
     pub(crate) struct ContainerLogPage {
         pub(super) settings: utils::PodsSettings,
         pub(super) log_timestamps: RefCell<VecDeque<String>>,
-        pub(super) fetch_until: OnceCell<String>,
+        pub(super) fetch_until: RefCell<Option<String>>,
+        /// Increased whenever the log is reloaded, so that streams of the previous load stop.
+        pub(super) log_generation: Cell<u32>,
+        /// The start of the container's current run, which is refreshed periodically.
+        pub(super) up_since: Cell<i64>,
         pub(super) fetch_lines_state: Cell<FetchLinesState>,
         pub(super) fetched_lines: RefCell<VecDeque<Vec<u8>>>,
         pub(super) prev_adj: Cell<f64>,
@@ -68,6 +74,8 @@ mod imp {
         pub(super) container: glib::WeakRef<model::Container>,
         #[property(get, set)]
         pub(super) sticky: Cell<bool>,
+        #[property(get, set = Self::set_since_last_start, explicit_notify)]
+        pub(super) since_last_start: Cell<bool>,
         #[template_child]
         pub(super) zoom_control: TemplateChild<widget::ZoomControl>,
         #[template_child]
@@ -123,9 +131,17 @@ mod imp {
             });
 
             klass.install_action_async(ACTION_SAVE_TO_FILE, None, |widget, _, _| async move {
-                widget.save_to_file().await;
+                widget.save_to_file(false).await;
             });
+            klass.install_action_async(
+                ACTION_SAVE_AS_JSON_LINES,
+                None,
+                |widget, _, _| async move {
+                    widget.save_to_file(true).await;
+                },
+            );
             klass.install_property_action(ACTION_SHOW_TIMESTAMPS, "show-timestamps");
+            klass.install_property_action(ACTION_SINCE_LAST_START, "since-last-start");
 
             klass.install_action(ACTION_SCROLL_DOWN, None, |widget, _, _| {
                 widget.scroll_down();
@@ -303,6 +319,19 @@ mod imp {
                         }
                     ),
                 );
+                self.up_since.set(container.up_since());
+                container.connect_up_since_notify(clone!(
+                    #[weak]
+                    obj,
+                    move |container| {
+                        let up_since = container.up_since();
+                        if obj.imp().up_since.replace(up_since) != up_since
+                            && obj.since_last_start()
+                        {
+                            obj.reload_log();
+                        }
+                    }
+                ));
             }
 
             obj.init_log();
@@ -315,6 +344,19 @@ mod imp {
 
     impl WidgetImpl for ContainerLogPage {}
 
+    impl ContainerLogPage {
+        fn set_since_last_start(&self, value: bool) {
+            let obj = &*self.obj();
+            if obj.since_last_start() == value {
+                return;
+            }
+            self.since_last_start.set(value);
+            obj.notify_since_last_start();
+
+            obj.reload_log();
+        }
+    }
+
     #[gtk::template_callbacks]
     impl ContainerLogPage {
         #[template_callback]
@@ -435,10 +477,15 @@ impl ContainerLogPage {
 
         let mut perform = MarkupPerform::default();
 
+        let mut opts = basic_opts_builder(false, true).tail("512");
+        if let Some(since) = self.log_since() {
+            opts = opts.since(since);
+        }
+
+        let generation = self.imp().log_generation.get();
+
         rt::Pipe::new(container, move |container| {
-            container
-                .logs(&basic_opts_builder(false, true).tail("512").build())
-                .boxed()
+            container.logs(&opts.build()).boxed()
         })
         .on_next(clone!(
             #[weak(rename_to = obj)]
@@ -446,6 +493,9 @@ impl ContainerLogPage {
             #[upgrade_or]
             glib::ControlFlow::Break,
             move |result| {
+                if obj.is_outdated(generation) {
+                    return glib::ControlFlow::Break;
+                }
                 obj.imp().stack.set_visible_child_name("loaded");
                 obj.append_line(result, &mut perform)
             }
@@ -454,12 +504,46 @@ impl ContainerLogPage {
             #[weak(rename_to = obj)]
             self,
             move || {
+                if obj.is_outdated(generation) {
+                    return;
+                }
                 obj.imp().stack.set_visible_child_name("loaded");
                 obj.follow_log();
             }
         ));
     }
 
+    /// Clears the log and fetches it again, e.g. after the time range has been changed.
+    fn reload_log(&self) {
+        let imp = self.imp();
+
+        imp.log_generation
+            .set(imp.log_generation.get().wrapping_add(1));
+
+        imp.log_timestamps.borrow_mut().clear();
+        imp.fetch_until.replace(None);
+        imp.fetch_lines_state.set(FetchLinesState::Waiting);
+        imp.fetched_lines.borrow_mut().clear();
+        imp.lines_loading_revealer.set_reveal_child(false);
+        imp.source_buffer.set_text("");
+        imp.stack.set_visible_child_name("loading");
+
+        self.init_log();
+    }
+
+    /// Whether a stream belongs to a load of the log that has been replaced in the meantime.
+    fn is_outdated(&self, generation: u32) -> bool {
+        self.imp().log_generation.get() != generation
+    }
+
+    /// The start of the current run of the container if only its logs are to be shown.
+    fn log_since(&self) -> Option<String> {
+        self.since_last_start()
+            .then(|| self.container())
+            .flatten()
+            .map(|container| container.up_since().to_string())
+    }
+
     fn follow_log(&self) {
         let container =
             if let Some(container) = self.container().as_ref().and_then(model::Container::api) {
@@ -482,10 +566,14 @@ impl ContainerLogPage {
                 ),
                 AtomicUsize::new(iter.take_while(|t| *t == last).count() + 1),
             ),
-            None => (opts, AtomicUsize::new(0)),
+            None => match self.log_since() {
+                Some(since) => (opts.since(since), AtomicUsize::new(0)),
+                None => (opts, AtomicUsize::new(0)),
+            },
         };
 
         let mut perform = MarkupPerform::default();
+        let generation = self.imp().log_generation.get();
 
         rt::Pipe::new(container, |container| container.logs(&opts.build()).boxed()).on_next(
             clone!(
@@ -494,7 +582,9 @@ impl ContainerLogPage {
                 #[upgrade_or]
                 glib::ControlFlow::Break,
                 move |result: podman::Result<podman::conn::TtyChunk>| {
-                    if skip.load(Ordering::Relaxed) == 0 {
+                    if obj.is_outdated(generation) {
+                        glib::ControlFlow::Break
+                    } else if skip.load(Ordering::Relaxed) == 0 {
                         obj.append_line(result, &mut perform)
                     } else {
                         skip.fetch_sub(1, Ordering::Relaxed);
@@ -533,7 +623,9 @@ impl ContainerLogPage {
         let line_buffer = perform.decode(&line);
         let (timestamp, log_message) = line_buffer.split_once(' ').unwrap();
 
-        imp.fetch_until.get_or_init(|| timestamp.to_owned());
+        imp.fetch_until
+            .borrow_mut()
+            .get_or_insert_with(|| timestamp.to_owned());
 
         let source_buffer = &*imp.source_buffer;
         source_buffer.insert_markup(
@@ -568,7 +660,7 @@ impl ContainerLogPage {
 
         match imp.fetch_lines_state.get() {
             FetchLinesState::Waiting => {
-                let until = if let Some(until) = imp.fetch_until.get().map(ToOwned::to_owned) {
+                let until = if let Some(until) = imp.fetch_until.borrow().clone() {
                     until
                 } else {
                     return;
@@ -583,10 +675,15 @@ impl ContainerLogPage {
 
                 imp.lines_loading_revealer.set_reveal_child(true);
 
-                rt::Pipe::new(container, |container| {
-                    container
-                        .logs(&basic_opts_builder(false, true).until(until).build())
-                        .boxed()
+                let mut opts = basic_opts_builder(false, true).until(until);
+                if let Some(since) = self.log_since() {
+                    opts = opts.since(since);
+                }
+
+                let generation = imp.log_generation.get();
+
+                rt::Pipe::new(container, move |container| {
+                    container.logs(&opts.build()).boxed()
                 })
                 .on_next(clone!(
                     #[weak(rename_to = obj)]
@@ -594,6 +691,10 @@ impl ContainerLogPage {
                     #[upgrade_or]
                     glib::ControlFlow::Break,
                     move |result| {
+                        if obj.is_outdated(generation) {
+                            return glib::ControlFlow::Break;
+                        }
+
                         let imp = obj.imp();
                         imp.fetch_lines_state.set(FetchLinesState::Fetching);
 
@@ -613,6 +714,10 @@ impl ContainerLogPage {
                     #[weak(rename_to = obj)]
                     self,
                     move || {
+                        if obj.is_outdated(generation) {
+                            return;
+                        }
+
                         let imp = obj.imp();
                         imp.lines_loading_revealer.set_reveal_child(false);
                         imp.fetch_lines_state.set(FetchLinesState::Finished);
@@ -661,11 +766,23 @@ impl ContainerLogPage {
         );
     }
 
-    async fn save_to_file(&self) {
+    /// Saves the log either as plain text or as JSON lines that keep the stream of each message.
+    async fn save_to_file(&self, json_lines: bool) {
         if let Some(container) = self.container() {
+            let Some((since, until)) = self.choose_time_range().await else {
+                return;
+            };
+
             let request = SaveFileRequest::default()
                 .identifier(WindowIdentifier::from_native(&self.native().unwrap()).await)
-                .current_name(format!("{}.log", container.name()).as_str())
+                .current_name(
+                    format!(
+                        "{}.{}",
+                        container.name(),
+                        if json_lines { "jsonl" } else { "log" }
+                    )
+                    .as_str(),
+                )
                 .choice(Choice::boolean(
                     "timestamps",
                     &gettext("Include timestamps"),
@@ -680,7 +797,7 @@ impl ContainerLogPage {
                     #[weak(rename_to = obj)]
                     self,
                     move |files| {
-                        obj.action_set_enabled(ACTION_SAVE_TO_FILE, false);
+                        obj.set_save_actions_enabled(false);
 
                         let file = gio::File::for_uri(files.uris()[0].as_str());
 
@@ -702,10 +819,16 @@ impl ContainerLogPage {
 
                         let timestamps = files.choices()[0].1 == "true";
 
+                        let mut opts = basic_opts_builder(false, timestamps);
+                        if let Some(since) = since {
+                            opts = opts.since(since.to_string());
+                        }
+                        if let Some(until) = until {
+                            opts = opts.until(until.to_string());
+                        }
+
                         rt::Pipe::new(container.api().unwrap(), move |container| {
-                            container
-                                .logs(&basic_opts_builder(false, timestamps).build())
-                                .boxed()
+                            container.logs(&opts.build()).boxed()
                         })
                         .on_next(clone!(
                             #[weak]
@@ -713,12 +836,17 @@ impl ContainerLogPage {
                             #[upgrade_or]
                             glib::ControlFlow::Break,
                             move |result: podman::Result<podman::conn::TtyChunk>| {
-                                match result.map(Vec::from) {
-                                    Ok(line) => {
-                                        perform.decode(&line);
+                                match result {
+                                    Ok(chunk) => {
+                                        let line = if json_lines {
+                                            json_line(chunk, timestamps, &mut perform)
+                                        } else {
+                                            perform.decode(&Vec::from(chunk));
+                                            Some(perform.move_out_buffer())
+                                                .filter(|line| !line.is_empty())
+                                        };
 
-                                        let line = perform.move_out_buffer();
-                                        if !line.is_empty() {
+                                        if let Some(line) = line {
                                             match writer
                                                 .write_all(line.as_bytes())
                                                 .and_then(|_| writer.write_all(b"\n"))
@@ -754,7 +882,7 @@ impl ContainerLogPage {
                             #[weak]
                             obj,
                             move || {
-                                obj.action_set_enabled(ACTION_SAVE_TO_FILE, true);
+                                obj.set_save_actions_enabled(true);
                                 utils::show_toast(&obj, gettext("Log has been saved"));
                             }
                         ));
@@ -765,6 +893,89 @@ impl ContainerLogPage {
         }
     }
 
+    fn set_save_actions_enabled(&self, enabled: bool) {
+        self.action_set_enabled(ACTION_SAVE_TO_FILE, enabled);
+        self.action_set_enabled(ACTION_SAVE_AS_JSON_LINES, enabled);
+    }
+
+    /// Lets the user limit the saved log to a time range. Returns `None` if the user cancels.
+    async fn choose_time_range(&self) -> Option<(Option<i64>, Option<i64>)> {
+        let since_row = widget::DateTimeRow::default();
+        since_row.set_title(&gettext("Since"));
+
+        let until_row = widget::DateTimeRow::default();
+        until_row.set_title(&gettext("Until"));
+
+        let list_box = gtk::ListBox::builder()
+            .css_classes(vec!["boxed-list".to_string()])
+            .selection_mode(gtk::SelectionMode::None)
+            .build();
+        list_box.append(&since_row);
+        list_box.append(&until_row);
+
+        let error_label = gtk::Label::builder()
+            .css_classes(vec!["caption".to_string(), "error".to_string()])
+            .label(gettext(
+                "The start of the time range must be before its end",
+            ))
+            .visible(false)
+            .wrap(true)
+            .build();
+
+        let box_ = gtk::Box::builder()
+            .orientation(gtk::Orientation::Vertical)
+            .spacing(12)
+            .build();
+        box_.append(&list_box);
+        box_.append(&error_label);
+
+        let dialog = adw::AlertDialog::builder()
+            .heading(gettext("Save Log"))
+            .body(gettext("Optionally limit the log to a time range."))
+            .extra_child(&box_)
+            .build();
+
+        dialog.add_responses(&[("cancel", &gettext("_Cancel")), ("save", &gettext("_Save"))]);
+        dialog.set_default_response(Some("save"));
+        dialog.set_response_appearance("save", adw::ResponseAppearance::Suggested);
+
+        let validate = clone!(
+            #[weak]
+            dialog,
+            #[weak]
+            since_row,
+            #[weak]
+            until_row,
+            #[weak]
+            error_label,
+            move || {
+                let valid = match time_range(&since_row, &until_row) {
+                    (Some(since), Some(until)) => since < until,
+                    _ => true,
+                };
+
+                error_label.set_visible(!valid);
+                dialog.set_response_enabled("save", valid);
+            }
+        );
+        [&since_row, &until_row].into_iter().for_each(|row| {
+            ["enable-expansion", "timestamp"]
+                .into_iter()
+                .for_each(|name| {
+                    row.connect_notify_local(Some(name), {
+                        let validate = validate.clone();
+                        move |_, _| validate()
+                    });
+                });
+        });
+
+        if dialog.choose_future(self).await == "save" {
+            Some(time_range(&since_row, &until_row))
+        } else {
+            None
+        }
+    }
+
     pub(crate) fn set_search_mode(&self, value: bool) {
         self.imp().search_bar.set_search_mode(value);
     }
@@ -776,19 +987,29 @@ impl ContainerLogPage {
     pub(crate) fn start_or_resume_container(&self) {
         if let Some(container) = self.container() {
             if container.can_start() {
-                container.start(clone!(
-                    #[weak(rename_to = obj)]
+                view::container::confirm_start(
                     self,
-                    move |result| {
-                        if let Err(e) = result {
-                            utils::show_error_toast(
-                                &obj,
-                                &gettext("Error starting container"),
-                                &e.to_string(),
-                            );
-                        }
-                    }
-                ));
+                    &container,
+                    clone!(
+                        #[weak(rename_to = obj)]
+                        self,
+                        #[weak]
+                        container,
+                        move || container.start(clone!(
+                            #[weak]
+                            obj,
+                            move |result| {
+                                if let Err(e) = result {
+                                    utils::show_error_toast(
+                                        &obj,
+                                        &gettext("Error starting container"),
+                                        &e.to_string(),
+                                    );
+                                }
+                            }
+                        ))
+                    ),
+                );
             } else if container.can_resume() {
                 container.resume(clone!(
                     #[weak(rename_to = obj)]
@@ -808,6 +1029,53 @@ impl ContainerLogPage {
     }
 }
 
+/// Returns the timestamps of the enabled rows.
+fn time_range(
+    since_row: &widget::DateTimeRow,
+    until_row: &widget::DateTimeRow,
+) -> (Option<i64>, Option<i64>) {
+    let timestamp = |row: &widget::DateTimeRow| row.enables_expansion().then(|| row.timestamp());
+    (timestamp(since_row), timestamp(until_row))
+}
+
+/// Serializes a chunk of the demultiplexed log stream as `{timestamp, stream, message}`. The
+/// timestamp is only known if Podman has been asked to prepend it to the message.
+fn json_line(
+    chunk: podman::conn::TtyChunk,
+    timestamps: bool,
+    perform: &mut PlainTextPerform,
+) -> Option<String> {
+    let stream = match &chunk {
+        podman::conn::TtyChunk::StdIn(_) => "stdin",
+        podman::conn::TtyChunk::StdOut(_) => "stdout",
+        podman::conn::TtyChunk::StdErr(_) => "stderr",
+    };
+
+    perform.decode(&Vec::from(chunk));
+    let line = perform.move_out_buffer();
+    if line.is_empty() {
+        return None;
+    }
+
+    let (timestamp, message) = if timestamps {
+        line.split_once(' ')
+            .map_or((Some(line.as_str()), ""), |(timestamp, message)| {
+                (Some(timestamp), message)
+            })
+    } else {
+        (None, line.as_str())
+    };
+
+    Some(
+        serde_json::json!({
+            "timestamp": timestamp,
+            "stream": stream,
+            "message": message,
+        })
+        .to_string(),
+    )
+}
+
 fn basic_opts_builder(follow: bool, timestamps: bool) -> podman::opts::ContainerLogsOptsBuilder {
     podman::opts::ContainerLogsOpts::builder()
         .follow(follow)
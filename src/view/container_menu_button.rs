@@ -13,10 +13,12 @@ use crate::view;
 const ACTION_START: &str = "container-menu-button.start";
 const ACTION_STOP: &str = "container-menu-button.stop";
 const ACTION_KILL: &str = "container-menu-button.kill";
+const ACTION_SEND_SIGNAL: &str = "container-menu-button.send-signal";
 const ACTION_RESTART: &str = "container-menu-button.restart";
 const ACTION_PAUSE: &str = "container-menu-button.pause";
 const ACTION_RESUME: &str = "container-menu-button.resume";
 const ACTION_RENAME: &str = "container-menu-button.rename";
+const ACTION_REFRESH: &str = "container-menu-button.refresh";
 const ACTION_DELETE: &str = "container-menu-button.delete";
 
 mod imp {
@@ -42,7 +44,7 @@ mod imp {
             klass.bind_template();
 
             klass.install_action(ACTION_START, None, |widget, _, _| {
-                view::container::start(widget, widget.container());
+                view::container::start_and_show_log(widget, widget.container());
             });
             klass.install_action(ACTION_STOP, None, |widget, _, _| {
                 view::container::stop(widget, widget.container());
@@ -50,6 +52,9 @@ mod imp {
             klass.install_action(ACTION_KILL, None, |widget, _, _| {
                 view::container::kill(widget, widget.container());
             });
+            klass.install_action(ACTION_SEND_SIGNAL, None, |widget, _, _| {
+                view::container::send_signal_show_dialog(widget, widget.container());
+            });
             klass.install_action(ACTION_RESTART, None, |widget, _, _| {
                 view::container::restart(widget, widget.container());
             });
@@ -63,9 +68,12 @@ mod imp {
             klass.install_action(ACTION_RENAME, None, |widget, _, _| {
                 view::container::rename(widget, widget.container().as_ref());
             });
+            klass.install_action(ACTION_REFRESH, None, |widget, _, _| {
+                view::container::refresh(widget, widget.container());
+            });
 
             klass.install_action(ACTION_DELETE, None, |widget, _, _| {
-                view::container::delete(widget, widget.container());
+                view::container::delete_show_confirmation(widget, widget.container());
             });
         }
 
@@ -107,6 +115,17 @@ mod imp {
                 }))
                 .bind(&*self.menu_button, "sensitive", Some(obj));
 
+            container_expr
+                .chain_property::<model::Container>("refreshing")
+                .watch(
+                    Some(obj),
+                    clone!(
+                        #[weak]
+                        obj,
+                        move || obj.update_refreshing()
+                    ),
+                );
+
             container_expr
                 .chain_property::<model::Container>("status")
                 .watch(
@@ -117,6 +136,20 @@ mod imp {
                         move || obj.update_actions()
                     ),
                 );
+
+            container_expr
+                .chain_property::<model::Container>("container-list")
+                .chain_property::<model::ContainerList>("client")
+                .chain_property::<model::Client>("connection")
+                .chain_property::<model::Connection>("read-only")
+                .watch(
+                    Some(obj),
+                    clone!(
+                        #[weak]
+                        obj,
+                        move || obj.update_actions()
+                    ),
+                );
         }
 
         fn dispose(&self) {
@@ -134,6 +167,16 @@ glib::wrapper! {
 }
 
 impl ContainerMenuButton {
+    fn update_refreshing(&self) {
+        let refreshing = self
+            .container()
+            .map(|container| container.refreshing())
+            .unwrap_or(false);
+
+        self.action_set_enabled(ACTION_REFRESH, !refreshing);
+        utils::set_menu_button_busy(&self.imp().menu_button, refreshing);
+    }
+
     fn update_actions(&self) {
         if let Some(container) = self.container() {
             let can_stop = container.can_stop();
@@ -141,10 +184,12 @@ impl ContainerMenuButton {
             self.action_set_enabled(ACTION_START, container.can_start());
             self.action_set_enabled(ACTION_STOP, can_stop);
             self.action_set_enabled(ACTION_KILL, can_stop);
+            self.action_set_enabled(ACTION_SEND_SIGNAL, container.can_kill());
             self.action_set_enabled(ACTION_RESTART, container.can_restart());
             self.action_set_enabled(ACTION_RESUME, container.can_resume());
             self.action_set_enabled(ACTION_PAUSE, container.can_pause());
             self.action_set_enabled(ACTION_DELETE, container.can_delete());
+            self.action_set_enabled(ACTION_RENAME, !container.is_read_only());
         }
     }
 }
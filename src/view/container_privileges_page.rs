@@ -0,0 +1,342 @@
+use std::collections::BTreeSet;
+
+use adw::prelude::*;
+use adw::subclass::prelude::*;
+use gettextrs::gettext;
+use glib::Properties;
+use glib::clone;
+use gtk::CompositeTemplate;
+use gtk::glib;
+use serde_json::Value;
+
+use crate::model;
+use crate::rt;
+use crate::utils;
+
+/// The capabilities Podman grants to containers unless configured otherwise in containers.conf.
+const DEFAULT_CAPABILITIES: [&str; 11] = [
+    "CAP_CHOWN",
+    "CAP_DAC_OVERRIDE",
+    "CAP_FOWNER",
+    "CAP_FSETID",
+    "CAP_KILL",
+    "CAP_NET_BIND_SERVICE",
+    "CAP_SETFCAP",
+    "CAP_SETGID",
+    "CAP_SETPCAP",
+    "CAP_SETUID",
+    "CAP_SYS_CHROOT",
+];
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default, Properties, CompositeTemplate)]
+    #[properties(wrapper_type = super::ContainerPrivilegesPage)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/view/container_privileges_page.ui")]
+    pub(crate) struct ContainerPrivilegesPage {
+        #[property(get, set, construct_only, nullable)]
+        pub(super) container: glib::WeakRef<model::Container>,
+        #[template_child]
+        pub(super) stack: TemplateChild<gtk::Stack>,
+        #[template_child]
+        pub(super) preferences_page: TemplateChild<adw::PreferencesPage>,
+        #[template_child]
+        pub(super) privileged_row: TemplateChild<adw::ActionRow>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for ContainerPrivilegesPage {
+        const NAME: &'static str = "PdsContainerPrivilegesPage";
+        type Type = super::ContainerPrivilegesPage;
+        type ParentType = gtk::Widget;
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.bind_template();
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for ContainerPrivilegesPage {
+        fn properties() -> &'static [glib::ParamSpec] {
+            Self::derived_properties()
+        }
+
+        fn set_property(&self, id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+            self.derived_set_property(id, value, pspec);
+        }
+
+        fn property(&self, id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            self.derived_property(id, pspec)
+        }
+
+        fn constructed(&self) {
+            self.parent_constructed();
+            self.obj().load();
+        }
+
+        fn dispose(&self) {
+            utils::unparent_children(&*self.obj());
+        }
+    }
+
+    impl WidgetImpl for ContainerPrivilegesPage {}
+}
+
+glib::wrapper! {
+    pub(crate) struct ContainerPrivilegesPage(ObjectSubclass<imp::ContainerPrivilegesPage>)
+        @extends gtk::Widget,
+        @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget;
+}
+
+impl From<&model::Container> for ContainerPrivilegesPage {
+    fn from(container: &model::Container) -> Self {
+        glib::Object::builder()
+            .property("container", container)
+            .build()
+    }
+}
+
+impl ContainerPrivilegesPage {
+    fn load(&self) {
+        let Some(container) = self.container().as_ref().and_then(model::Container::api) else {
+            return;
+        };
+
+        rt::Promise::new(async move {
+            let data = container.inspect().await?;
+            serde_json::to_value(data).map_err(anyhow::Error::from)
+        })
+        .defer(clone!(
+            #[weak(rename_to = obj)]
+            self,
+            move |result| match result {
+                Ok(data) => obj.init(&data),
+                Err(e) => {
+                    log::error!("Error on inspecting container: {e}");
+                    utils::show_error_toast(
+                        &obj,
+                        &gettext("Error on inspecting container"),
+                        &e.to_string(),
+                    );
+                    utils::navigation_view(&obj).pop();
+                }
+            }
+        ));
+    }
+
+    fn init(&self, data: &Value) {
+        let imp = self.imp();
+        let host_config = &data["HostConfig"];
+
+        let privileged = host_config["Privileged"].as_bool().unwrap_or(false);
+        imp.privileged_row.set_subtitle(&if privileged {
+            gettext("The container has all capabilities and access to all devices of the host")
+        } else {
+            gettext("Off")
+        });
+        if privileged {
+            imp.privileged_row.add_css_class("error");
+            imp.privileged_row
+                .add_prefix(&gtk::Image::from_icon_name("dialog-warning-symbolic"));
+        }
+
+        let added = capabilities(&host_config["CapAdd"]);
+        let dropped = capabilities(&host_config["CapDrop"]);
+        let effective = match data["EffectiveCaps"].as_array() {
+            Some(_) => capabilities(&data["EffectiveCaps"]),
+            None => DEFAULT_CAPABILITIES
+                .iter()
+                .map(|capability| capability.to_string())
+                .chain(added.iter().cloned())
+                .filter(|capability| !dropped.contains(capability))
+                .collect(),
+        };
+
+        [
+            Category::Files,
+            Category::Processes,
+            Category::Network,
+            Category::System,
+        ]
+        .into_iter()
+        .for_each(|category| {
+            let capabilities = effective
+                .iter()
+                .filter(|capability| Category::of(capability) == category)
+                .collect::<Vec<_>>();
+            if capabilities.is_empty() {
+                return;
+            }
+
+            let group = adw::PreferencesGroup::builder()
+                .title(category.title())
+                .build();
+            capabilities
+                .into_iter()
+                .for_each(|capability| group.add(&capability_row(capability)));
+            imp.preferences_page.add(&group);
+        });
+
+        if !dropped.is_empty() {
+            let group = adw::PreferencesGroup::builder()
+                .title(gettext("Dropped Capabilities"))
+                .build();
+            dropped.iter().for_each(|capability| {
+                group.add(
+                    &adw::ActionRow::builder()
+                        .title(display_name(capability))
+                        .build(),
+                );
+            });
+            imp.preferences_page.add(&group);
+        }
+
+        let ulimits_group = adw::PreferencesGroup::builder()
+            .title(gettext("Resource Limits"))
+            .build();
+        let ulimits = host_config["Ulimits"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|ulimit| {
+                Some((
+                    ulimit["Name"].as_str()?,
+                    ulimit["Soft"].as_i64(),
+                    ulimit["Hard"].as_i64(),
+                ))
+            })
+            .collect::<Vec<_>>();
+        if ulimits.is_empty() {
+            ulimits_group.set_description(Some(&gettext(
+                "No limits are set on the container, so those of Podman apply",
+            )));
+        }
+        ulimits.into_iter().for_each(|(name, soft, hard)| {
+            ulimits_group.add(
+                &adw::ActionRow::builder()
+                    .title(name.trim_start_matches("RLIMIT_").to_lowercase())
+                    .subtitle(gettext!(
+                        "Soft limit {}, hard limit {}",
+                        format_limit(soft),
+                        format_limit(hard)
+                    ))
+                    .subtitle_selectable(true)
+                    .build(),
+            );
+        });
+        imp.preferences_page.add(&ulimits_group);
+
+        imp.stack.set_visible_child_name("loaded");
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Category {
+    Files,
+    Processes,
+    Network,
+    System,
+}
+
+impl Category {
+    fn of(capability: &str) -> Self {
+        match capability.trim_start_matches("CAP_") {
+            "CHOWN" | "DAC_OVERRIDE" | "DAC_READ_SEARCH" | "FOWNER" | "FSETID" | "SETFCAP"
+            | "LINUX_IMMUTABLE" | "LEASE" | "MKNOD" => Self::Files,
+            "KILL" | "SETUID" | "SETGID" | "SETPCAP" | "SYS_NICE" | "SYS_PTRACE"
+            | "SYS_RESOURCE" | "IPC_LOCK" | "IPC_OWNER" | "SYS_CHROOT" => Self::Processes,
+            name if name.starts_with("NET_") => Self::Network,
+            _ => Self::System,
+        }
+    }
+
+    fn title(&self) -> String {
+        match self {
+            Self::Files => gettext("Files and Ownership"),
+            Self::Processes => gettext("Processes and Identity"),
+            Self::Network => gettext("Network"),
+            Self::System => gettext("System Administration"),
+        }
+    }
+}
+
+/// Explains the capabilities that are commonly used to break out of a container.
+fn risk(capability: &str) -> Option<String> {
+    Some(match capability.trim_start_matches("CAP_") {
+        "SYS_ADMIN" => gettext("Allows mounting file systems and many other privileged operations"),
+        "SYS_MODULE" => gettext("Allows loading kernel modules into the host kernel"),
+        "SYS_RAWIO" => gettext("Allows raw access to devices and I/O ports"),
+        "SYS_PTRACE" => gettext("Allows tracing and inspecting the memory of other processes"),
+        "SYS_BOOT" => gettext("Allows rebooting the host"),
+        "SYS_TIME" => gettext("Allows changing the clock of the host"),
+        "NET_ADMIN" => gettext("Allows reconfiguring network interfaces, routes and firewalls"),
+        "NET_RAW" => gettext("Allows crafting arbitrary network packets"),
+        "DAC_READ_SEARCH" => gettext("Allows reading any file regardless of its permissions"),
+        "MKNOD" => gettext("Allows creating device files"),
+        "BPF" => gettext("Allows loading BPF programs into the kernel"),
+        "MAC_ADMIN" | "MAC_OVERRIDE" => {
+            gettext("Allows changing or bypassing mandatory access control like SELinux")
+        }
+        "AUDIT_CONTROL" => gettext("Allows changing the audit rules of the kernel"),
+        _ => return None,
+    })
+}
+
+fn capability_row(capability: &str) -> adw::ActionRow {
+    let row = adw::ActionRow::builder()
+        .title(display_name(capability))
+        .build();
+
+    if !DEFAULT_CAPABILITIES.contains(&capability) {
+        row.set_subtitle(&gettext("Added beyond the defaults"));
+        row.add_css_class("warning");
+    }
+
+    if let Some(risk) = risk(capability) {
+        row.add_suffix(
+            &gtk::Image::builder()
+                .icon_name("dialog-warning-symbolic")
+                .css_classes(["error"])
+                .tooltip_text(risk)
+                .build(),
+        );
+    }
+
+    row
+}
+
+/// Podman accepts capabilities with or without prefix and in any case.
+fn capabilities(value: &Value) -> BTreeSet<String> {
+    value
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(Value::as_str)
+        .map(|capability| {
+            let capability = capability.to_uppercase();
+            if capability.starts_with("CAP_") || capability == "ALL" {
+                capability
+            } else {
+                format!("CAP_{capability}")
+            }
+        })
+        .collect()
+}
+
+fn display_name(capability: &str) -> String {
+    match capability {
+        "ALL" => gettext("All"),
+        _ => capability.trim_start_matches("CAP_").to_owned(),
+    }
+}
+
+fn format_limit(limit: Option<i64>) -> String {
+    match limit {
+        Some(limit) if limit >= 0 => limit.to_string(),
+        _ => gettext("unlimited"),
+    }
+}
@@ -41,8 +41,20 @@ mod imp {
         #[template_child]
         pub(super) port_bindings_row: TemplateChild<adw::ExpanderRow>,
         #[template_child]
+        pub(super) extra_hosts_row: TemplateChild<widget::PropertyRow>,
+        #[template_child]
         pub(super) port_bindings_label: TemplateChild<gtk::Label>,
         #[template_child]
+        pub(super) namespaces_row: TemplateChild<adw::ExpanderRow>,
+        #[template_child]
+        pub(super) network_mode_row: TemplateChild<widget::PropertyRow>,
+        #[template_child]
+        pub(super) pid_mode_row: TemplateChild<widget::PropertyRow>,
+        #[template_child]
+        pub(super) ipc_mode_row: TemplateChild<widget::PropertyRow>,
+        #[template_child]
+        pub(super) cgroup_parent_row: TemplateChild<widget::PropertyRow>,
+        #[template_child]
         pub(super) health_row: TemplateChild<adw::ActionRow>,
         #[template_child]
         pub(super) health_status_label: TemplateChild<gtk::Label>,
@@ -260,6 +272,60 @@ mod imp {
                 }))
                 .bind(&*self.port_bindings_row, "visible", Some(obj));
 
+            let extra_hosts_expr = data_expr
+                .chain_property::<model::ContainerData>("extra-hosts")
+                .chain_closure::<String>(closure!(|_: Self::Type, hosts: Vec<String>| {
+                    hosts.join(", ")
+                }));
+            extra_hosts_expr.bind(&*self.extra_hosts_row, "value", Some(obj));
+            extra_hosts_expr
+                .chain_closure::<bool>(closure!(|_: Self::Type, hosts: &str| !hosts.is_empty()))
+                .bind(&*self.extra_hosts_row, "visible", Some(obj));
+
+            data_expr
+                .chain_closure::<bool>(closure!(
+                    |_: Self::Type, data: Option<model::ContainerData>| data.is_some()
+                ))
+                .bind(&*self.namespaces_row, "visible", Some(obj));
+
+            let network_mode_expr =
+                data_expr.chain_closure::<String>(closure!(|obj: Self::Type,
+                                                            data: Option<
+                    model::ContainerData,
+                >| {
+                    data.map(|data| network_mode(&obj, &data))
+                        .unwrap_or_default()
+                }));
+            let pid_mode_expr = data_expr
+                .chain_property::<model::ContainerData>("pid-mode")
+                .chain_closure::<String>(closure!(|_: Self::Type, mode: &str| namespace_mode(
+                    mode
+                )));
+            let ipc_mode_expr = data_expr
+                .chain_property::<model::ContainerData>("ipc-mode")
+                .chain_closure::<String>(closure!(|_: Self::Type, mode: &str| namespace_mode(
+                    mode
+                )));
+
+            network_mode_expr.bind(&*self.network_mode_row, "value", Some(obj));
+            pid_mode_expr.bind(&*self.pid_mode_row, "value", Some(obj));
+            ipc_mode_expr.bind(&*self.ipc_mode_row, "value", Some(obj));
+            data_expr
+                .chain_property::<model::ContainerData>("cgroup-parent")
+                .chain_closure::<String>(closure!(|_: Self::Type, parent: &str| {
+                    namespace_mode(parent)
+                }))
+                .bind(&*self.cgroup_parent_row, "value", Some(obj));
+
+            gtk::ClosureExpression::new::<String>(
+                [&network_mode_expr, &pid_mode_expr, &ipc_mode_expr],
+                closure!(|_: Self::Type, network: &str, pid: &str, ipc: &str| {
+                    // Translators: Abbreviations of the network, process and IPC namespaces.
+                    gettext!("net: {} · pid: {} · ipc: {}", network, pid, ipc)
+                }),
+            )
+            .bind(&*self.namespaces_row, "subtitle", Some(obj));
+
             gtk::ClosureExpression::new::<String>(
                 [
                     &ticks_expr,
@@ -375,3 +441,31 @@ glib::wrapper! {
         @extends gtk::Widget, adw::PreferencesGroup,
         @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget;
 }
+
+/// Names the container whose network is shared instead of showing its id, and lists the networks
+/// of bridged containers because Podman reports the same mode for all of them.
+fn network_mode(obj: &ContainerPropertiesGroup, data: &model::ContainerData) -> String {
+    let mode = data.network_mode();
+    match mode.split_once(':') {
+        Some(("container", id)) => format!(
+            "container:{}",
+            obj.container()
+                .and_then(|container| container.container_list())
+                .and_then(|list| list.get_container(id))
+                .map(|container| container.name())
+                .unwrap_or_else(|| utils::format_id(id))
+        ),
+        _ if mode == "bridge" && !data.networks().is_empty() => {
+            format!("bridge ({})", data.networks().join(", "))
+        }
+        _ => namespace_mode(&mode),
+    }
+}
+
+fn namespace_mode(mode: &str) -> String {
+    if mode.is_empty() {
+        gettext("default")
+    } else {
+        mode.to_owned()
+    }
+}
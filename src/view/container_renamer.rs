@@ -22,8 +22,13 @@ mod imp {
         pub(super) container: glib::WeakRef<model::Container>,
         #[property(get, set)]
         pub(super) new_name: RefCell<String>,
+        /// Why the container can't be renamed to the new name, if it can't.
+        #[property(get, nullable)]
+        pub(super) error: RefCell<Option<String>>,
         #[template_child]
         pub(super) entry_row: TemplateChild<widget::RandomNameEntryRow>,
+        #[template_child]
+        pub(super) error_label: TemplateChild<gtk::Label>,
     }
 
     #[glib::object_subclass]
@@ -63,6 +68,7 @@ mod imp {
                 self.entry_row.set_text(&container.name());
             }
 
+            obj.connect_new_name_notify(|obj| obj.update_error());
             self.entry_row
                 .bind_property("text", obj, "new-name")
                 .flags(glib::BindingFlags::SYNC_CREATE | glib::BindingFlags::BIDIRECTIONAL)
@@ -107,3 +113,25 @@ impl From<&model::Container> for ContainerRenamer {
             .build()
     }
 }
+
+impl ContainerRenamer {
+    fn update_error(&self) {
+        let imp = self.imp();
+
+        let error = self
+            .container()
+            .and_then(|container| container.rename_error(&self.new_name()));
+
+        if error.is_some() {
+            imp.entry_row.add_css_class("error");
+        } else {
+            imp.entry_row.remove_css_class("error");
+        }
+        imp.error_label
+            .set_label(error.as_deref().unwrap_or_default());
+        imp.error_label.set_visible(error.is_some());
+
+        imp.error.replace(error);
+        self.notify_error();
+    }
+}
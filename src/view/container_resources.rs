@@ -24,6 +24,10 @@ mod imp {
         #[property(get, set, construct, nullable)]
         pub(super) container: glib::WeakRef<model::Container>,
         #[template_child]
+        pub(super) list_box: TemplateChild<gtk::ListBox>,
+        #[template_child]
+        pub(super) paused_label: TemplateChild<gtk::Label>,
+        #[template_child]
         pub(super) cpu_name_label: TemplateChild<gtk::Label>,
         #[template_child]
         pub(super) cpu_percent_label: TemplateChild<gtk::Label>,
@@ -82,6 +86,18 @@ mod imp {
                 .chain_property::<model::ContainerList>("client")
                 .chain_property::<model::Client>("cpus");
             let stats_expr = container_expr.chain_property::<model::Container>("stats");
+            let paused_expr = container_expr
+                .chain_property::<model::Container>("status")
+                .chain_closure::<bool>(closure!(
+                    |_: Self::Type, status: model::ContainerStatus| {
+                        status == model::ContainerStatus::Paused
+                    }
+                ));
+
+            paused_expr.bind(&*self.paused_label, "visible", Some(obj));
+            paused_expr
+                .chain_closure::<bool>(closure!(|_: Self::Type, paused: bool| !paused))
+                .bind(&*self.list_box, "sensitive", Some(obj));
 
             cpus_expr
                 .chain_closure::<String>(closure!(|_: Self::Type, cpus: i64| {
@@ -279,19 +295,22 @@ impl ContainerResources {
 
         stats_expr
             .chain_closure::<String>(closure_local!(move |_: Self, value: u64| {
-                let s = gettext!(
+                // There are no stats while the container is paused. Start over afterwards instead
+                // of showing the whole counter as the first rate.
+                let rate = if value == 0 {
+                    prev_value.set(u64::MAX);
+                    0
+                } else {
+                    let rate = value.saturating_sub(prev_value.get());
+                    prev_value.set(value);
+                    rate
+                };
+
+                gettext!(
                     // Translators: For example 5 MB / s.
                     "{} / s",
-                    glib::format_size(if prev_value.get() >= value {
-                        0
-                    } else {
-                        value - prev_value.get()
-                    })
-                );
-
-                prev_value.set(value);
-
-                s
+                    glib::format_size(rate)
+                )
             }))
             .bind(label, "label", Some(self));
     }
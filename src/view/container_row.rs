@@ -36,6 +36,8 @@ mod imp {
         #[template_child]
         pub(super) repo_label: TemplateChild<gtk::Label>,
         #[template_child]
+        pub(super) compose_project_label: TemplateChild<gtk::Label>,
+        #[template_child]
         pub(super) ports_wrap_box: TemplateChild<adw::WrapBox>,
         #[template_child]
         pub(super) stats_box: TemplateChild<gtk::Box>,
@@ -163,6 +165,21 @@ mod imp {
             )
             .bind(&*self.name_label, "label", Some(obj));
 
+            let compose_project_expr =
+                container_expr.chain_property::<model::Container>("compose-project");
+            compose_project_expr
+                .chain_closure::<String>(closure!(
+                    |_: Self::Type, compose_project: Option<String>| {
+                        compose_project.unwrap_or_default()
+                    }
+                ))
+                .bind(&*self.compose_project_label, "label", Some(obj));
+            compose_project_expr
+                .chain_closure::<bool>(closure!(
+                    |_: Self::Type, compose_project: Option<String>| compose_project.is_some()
+                ))
+                .bind(&*self.compose_project_label, "visible", Some(obj));
+
             gtk::ClosureExpression::new::<bool>(
                 [
                     &pod_expr,
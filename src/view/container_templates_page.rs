@@ -0,0 +1,254 @@
+use std::cell::RefCell;
+use std::sync::OnceLock;
+
+use adw::prelude::*;
+use adw::subclass::prelude::*;
+use gettextrs::gettext;
+use glib::clone;
+use glib::subclass::Signal;
+use gtk::CompositeTemplate;
+use gtk::glib;
+use indexmap::IndexMap;
+
+use crate::model;
+use crate::utils;
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default, CompositeTemplate)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/view/container_templates_page.ui")]
+    pub(crate) struct ContainerTemplatesPage {
+        pub(super) templates: RefCell<IndexMap<String, model::ContainerTemplate>>,
+        #[template_child]
+        pub(super) stack: TemplateChild<gtk::Stack>,
+        #[template_child]
+        pub(super) list_box: TemplateChild<gtk::ListBox>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for ContainerTemplatesPage {
+        const NAME: &'static str = "PdsContainerTemplatesPage";
+        type Type = super::ContainerTemplatesPage;
+        type ParentType = gtk::Widget;
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.bind_template();
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for ContainerTemplatesPage {
+        fn signals() -> &'static [Signal] {
+            static SIGNALS: OnceLock<Vec<Signal>> = OnceLock::new();
+            SIGNALS.get_or_init(|| {
+                vec![
+                    Signal::builder("template-selected")
+                        .param_types([String::static_type()])
+                        .build(),
+                ]
+            })
+        }
+
+        fn dispose(&self) {
+            utils::unparent_children(&*self.obj());
+        }
+    }
+
+    impl WidgetImpl for ContainerTemplatesPage {}
+}
+
+glib::wrapper! {
+    pub(crate) struct ContainerTemplatesPage(ObjectSubclass<imp::ContainerTemplatesPage>)
+        @extends gtk::Widget,
+        @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget;
+}
+
+impl From<IndexMap<String, model::ContainerTemplate>> for ContainerTemplatesPage {
+    fn from(templates: IndexMap<String, model::ContainerTemplate>) -> Self {
+        let obj: Self = glib::Object::builder().build();
+        obj.imp().templates.replace(templates);
+        obj.update_rows();
+        obj
+    }
+}
+
+impl ContainerTemplatesPage {
+    pub(crate) fn template(&self, name: &str) -> Option<model::ContainerTemplate> {
+        self.imp().templates.borrow().get(name).cloned()
+    }
+
+    fn update_rows(&self) {
+        let imp = self.imp();
+
+        imp.list_box.remove_all();
+        imp.templates.borrow().keys().for_each(|name| {
+            imp.list_box.append(&self.row(name));
+        });
+
+        imp.stack
+            .set_visible_child_name(if imp.templates.borrow().is_empty() {
+                "empty"
+            } else {
+                "templates"
+            });
+    }
+
+    fn row(&self, name: &str) -> adw::ActionRow {
+        let row = adw::ActionRow::builder()
+            .activatable(true)
+            .title(name)
+            .use_markup(false)
+            .build();
+
+        let rename_button = gtk::Button::builder()
+            .css_classes(vec!["flat".to_string()])
+            .icon_name("document-edit-symbolic")
+            .tooltip_text(gettext("Rename"))
+            .valign(gtk::Align::Center)
+            .build();
+        rename_button.connect_clicked(clone!(
+            #[weak(rename_to = obj)]
+            self,
+            #[to_owned]
+            name,
+            move |_| {
+                glib::spawn_future_local(clone!(
+                    #[weak]
+                    obj,
+                    #[strong]
+                    name,
+                    async move {
+                        obj.rename_template(&name).await;
+                    }
+                ));
+            }
+        ));
+        row.add_suffix(&rename_button);
+
+        let delete_button = gtk::Button::builder()
+            .css_classes(vec!["flat".to_string()])
+            .icon_name("user-trash-symbolic")
+            .tooltip_text(gettext("Delete"))
+            .valign(gtk::Align::Center)
+            .build();
+        delete_button.connect_clicked(clone!(
+            #[weak(rename_to = obj)]
+            self,
+            #[to_owned]
+            name,
+            move |_| {
+                glib::spawn_future_local(clone!(
+                    #[weak]
+                    obj,
+                    #[strong]
+                    name,
+                    async move {
+                        obj.delete_template(&name).await;
+                    }
+                ));
+            }
+        ));
+        row.add_suffix(&delete_button);
+
+        row.connect_activated(clone!(
+            #[weak(rename_to = obj)]
+            self,
+            #[to_owned]
+            name,
+            move |_| {
+                obj.emit_by_name::<()>("template-selected", &[&name]);
+            }
+        ));
+
+        row
+    }
+
+    async fn rename_template(&self, name: &str) {
+        let Some(new_name) = ask_template_name(self, &gettext("Rename Template"), name).await
+        else {
+            return;
+        };
+        if new_name == name {
+            return;
+        }
+
+        let imp = self.imp();
+
+        if imp.templates.borrow().contains_key(&new_name) {
+            utils::show_error_toast(
+                self,
+                &gettext("Error on renaming template"),
+                &gettext!("Template '{}' already exists", new_name),
+            );
+            return;
+        }
+
+        {
+            let mut templates = imp.templates.borrow_mut();
+            if let Some(index) = templates.get_index_of(name) {
+                let (_, template) = templates.shift_remove_index(index).unwrap();
+                templates.shift_insert(index, new_name, template);
+            }
+        }
+
+        self.sync().await;
+    }
+
+    async fn delete_template(&self, name: &str) {
+        self.imp().templates.borrow_mut().shift_remove(name);
+        self.sync().await;
+    }
+
+    async fn sync(&self) {
+        let templates = self.imp().templates.borrow().clone();
+        if let Err(e) = model::save_container_templates(&templates).await {
+            utils::show_error_toast(self, &gettext("Error on saving templates"), &e.to_string());
+        }
+        self.update_rows();
+    }
+
+    pub(crate) fn connect_template_selected<F: Fn(&Self, String) + 'static>(
+        &self,
+        f: F,
+    ) -> glib::SignalHandlerId {
+        self.connect_local("template-selected", true, move |values| {
+            let obj = values[0].get::<Self>().unwrap();
+            let name = values[1].get::<String>().unwrap();
+            f(&obj, name);
+
+            None
+        })
+    }
+}
+
+/// Asks for the name of a template. Returns `None` if the user cancels or enters nothing.
+pub(crate) async fn ask_template_name(
+    widget: &impl IsA<gtk::Widget>,
+    heading: &str,
+    initial_name: &str,
+) -> Option<String> {
+    let entry = gtk::Entry::builder()
+        .activates_default(true)
+        .placeholder_text(gettext("Name"))
+        .text(initial_name)
+        .build();
+
+    let dialog = adw::AlertDialog::builder()
+        .heading(heading)
+        .extra_child(&entry)
+        .build();
+
+    dialog.add_responses(&[("cancel", &gettext("_Cancel")), ("save", &gettext("_Save"))]);
+    dialog.set_default_response(Some("save"));
+    dialog.set_response_appearance("save", adw::ResponseAppearance::Suggested);
+
+    if dialog.choose_future(widget).await == "save" {
+        Some(entry.text().trim().to_owned()).filter(|name| !name.is_empty())
+    } else {
+        None
+    }
+}
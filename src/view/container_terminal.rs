@@ -1,6 +1,7 @@
 use std::cell::Cell;
 use std::cell::RefCell;
 use std::sync::OnceLock;
+use std::time::Duration;
 
 use adw::prelude::*;
 use adw::subclass::prelude::*;
@@ -30,6 +31,12 @@ const ACTION_COPY: &str = "container-terminal.copy";
 const ACTION_COPY_HTML: &str = "container-terminal.copy-html";
 const ACTION_PASTE: &str = "container-terminal.paste";
 
+/// The shell that is used if the configured one can't be started.
+const FALLBACK_SHELL: &str = "/bin/sh";
+/// How often and in which interval a started exec is checked for having failed right away.
+const EXEC_CHECKS: u32 = 5;
+const EXEC_CHECK_INTERVAL: Duration = Duration::from_millis(100);
+
 #[derive(Debug)]
 enum ExecInput {
     Data(Vec<u8>),
@@ -47,8 +54,12 @@ mod imp {
         pub(super) settings: utils::PodsSettings,
         pub(super) tx_input: RefCell<Option<tokio::sync::mpsc::UnboundedSender<ExecInput>>>,
         pub(super) keep_alive_on_next_unroot: Cell<bool>,
+        pub(super) restarting: Cell<bool>,
         #[property(get, set, nullable)]
         pub(super) container: glib::WeakRef<model::Container>,
+        /// Overrides the shell from the settings for this session.
+        #[property(get, set, nullable)]
+        pub(super) shell: RefCell<Option<String>>,
         #[template_child]
         pub(super) popover_menu: TemplateChild<gtk::PopoverMenu>,
         #[template_child]
@@ -388,11 +399,48 @@ impl ContainerTerminal {
         self.imp().terminal.set_font_scale(value);
     }
 
+    /// Terminates the current session and starts a new one with the given shell.
+    pub(crate) fn restart_with_shell(&self, shell: &str) {
+        let imp = self.imp();
+
+        self.set_shell(Some(shell));
+
+        if let Some(tx_input) = &*imp.tx_input.borrow()
+            && tx_input.send(ExecInput::Terminate).is_ok()
+        {
+            imp.restarting.set(true);
+        }
+    }
+
     fn setup_tty_connection(&self, container: &model::Container) {
         let imp = self.imp();
 
         let container = container.api().unwrap();
 
+        let shell = self
+            .shell()
+            .unwrap_or_else(|| imp.settings.string("terminal-shell").into());
+        let shell = match shell.trim() {
+            "" => FALLBACK_SHELL.to_owned(),
+            shell => shell.to_owned(),
+        };
+
+        let (tx_fallback, rx_fallback) = tokio::sync::oneshot::channel::<()>();
+        glib::spawn_future_local(clone!(
+            #[weak(rename_to = obj)]
+            self,
+            #[strong]
+            shell,
+            async move {
+                if rx_fallback.await.is_ok() {
+                    utils::show_toast(
+                        &obj,
+                        gettext!("Could not start '{}', using '{}'", shell, FALLBACK_SHELL),
+                    );
+                }
+            }
+        ));
+
         let (tx_output, mut rx_output) = tokio::sync::mpsc::channel::<Vec<u8>>(5);
 
         glib::spawn_future_local(clone!(
@@ -417,19 +465,18 @@ impl ContainerTerminal {
         self.grab_focus();
 
         rt::Promise::new(async move {
-            let opts = podman::opts::ExecCreateOpts::builder()
-                .attach_stderr(true)
-                .attach_stdout(true)
-                .attach_stdin(true)
-                .tty(true)
-                .command(["/bin/sh"])
-                .build();
-            let exec = container.create_exec(&opts).await.unwrap();
-
-            let opts = podman::opts::ExecStartOpts::builder().tty(true).build();
-            let (mut reader, mut writer) = exec.start(&opts).await.unwrap().unwrap().split();
-
-            exec.resize(width as usize, height as usize).await?;
+            let (exec, multiplexer) =
+                match start_exec(&container, &shell, width as usize, height as usize).await {
+                    Ok(started) => started,
+                    Err(e) if shell != FALLBACK_SHELL => {
+                        log::warn!("Error on starting shell '{shell}': {e}");
+                        _ = tx_fallback.send(());
+                        start_exec(&container, FALLBACK_SHELL, width as usize, height as usize)
+                            .await?
+                    }
+                    Err(e) => return Err(e),
+                };
+            let (mut reader, mut writer) = multiplexer.split();
 
             loop {
                 match future::select(Box::pin(rx_input.recv()), reader.next()).await {
@@ -480,7 +527,20 @@ impl ContainerTerminal {
             #[weak(rename_to = obj)]
             self,
             move |result: podman::Result<_>| {
-                if result.is_err() {
+                let imp = obj.imp();
+
+                if imp.restarting.replace(false) {
+                    imp.terminal.reset(true, true);
+                    if let Some(container) = obj
+                        .container()
+                        .filter(|container| container.status() == model::ContainerStatus::Running)
+                    {
+                        obj.setup_tty_connection(&container);
+                    }
+                    return;
+                }
+
+                if let Err(e) = result {
                     utils::show_error_toast(
                         &gio::Application::default()
                             .unwrap()
@@ -489,7 +549,7 @@ impl ContainerTerminal {
                             .main_window()
                             .toast_overlay(),
                         &gettext("Terminal error"),
-                        &gettext("'/bin/sh' not found"),
+                        &e.to_string(),
                     );
                 }
                 obj.emit_by_name::<()>("terminated", &[]);
@@ -540,6 +600,60 @@ impl ContainerTerminal {
     }
 }
 
+/// Creates and starts an interactive exec session running the given shell. Arguments are split the
+/// way a shell would, so that commands like `psql -U postgres` can be run, too.
+///
+/// An error is returned if the shell exits shortly after starting because it doesn't exist in the
+/// container.
+async fn start_exec(
+    container: &podman::api::Container,
+    shell: &str,
+    columns: usize,
+    rows: usize,
+) -> podman::Result<(podman::api::Exec, podman::conn::Multiplexer)> {
+    let command = glib::shell_parse_argv(shell)
+        .map(|argv| {
+            argv.iter()
+                .map(|arg| arg.to_string_lossy().into_owned())
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_else(|_| vec![shell.to_owned()]);
+
+    let opts = podman::opts::ExecCreateOpts::builder()
+        .attach_stderr(true)
+        .attach_stdout(true)
+        .attach_stdin(true)
+        .tty(true)
+        .command(command)
+        .build();
+    let exec = container.create_exec(&opts).await?;
+
+    let opts = podman::opts::ExecStartOpts::builder().tty(true).build();
+    let multiplexer = exec
+        .start(&opts)
+        .await?
+        .ok_or_else(|| podman::Error::StringError(gettext!("'{}' not found", shell)))?;
+
+    exec.resize(columns, rows).await?;
+
+    // The exec may not have failed yet right after starting it, so it is watched for a moment.
+    for _ in 0..EXEC_CHECKS {
+        let inspect = exec.inspect().await?;
+        if inspect.running == Some(false) {
+            if matches!(inspect.exit_code, Some(126 | 127)) {
+                return Err(podman::Error::StringError(gettext!(
+                    "'{}' not found",
+                    shell
+                )));
+            }
+            break;
+        }
+        tokio::time::sleep(EXEC_CHECK_INTERVAL).await;
+    }
+
+    Ok((exec, multiplexer))
+}
+
 fn rgba_from_hex(r: i32, g: i32, b: i32) -> gdk::RGBA {
     gdk::RGBA::new(r as f32 / 255.0, g as f32 / 255.0, b as f32 / 255.0, 0.0)
 }
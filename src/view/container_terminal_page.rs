@@ -1,6 +1,8 @@
 use adw::prelude::*;
 use adw::subclass::prelude::*;
+use gettextrs::gettext;
 use glib::Properties;
+use glib::clone;
 use gtk::CompositeTemplate;
 use gtk::gdk;
 use gtk::glib;
@@ -11,6 +13,7 @@ use crate::view;
 use crate::widget;
 
 const ACTION_PIP_OUT: &str = "container-terminal-page.pip-out";
+const ACTION_RUN_COMMAND: &str = "container-terminal-page.run-command";
 const ACTION_ZOOM_OUT: &str = "container-terminal-page.zoom-out";
 const ACTION_ZOOM_IN: &str = "container-terminal-page.zoom-in";
 const ACTION_ZOOM_NORMAL: &str = "container-terminal-page.zoom-normal";
@@ -48,6 +51,10 @@ mod imp {
                 widget.pip_out();
             });
 
+            klass.install_action(ACTION_RUN_COMMAND, None, |widget, _, _| {
+                widget.run_command();
+            });
+
             klass.install_action(ACTION_ZOOM_OUT, None, |widget, _, _| {
                 widget.imp().terminal.zoom_out();
             });
@@ -182,4 +189,119 @@ impl ContainerTerminalPage {
             window.present();
         }
     }
+
+    fn run_command(&self) {
+        let terminal = &*self.imp().terminal;
+
+        let image = self
+            .container()
+            .and_then(|container| container.image_name())
+            .unwrap_or_default();
+        let history = model::load_exec_history().unwrap_or_else(|e| {
+            log::warn!("Error on loading exec history: {e}");
+            model::ExecHistory::default()
+        });
+
+        let entry = gtk::Entry::builder()
+            .activates_default(true)
+            .placeholder_text("/bin/sh")
+            .text(terminal.shell().unwrap_or_else(|| {
+                utils::PodsSettings::default()
+                    .string("terminal-shell")
+                    .into()
+            }))
+            .build();
+
+        let recent_commands = gtk::StringList::new(
+            &history
+                .get(&image)
+                .map(|commands| commands.iter().map(String::as_str).collect::<Vec<_>>())
+                .unwrap_or_default(),
+        );
+
+        let history_drop_down = gtk::DropDown::builder()
+            .hexpand(true)
+            .model(&recent_commands)
+            .selected(gtk::INVALID_LIST_POSITION)
+            .tooltip_text(gettext("Recent Commands"))
+            .build();
+        history_drop_down.connect_selected_item_notify(clone!(
+            #[weak]
+            entry,
+            move |drop_down| {
+                if let Some(command) = drop_down
+                    .selected_item()
+                    .and_downcast::<gtk::StringObject>()
+                {
+                    entry.set_text(&command.string());
+                }
+            }
+        ));
+
+        let clear_history_button = gtk::Button::builder()
+            .icon_name("edit-clear-all-symbolic")
+            .tooltip_text(gettext("Clear History"))
+            .build();
+
+        let history_box = gtk::Box::builder()
+            .css_classes(["linked"])
+            .visible(recent_commands.n_items() > 0)
+            .build();
+        history_box.append(&history_drop_down);
+        history_box.append(&clear_history_button);
+
+        let content = gtk::Box::builder()
+            .orientation(gtk::Orientation::Vertical)
+            .spacing(12)
+            .build();
+        content.append(&history_box);
+        content.append(&entry);
+
+        let dialog = adw::AlertDialog::builder()
+            .heading(gettext("Run Command"))
+            .body(gettext("The current session will be terminated"))
+            .extra_child(&content)
+            .build();
+
+        dialog.add_responses(&[("cancel", &gettext("_Cancel")), ("run", &gettext("_Run"))]);
+        dialog.set_default_response(Some("run"));
+        dialog.set_response_appearance("run", adw::ResponseAppearance::Suggested);
+
+        clear_history_button.connect_clicked(clone!(
+            #[strong]
+            image,
+            #[weak]
+            history_box,
+            move |_| {
+                history_box.set_visible(false);
+
+                let image = image.clone();
+                glib::spawn_future_local(async move {
+                    let mut history = model::load_exec_history().unwrap_or_default();
+                    if history.shift_remove(&image).is_some() {
+                        _ = model::save_exec_history(&history).await;
+                    }
+                });
+            }
+        ));
+
+        glib::spawn_future_local(clone!(
+            #[weak(rename_to = obj)]
+            self,
+            async move {
+                if dialog.choose_future(&obj).await == "run" {
+                    let command = entry.text();
+                    let command = command.trim();
+                    if !command.is_empty() {
+                        obj.imp().terminal.restart_with_shell(command);
+
+                        // Reload it as it might have been cleared in the meantime.
+                        let mut history = model::load_exec_history().unwrap_or_default();
+                        model::add_to_exec_history(&mut history, &image, command);
+                        _ = model::save_exec_history(&history).await;
+                    }
+                }
+            }
+        ));
+    }
 }
@@ -1,7 +1,12 @@
+use std::cell::Cell;
+
 use adw::prelude::*;
 use adw::subclass::prelude::*;
+use gettextrs::gettext;
 use glib::Properties;
+use glib::clone;
 use gtk::CompositeTemplate;
+use gtk::gdk;
 use gtk::gio;
 use gtk::glib;
 
@@ -17,8 +22,17 @@ mod imp {
     pub(crate) struct ContainersGridView {
         #[property(get, set = Self::set_model, nullable, construct)]
         pub(super) model: glib::WeakRef<gio::ListModel>,
+        /// The stopped containers that are shown in their own collapsible section.
+        #[property(get, set = Self::set_stopped_model, nullable)]
+        pub(super) stopped_model: glib::WeakRef<gio::ListModel>,
+        #[property(get, set)]
+        pub(super) stopped_expanded: Cell<bool>,
         #[template_child]
         pub(super) flow_box: TemplateChild<gtk::FlowBox>,
+        #[template_child]
+        pub(super) stopped_expander: TemplateChild<gtk::Expander>,
+        #[template_child]
+        pub(super) stopped_flow_box: TemplateChild<gtk::FlowBox>,
     }
 
     #[glib::object_subclass]
@@ -29,6 +43,7 @@ mod imp {
 
         fn class_init(klass: &mut Self::Class) {
             klass.bind_template();
+            klass.bind_template_callbacks();
         }
 
         fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
@@ -56,6 +71,23 @@ mod imp {
 
     impl WidgetImpl for ContainersGridView {}
 
+    #[gtk::template_callbacks]
+    impl ContainersGridView {
+        #[template_callback]
+        fn on_flow_box_key_pressed(
+            &self,
+            key: gdk::Key,
+            _: u32,
+            _: gdk::ModifierType,
+            controller: &gtk::EventControllerKey,
+        ) -> glib::Propagation {
+            match controller.widget().and_downcast::<gtk::FlowBox>() {
+                Some(flow_box) => move_focus(&flow_box, key),
+                None => glib::Propagation::Proceed,
+            }
+        }
+    }
+
     impl ContainersGridView {
         pub(super) fn set_model(&self, value: Option<&gio::ListModel>) {
             let obj = &*self.obj();
@@ -63,16 +95,40 @@ mod imp {
                 return;
             }
 
-            self.flow_box.bind_model(value, |item| {
-                gtk::FlowBoxChild::builder()
-                    .focusable(false)
-                    .child(&view::ContainerCard::from(item.downcast_ref().unwrap()))
-                    .build()
-                    .upcast()
-            });
+            self.flow_box.bind_model(value, create_child);
 
             self.model.set(value);
         }
+
+        pub(super) fn set_stopped_model(&self, value: Option<&gio::ListModel>) {
+            let obj = &*self.obj();
+            if obj.stopped_model().as_ref() == value {
+                return;
+            }
+
+            self.stopped_flow_box.bind_model(value, create_child);
+
+            if let Some(model) = value {
+                self.update_stopped_expander(model);
+                model.connect_items_changed(clone!(
+                    #[weak]
+                    obj,
+                    move |model, _, _, _| obj.imp().update_stopped_expander(model)
+                ));
+            } else {
+                self.stopped_expander.set_visible(false);
+            }
+
+            self.stopped_model.set(value);
+        }
+
+        fn update_stopped_expander(&self, model: &gio::ListModel) {
+            let n_items = model.n_items();
+
+            self.stopped_expander.set_visible(n_items > 0);
+            self.stopped_expander
+                .set_label(Some(&gettext!("Stopped ({})", n_items)));
+        }
     }
 }
 
@@ -95,19 +151,95 @@ impl From<Option<&gio::ListModel>> for ContainersGridView {
 }
 
 impl ContainersGridView {
+    /// Selects the containers of all visible cards, including those of the stopped section while
+    /// it is expanded.
     pub(crate) fn select_visible(&self) {
-        (0..)
-            .map(|pos| self.imp().flow_box.child_at_index(pos))
-            .take_while(Option::is_some)
-            .flatten()
-            .for_each(|row| {
-                row.child()
+        let imp = self.imp();
+
+        let children = |flow_box: &gtk::FlowBox| {
+            (0..)
+                .map(|pos| flow_box.child_at_index(pos))
+                .take_while(Option::is_some)
+                .flatten()
+                .collect::<Vec<_>>()
+        };
+
+        let stopped_expanded = self.stopped_expanded();
+        children(&imp.flow_box)
+            .into_iter()
+            .map(|child| (child.is_visible(), child))
+            .chain(
+                children(&imp.stopped_flow_box)
+                    .into_iter()
+                    .map(|child| (stopped_expanded && child.is_visible(), child)),
+            )
+            .for_each(|(visible, child)| {
+                child
+                    .child()
                     .unwrap()
                     .downcast_ref::<view::ContainerCard>()
                     .unwrap()
                     .container()
                     .unwrap()
-                    .set_selected(row.is_visible());
+                    .set_selected(visible);
             });
     }
 }
+
+fn create_child(item: &glib::Object) -> gtk::Widget {
+    gtk::FlowBoxChild::builder()
+        .focusable(false)
+        .child(&view::ContainerCard::from(item.downcast_ref().unwrap()))
+        .build()
+        .upcast()
+}
+
+/// Moves the focus from card to card with the arrow keys. Otherwise, they would first walk
+/// through the buttons of the focused card.
+fn move_focus(flow_box: &gtk::FlowBox, key: gdk::Key) -> glib::Propagation {
+    // Key events of the popovers of a card pass the flow box, too.
+    if flow_box
+        .root()
+        .and_then(|root| root.focus())
+        .is_none_or(|focus| focus.native() != flow_box.native())
+    {
+        return glib::Propagation::Proceed;
+    }
+
+    let Some(child) = flow_box.focus_child().and_downcast::<gtk::FlowBoxChild>() else {
+        return glib::Propagation::Proceed;
+    };
+
+    let next = match key {
+        gdk::Key::Left | gdk::Key::KP_Left => (0..child.index())
+            .rev()
+            .filter_map(|index| flow_box.child_at_index(index))
+            .find(|child| child.is_child_visible()),
+        gdk::Key::Right | gdk::Key::KP_Right => (child.index() + 1..)
+            .map(|index| flow_box.child_at_index(index))
+            .take_while(Option::is_some)
+            .flatten()
+            .find(|child| child.is_child_visible()),
+        gdk::Key::Up | gdk::Key::KP_Up | gdk::Key::Down | gdk::Key::KP_Down => {
+            let Some(bounds) = child.compute_bounds(flow_box) else {
+                return glib::Propagation::Proceed;
+            };
+
+            let offset = flow_box.row_spacing() as f32 + 1.0;
+            let y = if matches!(key, gdk::Key::Up | gdk::Key::KP_Up) {
+                bounds.y() - offset
+            } else {
+                bounds.y() + bounds.height() + offset
+            };
+
+            flow_box.child_at_pos((bounds.x() + bounds.width() / 2.0) as i32, y as i32)
+        }
+        _ => return glib::Propagation::Proceed,
+    };
+
+    if let Some(card) = next.as_ref().and_then(gtk::FlowBoxChild::child) {
+        card.grab_focus();
+    }
+
+    glib::Propagation::Stop
+}
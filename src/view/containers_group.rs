@@ -5,6 +5,7 @@ use adw::subclass::prelude::PreferencesGroupImpl;
 use adw::subclass::prelude::*;
 use glib::Properties;
 use glib::clone;
+use glib::closure;
 use gtk::CompositeTemplate;
 use gtk::glib;
 
@@ -26,6 +27,8 @@ mod imp {
         pub(super) create_container_row: TemplateChild<gtk::ListBoxRow>,
         #[template_child]
         pub(super) list_box: TemplateChild<gtk::ListBox>,
+        #[template_child]
+        pub(super) empty_status_page: TemplateChild<adw::StatusPage>,
     }
 
     #[glib::object_subclass]
@@ -75,6 +78,17 @@ mod imp {
                     .into()
             });
             self.sorter.set(sorter.upcast()).unwrap();
+
+            let obj = &*self.obj();
+
+            let is_empty_expr = Self::Type::this_expression("container-list")
+                .chain_property::<model::AbstractContainerList>("len")
+                .chain_closure::<bool>(closure!(|_: Self::Type, len: u32| len == 0));
+
+            is_empty_expr.bind(&*self.empty_status_page, "visible", Some(obj));
+            is_empty_expr
+                .chain_closure::<bool>(closure!(|_: Self::Type, is_empty: bool| !is_empty))
+                .bind(&*self.list_box, "visible", Some(obj));
         }
     }
 
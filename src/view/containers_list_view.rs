@@ -1,6 +1,10 @@
+use std::cell::Cell;
+
 use adw::prelude::*;
 use adw::subclass::prelude::*;
+use gettextrs::gettext;
 use glib::Properties;
+use glib::clone;
 use gtk::CompositeTemplate;
 use gtk::gio;
 use gtk::glib;
@@ -17,8 +21,17 @@ mod imp {
     pub(crate) struct ContainersListView {
         #[property(get, set = Self::set_model, nullable, construct)]
         pub(super) model: glib::WeakRef<gio::ListModel>,
+        /// The stopped containers that are shown in their own collapsible section.
+        #[property(get, set = Self::set_stopped_model, nullable)]
+        pub(super) stopped_model: glib::WeakRef<gio::ListModel>,
+        #[property(get, set)]
+        pub(super) stopped_expanded: Cell<bool>,
         #[template_child]
         pub(super) list_box: TemplateChild<gtk::ListBox>,
+        #[template_child]
+        pub(super) stopped_group: TemplateChild<adw::PreferencesGroup>,
+        #[template_child]
+        pub(super) stopped_list_box: TemplateChild<gtk::ListBox>,
     }
 
     #[glib::object_subclass]
@@ -49,6 +62,22 @@ mod imp {
             self.derived_property(id, pspec)
         }
 
+        fn constructed(&self) {
+            self.parent_constructed();
+
+            [&*self.list_box, &*self.stopped_list_box]
+                .into_iter()
+                .for_each(|list_box| {
+                    utils::add_type_ahead_find(list_box, |row| {
+                        row.downcast_ref::<view::ContainerRow>()
+                            .unwrap()
+                            .container()
+                            .map(|container| vec![container.name()])
+                            .unwrap_or_default()
+                    });
+                });
+        }
+
         fn dispose(&self) {
             utils::unparent_children(&*self.obj());
         }
@@ -69,6 +98,38 @@ mod imp {
 
             self.model.set(value);
         }
+
+        pub(super) fn set_stopped_model(&self, value: Option<&gio::ListModel>) {
+            let obj = &*self.obj();
+            if obj.stopped_model().as_ref() == value {
+                return;
+            }
+
+            self.stopped_list_box.bind_model(value, |item| {
+                view::ContainerRow::from(item.downcast_ref().unwrap()).upcast()
+            });
+
+            if let Some(model) = value {
+                self.update_stopped_group(model);
+                model.connect_items_changed(clone!(
+                    #[weak]
+                    obj,
+                    move |model, _, _, _| obj.imp().update_stopped_group(model)
+                ));
+            } else {
+                self.stopped_group.set_visible(false);
+            }
+
+            self.stopped_model.set(value);
+        }
+
+        fn update_stopped_group(&self, model: &gio::ListModel) {
+            let n_items = model.n_items();
+
+            self.stopped_group.set_visible(n_items > 0);
+            self.stopped_group
+                .set_title(&gettext!("Stopped ({})", n_items));
+        }
     }
 }
 
@@ -91,17 +152,34 @@ impl From<Option<&gio::ListModel>> for ContainersListView {
 }
 
 impl ContainersListView {
+    /// Selects the containers of all visible rows, including those of the stopped section while it
+    /// is expanded.
     pub(crate) fn select_visible(&self) {
-        (0..)
-            .map(|pos| self.imp().list_box.row_at_index(pos))
-            .take_while(Option::is_some)
-            .flatten()
-            .for_each(|row| {
+        let imp = self.imp();
+
+        let rows = |list_box: &gtk::ListBox| {
+            (0..)
+                .map(|pos| list_box.row_at_index(pos))
+                .take_while(Option::is_some)
+                .flatten()
+                .collect::<Vec<_>>()
+        };
+
+        let stopped_expanded = self.stopped_expanded();
+        rows(&imp.list_box)
+            .into_iter()
+            .map(|row| (row.is_visible(), row))
+            .chain(
+                rows(&imp.stopped_list_box)
+                    .into_iter()
+                    .map(|row| (stopped_expanded && row.is_visible(), row)),
+            )
+            .for_each(|(visible, row)| {
                 row.downcast_ref::<view::ContainerRow>()
                     .unwrap()
                     .container()
                     .unwrap()
-                    .set_selected(row.is_visible());
+                    .set_selected(visible);
             });
     }
 }
@@ -1,6 +1,7 @@
 use std::cell::Cell;
 use std::cell::OnceCell;
 use std::cell::RefCell;
+use std::collections::BTreeSet;
 use std::ops::Deref;
 
 use adw::prelude::*;
@@ -35,11 +36,14 @@ const ACTION_START_OR_RESUME_SELECTION: &str = "containers-panel.start-or-resume
 const ACTION_STOP_SELECTION: &str = "containers-panel.stop-selection";
 const ACTION_PAUSE_SELECTION: &str = "containers-panel.pause-selection";
 const ACTION_DELETE_SELECTION: &str = "containers-panel.delete-selection";
+const ACTION_COPY_INSPECTION_SELECTION: &str = "containers-panel.copy-inspection-selection";
 const ACTION_TOGGLE_SORT_DIRECTION: &str = "containers-panel.toggle-sort-direction";
 const ACTION_CHANGE_SORT_ATTRIBUTE: &str = "containers-panel.change-sort-attribute";
 const ACTION_TOGGLE_SHOW_RUNNING_CONTAINERS_FIRST: &str =
     "containers-panel.toggle-show-running-containers-first";
 const ACTION_SHOW_ALL_CONTAINERS: &str = "containers-panel.show-all-containers";
+const ACTION_FILTER_COMPOSE_PROJECT: &str = "containers-panel.filter-compose-project";
+const ACTION_SELECT_COMPOSE_PROJECT: &str = "containers-panel.select-compose-project";
 
 const ACTIONS_SELECTION: &[&str] = &[
     ACTION_KILL_SELECTION,
@@ -89,6 +93,13 @@ impl ContainersView {
         }
     }
 
+    fn set_stopped_model(&self, model: Option<&gio::ListModel>) {
+        match self {
+            Self::Grid(view) => view.set_stopped_model(model),
+            Self::List(view) => view.set_stopped_model(model),
+        }
+    }
+
     pub(crate) fn select_visible(&self) {
         match self {
             Self::Grid(view) => view.select_visible(),
@@ -125,11 +136,15 @@ mod imp {
     #[template(resource = "/com/github/marhkb/Pods/ui/view/containers_panel.ui")]
     pub(crate) struct ContainersPanel {
         pub(super) settings: Settings,
+        pub(super) pods_settings: utils::PodsSettings,
         pub(super) containers_view: RefCell<Option<ContainersView>>,
         pub(super) filter: OnceCell<gtk::Filter>,
+        pub(super) stopped_filter: OnceCell<gtk::Filter>,
         pub(super) sorter: OnceCell<gtk::Sorter>,
         pub(super) search_term: RefCell<String>,
         pub(super) model: RefCell<Option<gio::ListModel>>,
+        pub(super) stopped_model: RefCell<Option<gio::ListModel>>,
+        pub(super) collapse_source: RefCell<Option<glib::SourceId>>,
         #[property(get, set = Self::set_container_list, nullable)]
         pub(super) container_list: glib::WeakRef<model::ContainerList>,
         #[property(get, set)]
@@ -140,6 +155,12 @@ mod imp {
         pub(super) sort_attribute: RefCell<SortAttribute>,
         #[property(get, set)]
         pub(super) show_running_containers_first: Cell<bool>,
+        #[property(get, set)]
+        pub(super) compose_project: RefCell<String>,
+        #[property(get, set)]
+        pub(super) stopped_expanded: Cell<bool>,
+        #[template_child]
+        pub(super) compose_projects_section: TemplateChild<gio::Menu>,
         #[template_child]
         pub(super) create_container_button: TemplateChild<gtk::Button>,
         #[template_child]
@@ -171,6 +192,8 @@ mod imp {
         #[template_child]
         pub(super) search_entry: TemplateChild<gtk::SearchEntry>,
         #[template_child]
+        pub(super) compose_project_banner: TemplateChild<adw::Banner>,
+        #[template_child]
         pub(super) filter_stack: TemplateChild<gtk::Stack>,
         #[template_child]
         pub(super) containers_view_bin: TemplateChild<adw::Bin>,
@@ -249,6 +272,9 @@ mod imp {
             klass.install_action(ACTION_DELETE_SELECTION, None, |widget, _, _| {
                 widget.delete_selection();
             });
+            klass.install_action(ACTION_COPY_INSPECTION_SELECTION, None, |widget, _, _| {
+                widget.copy_inspection_selection();
+            });
 
             klass.install_action(ACTION_TOGGLE_SORT_DIRECTION, None, |widget, _, _| {
                 widget.toggle_sort_direction();
@@ -262,6 +288,11 @@ mod imp {
             klass.install_action(ACTION_SHOW_ALL_CONTAINERS, None, |widget, _, _| {
                 widget.show_all_containers();
             });
+
+            klass.install_property_action(ACTION_FILTER_COMPOSE_PROJECT, "compose-project");
+            klass.install_action(ACTION_SELECT_COMPOSE_PROJECT, None, |widget, _, _| {
+                widget.select_compose_project();
+            });
         }
 
         fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
@@ -309,6 +340,26 @@ mod imp {
                 .bind("show-running-first", obj, "show-running-containers-first")
                 .build();
 
+            [
+                "collapse-stopped-containers",
+                "collapse-stopped-containers-delay",
+            ]
+            .into_iter()
+            .for_each(|key| {
+                self.pods_settings.connect_changed(
+                    Some(key),
+                    clone!(
+                        #[weak]
+                        obj,
+                        move |_, _| {
+                            let imp = obj.imp();
+                            imp.update_filter(gtk::FilterChange::Different);
+                            imp.schedule_collapse();
+                        }
+                    ),
+                );
+            });
+
             let container_list_expr = Self::Type::this_expression("container-list");
             let container_list_containers_expr =
                 container_list_expr.chain_property::<model::ContainerList>("containers");
@@ -319,6 +370,19 @@ mod imp {
             ));
             let collapsed_expr = Self::Type::this_expression("collapsed");
 
+            Self::Type::this_expression("container-list")
+                .chain_property::<model::ContainerList>("client")
+                .chain_property::<model::Client>("connection")
+                .chain_property::<model::Connection>("read-only")
+                .watch(
+                    Some(obj),
+                    clone!(
+                        #[weak]
+                        obj,
+                        move || obj.imp().update_actions()
+                    ),
+                );
+
             gtk::ClosureExpression::new::<Option<String>>(
                 [
                     &container_list_containers_expr,
@@ -401,6 +465,27 @@ mod imp {
 
             not_selection_mode_expr.bind(&self.search_bar.get(), "visible", Some(obj));
 
+            let compose_project_expr = Self::Type::this_expression("compose-project");
+
+            gtk::ClosureExpression::new::<bool>(
+                [
+                    compose_project_expr.upcast_ref(),
+                    not_selection_mode_expr.upcast_ref(),
+                ],
+                closure!(
+                    |_: Self::Type, compose_project: &str, not_selection_mode: bool| {
+                        !compose_project.is_empty() && not_selection_mode
+                    }
+                ),
+            )
+            .bind(&self.compose_project_banner.get(), "revealed", Some(obj));
+
+            compose_project_expr
+                .chain_closure::<String>(closure!(|_: Self::Type, compose_project: &str| {
+                    gettext!("Compose project “{}”", compose_project)
+                }))
+                .bind(&self.compose_project_banner.get(), "title", Some(obj));
+
             gtk::ClosureExpression::new::<bool>(
                 [
                     collapsed_expr.upcast_ref(),
@@ -423,14 +508,14 @@ mod imp {
             )
             .bind(&self.toolbar_view.get(), "reveal-bottom-bars", Some(obj));
 
-            let filter = gtk::EveryFilter::new();
-            filter.append(
+            let base_filter = gtk::EveryFilter::new();
+            base_filter.append(
                 gtk::BoolFilter::builder()
                     .expression(model::Container::this_expression("is-infra"))
                     .invert(true)
                     .build(),
             );
-            filter.append(gtk::CustomFilter::new(clone!(
+            base_filter.append(gtk::CustomFilter::new(clone!(
                 #[weak]
                 obj,
                 #[upgrade_or]
@@ -452,6 +537,44 @@ mod imp {
                     }
                 }
             )));
+            base_filter.append(gtk::CustomFilter::new(clone!(
+                #[weak]
+                obj,
+                #[upgrade_or]
+                false,
+                move |item| {
+                    let compose_project = &*obj.imp().compose_project.borrow();
+
+                    compose_project.is_empty()
+                        || item
+                            .downcast_ref::<model::Container>()
+                            .unwrap()
+                            .compose_project()
+                            .as_deref()
+                            == Some(compose_project)
+                }
+            )));
+
+            // Containers that have been stopped for a while go to a collapsible section.
+            let filter = gtk::EveryFilter::new();
+            filter.append(base_filter.clone());
+            filter.append(gtk::CustomFilter::new(clone!(
+                #[weak]
+                obj,
+                #[upgrade_or]
+                false,
+                move |item| !obj.imp().is_collapsed(item.downcast_ref().unwrap())
+            )));
+
+            let stopped_filter = gtk::EveryFilter::new();
+            stopped_filter.append(base_filter);
+            stopped_filter.append(gtk::CustomFilter::new(clone!(
+                #[weak]
+                obj,
+                #[upgrade_or]
+                false,
+                move |item| obj.imp().is_collapsed(item.downcast_ref().unwrap())
+            )));
 
             let sorter = gtk::CustomSorter::new(clone!(
                 #[weak]
@@ -475,10 +598,14 @@ mod imp {
             ));
 
             self.filter.set(filter.upcast()).unwrap();
+            self.stopped_filter.set(stopped_filter.upcast()).unwrap();
             self.sorter.set(sorter.upcast()).unwrap();
         }
 
         fn dispose(&self) {
+            if let Some(source_id) = self.collapse_source.take() {
+                source_id.remove();
+            }
             utils::unparent_children(&*self.obj());
         }
     }
@@ -513,6 +640,7 @@ mod imp {
         }
 
         fn set_containers_view(&self) {
+            let obj = &*self.obj();
             let model = self.model.borrow();
             let view = if self.settings.string("view") == "grid" {
                 self.view_button.set_icon_name("view-list-symbolic");
@@ -530,6 +658,12 @@ mod imp {
                 ContainersView::List(view::ContainersListView::from(model.as_ref()))
             };
 
+            view.set_stopped_model(self.stopped_model.borrow().as_ref());
+            obj.bind_property("stopped-expanded", view.view(), "stopped-expanded")
+                .sync_create()
+                .bidirectional()
+                .build();
+
             self.containers_view_bin.set_child(Some(view.view()));
             self.containers_view.replace(Some(view));
         }
@@ -581,10 +715,19 @@ mod imp {
             self.update_sorter();
         }
 
+        #[template_callback]
+        fn on_notify_compose_project(&self) {
+            self.update_filter(gtk::FilterChange::Different);
+        }
+
         #[template_callback]
         fn on_notify_search_mode_enabled(&self) {
             if self.search_bar.is_search_mode() {
                 self.search_entry.grab_focus();
+            } else {
+                self.search_entry.set_text("");
+                self.containers_view_bin
+                    .child_focus(gtk::DirectionType::TabForward);
             }
         }
 
@@ -608,20 +751,12 @@ mod imp {
                 return;
             }
 
-            ACTIONS_SELECTION
-                .iter()
-                .for_each(|action_name| obj.action_set_enabled(action_name, false));
-
             value.connect_notify_local(
                 Some("num-selected"),
                 clone!(
                     #[weak]
                     obj,
-                    move |list, _| {
-                        ACTIONS_SELECTION.iter().for_each(|action_name| {
-                            obj.action_set_enabled(action_name, list.num_selected() > 0);
-                        });
-                    }
+                    move |_, _| obj.imp().update_actions()
                 ),
             );
 
@@ -630,10 +765,21 @@ mod imp {
                 clone!(
                     #[weak]
                     obj,
-                    move |_, _| obj.imp().update_filter(gtk::FilterChange::Different)
+                    move |_, _| {
+                        let imp = obj.imp();
+                        imp.update_filter(gtk::FilterChange::Different);
+                        imp.schedule_collapse();
+                    }
                 ),
             );
 
+            self.update_compose_projects_section(value);
+            value.connect_items_changed(clone!(
+                #[weak]
+                obj,
+                move |list, _, _, _| obj.imp().update_compose_projects_section(list)
+            ));
+
             value.connect_container_name_changed(clone!(
                 #[weak]
                 obj,
@@ -657,47 +803,61 @@ mod imp {
                 )),
                 self.sorter.get().cloned(),
             );
+            let stopped_model = gtk::SortListModel::new(
+                Some(gtk::FilterListModel::new(
+                    Some(value.to_owned()),
+                    self.stopped_filter.get().cloned(),
+                )),
+                self.sorter.get().cloned(),
+            );
 
             if let Some(view) = &*self.containers_view.borrow() {
                 view.set_model(Some(model.upcast_ref()));
+                view.set_stopped_model(Some(stopped_model.upcast_ref()));
             }
 
-            self.set_filter_stack_visible_child(value, &model);
-            model.connect_items_changed(clone!(
-                #[weak]
-                obj,
-                #[weak]
-                value,
-                move |model, _, removed, _| {
-                    obj.imp().set_filter_stack_visible_child(&value, model);
+            self.model.replace(Some(model.clone().upcast()));
+            self.stopped_model
+                .replace(Some(stopped_model.clone().upcast()));
+
+            self.set_filter_stack_visible_child(value);
+            [model, stopped_model].into_iter().for_each(|model| {
+                model.connect_items_changed(clone!(
+                    #[weak]
+                    obj,
+                    #[weak]
+                    value,
+                    move |_, _, removed, _| {
+                        obj.imp().set_filter_stack_visible_child(&value);
 
-                    if removed > 0 {
-                        obj.deselect_hidden_containers(model.upcast_ref());
+                        if removed > 0 {
+                            obj.deselect_hidden_containers();
+                        }
                     }
-                }
-            ));
+                ));
+            });
             value.connect_initialized_notify(clone!(
                 #[weak]
                 obj,
-                #[weak]
-                model,
-                move |container_list| obj
-                    .imp()
-                    .set_filter_stack_visible_child(container_list, &model)
+                move |container_list| {
+                    let imp = obj.imp();
+                    imp.set_filter_stack_visible_child(container_list);
+                    imp.schedule_collapse();
+                }
             ));
 
-            self.model.replace(Some(model.upcast()));
-
             self.container_list.set(Some(value));
+            self.update_actions();
         }
 
-        fn set_filter_stack_visible_child(
-            &self,
-            container_list: &model::ContainerList,
-            model: &impl IsA<gio::ListModel>,
-        ) {
+        fn set_filter_stack_visible_child(&self, container_list: &model::ContainerList) {
+            let n_items = [&self.model, &self.stopped_model]
+                .into_iter()
+                .filter_map(|model| model.borrow().as_ref().map(gio::ListModel::n_items))
+                .sum::<u32>();
+
             self.filter_stack.set_visible_child_name(
-                if model.n_items() > 0 || !container_list.initialized() {
+                if n_items > 0 || !container_list.initialized() {
                     "containers"
                 } else {
                     "empty"
@@ -705,10 +865,122 @@ mod imp {
             );
         }
 
+        fn update_compose_projects_section(&self, container_list: &model::ContainerList) {
+            let compose_projects = container_list
+                .iter::<model::Container>()
+                .map(Result::unwrap)
+                .filter_map(|container| container.compose_project())
+                .collect::<BTreeSet<_>>();
+
+            self.compose_projects_section.remove_all();
+
+            if compose_projects.is_empty() {
+                return;
+            }
+
+            let menu = gio::Menu::new();
+            menu.append_item(&compose_project_menu_item(&gettext("All"), ""));
+            compose_projects.iter().for_each(|compose_project| {
+                menu.append_item(&compose_project_menu_item(
+                    &compose_project.replace('_', "__"),
+                    compose_project,
+                ));
+            });
+
+            self.compose_projects_section
+                .append_submenu(Some(&gettext("Compose _Project")), &menu);
+        }
+
+        /// Disables all actions that modify the Podman host while the connection is read-only.
+        fn update_actions(&self) {
+            let obj = &*self.obj();
+            let container_list = obj.container_list();
+
+            let read_only = container_list
+                .as_ref()
+                .and_then(model::ContainerList::client)
+                .is_some_and(|client| client.connection().read_only());
+            let any_selected = container_list.is_some_and(|list| list.num_selected() > 0);
+
+            ACTIONS_SELECTION.iter().for_each(|action_name| {
+                obj.action_set_enabled(action_name, any_selected && !read_only);
+            });
+            obj.action_set_enabled(ACTION_COPY_INSPECTION_SELECTION, any_selected);
+
+            obj.action_set_enabled(ACTION_CREATE_CONTAINER, !read_only);
+            obj.action_set_enabled(ACTION_PRUNE_UNUSED_CONTAINERS, !read_only);
+        }
+
         fn update_filter(&self, filter_change: gtk::FilterChange) {
-            if let Some(filter) = self.filter.get() {
-                filter.changed(filter_change);
+            [&self.filter, &self.stopped_filter]
+                .into_iter()
+                .filter_map(OnceCell::get)
+                .for_each(|filter| filter.changed(filter_change));
+        }
+
+        /// Whether the container has been stopped long enough to be moved into the collapsible
+        /// section of stopped containers.
+        fn is_collapsed(&self, container: &model::Container) -> bool {
+            if !self.pods_settings.boolean("collapse-stopped-containers") || !container.is_stopped()
+            {
+                return false;
             }
+
+            let stopped_at = stopped_at(container);
+            stopped_at == 0
+                || utils::timespan_now(stopped_at).as_seconds() >= self.collapse_delay() as i64
+        }
+
+        fn collapse_delay(&self) -> u32 {
+            self.pods_settings.uint("collapse-stopped-containers-delay") * 60
+        }
+
+        /// Newly stopped containers stay among the running ones until the delay has passed. A
+        /// single timer is armed for the container that is due next and rearmed once it fired.
+        fn schedule_collapse(&self) {
+            if let Some(source_id) = self.collapse_source.take() {
+                source_id.remove();
+            }
+
+            if !self.pods_settings.boolean("collapse-stopped-containers") {
+                return;
+            }
+
+            let obj = &*self.obj();
+            let Some(container_list) = obj.container_list() else {
+                return;
+            };
+
+            let now = glib::DateTime::now_utc().unwrap().to_unix();
+            let delay = self.collapse_delay() as i64;
+
+            let Some(due_at) = container_list
+                .iter::<model::Container>()
+                .map(Result::unwrap)
+                .filter(|container| container.is_stopped())
+                .map(|container| stopped_at(&container))
+                .filter(|stopped_at| *stopped_at > 0)
+                .map(|stopped_at| stopped_at + delay)
+                .filter(|due_at| *due_at > now)
+                .min()
+            else {
+                return;
+            };
+
+            self.collapse_source
+                .replace(Some(glib::timeout_add_seconds_local_once(
+                    (due_at - now) as u32 + 1,
+                    clone!(
+                        #[weak]
+                        obj,
+                        move || {
+                            let imp = obj.imp();
+                            imp.collapse_source.take();
+                            imp.update_filter(gtk::FilterChange::Different);
+                            imp.schedule_collapse();
+                        }
+                    ),
+                )));
         }
 
         pub(super) fn update_sorter(&self) {
@@ -749,6 +1021,7 @@ impl ContainersPanel {
 
     pub(crate) fn show_all_containers(&self) {
         self.set_show_running_containers_first(false);
+        self.set_compose_project("");
         self.set_search_mode(false);
     }
 
@@ -756,8 +1029,13 @@ impl ContainersPanel {
         self.imp().search_bar.set_search_mode(value);
     }
 
-    pub(crate) fn toggle_search_mode(&self) {
-        self.set_search_mode(!self.imp().search_bar.is_search_mode());
+    pub(crate) fn focus_search(&self) {
+        let imp = self.imp();
+        if imp.search_bar.is_search_mode() {
+            imp.search_entry.grab_focus();
+        } else {
+            self.set_search_mode(true);
+        }
     }
 
     pub(crate) fn create_container(&self) {
@@ -811,6 +1089,15 @@ impl ContainersPanel {
         }
     }
 
+    pub(crate) fn select_compose_project(&self) {
+        if self.compose_project().is_empty() {
+            return;
+        }
+
+        self.enter_selection_mode();
+        self.select_visible();
+    }
+
     pub(crate) fn select_none(&self) {
         if let Some(list) = self
             .container_list()
@@ -947,6 +1234,27 @@ impl ContainersPanel {
         }
     }
 
+    pub(crate) fn copy_inspection_selection(&self) {
+        let Some(list) = self.container_list() else {
+            return;
+        };
+
+        view::inspection::copy_combined_inspection(
+            self,
+            list.selected_items()
+                .iter()
+                .map(|obj| obj.downcast_ref::<model::Container>().unwrap())
+                .filter_map(|container| {
+                    container.api().map(|api| {
+                        view::inspection::Inspection::new(container.id(), async move {
+                            api.inspect().await
+                        })
+                    })
+                })
+                .collect(),
+        );
+    }
+
     pub(crate) fn delete_selection(&self) {
         if self
             .container_list()
@@ -957,6 +1265,11 @@ impl ContainersPanel {
             return;
         }
 
+        if !utils::confirm_destructive_actions() {
+            self.delete_selected();
+            return;
+        }
+
         let dialog = adw::AlertDialog::builder()
             .heading(gettext("Confirm Forced Deletion of Multiple Containers"))
             .body_use_markup(true)
@@ -977,31 +1290,8 @@ impl ContainersPanel {
             clone!(
                 #[weak(rename_to = obj)]
                 self,
-                move |_, response| if response == "delete"
-                    && let Some(list) = obj.container_list()
-                {
-                    list.selected_items()
-                        .iter()
-                        .map(|obj| obj.downcast_ref::<model::Container>().unwrap())
-                        .for_each(|container| {
-                            container.delete(
-                                true,
-                                clone!(
-                                    #[weak]
-                                    obj,
-                                    move |result| {
-                                        if let Err(e) = result {
-                                            utils::show_error_toast(
-                                                &obj,
-                                                &gettext("Error on deleting container"),
-                                                &e.to_string(),
-                                            );
-                                        }
-                                    }
-                                ),
-                            );
-                        });
-                    list.set_selection_mode(false);
+                move |_, response| if response == "delete" {
+                    obj.delete_selected();
                 }
             ),
         );
@@ -1009,11 +1299,49 @@ impl ContainersPanel {
         dialog.present(Some(self));
     }
 
-    fn deselect_hidden_containers(&self, model: &gio::ListModel) {
-        let visible_containers = model
-            .iter::<glib::Object>()
-            .map(Result::unwrap)
-            .map(|item| item.downcast::<model::Container>().unwrap())
+    fn delete_selected(&self) {
+        let Some(list) = self.container_list() else {
+            return;
+        };
+
+        list.selected_items()
+            .iter()
+            .map(|obj| obj.downcast_ref::<model::Container>().unwrap())
+            .for_each(|container| {
+                container.delete(
+                    true,
+                    false,
+                    clone!(
+                        #[weak(rename_to = obj)]
+                        self,
+                        move |result| {
+                            if let Err(e) = result {
+                                utils::show_error_toast(
+                                    &obj,
+                                    &gettext("Error on deleting container"),
+                                    &e.to_string(),
+                                );
+                            }
+                        }
+                    ),
+                );
+            });
+        list.set_selection_mode(false);
+    }
+
+    fn deselect_hidden_containers(&self) {
+        let imp = self.imp();
+
+        let visible_containers = [&imp.model, &imp.stopped_model]
+            .into_iter()
+            .filter_map(|model| model.borrow().clone())
+            .flat_map(|model| {
+                model
+                    .iter::<glib::Object>()
+                    .map(Result::unwrap)
+                    .map(|item| item.downcast::<model::Container>().unwrap())
+                    .collect::<Vec<_>>()
+            })
             .collect::<Vec<_>>();
 
         self.container_list()
@@ -1028,3 +1356,21 @@ impl ContainersPanel {
             });
     }
 }
+
+fn compose_project_menu_item(label: &str, compose_project: &str) -> gio::MenuItem {
+    let item = gio::MenuItem::new(Some(label), None);
+    item.set_action_and_target_value(
+        Some(ACTION_FILTER_COMPOSE_PROJECT),
+        Some(&compose_project.to_variant()),
+    );
+    item
+}
+
+/// The time the container has been stopped at. Containers that have never been started have no
+/// exit time, so their creation time is used instead.
+fn stopped_at(container: &model::Container) -> i64 {
+    match container.exited_at() {
+        0 => container.created(),
+        exited_at => exited_at,
+    }
+}
@@ -87,7 +87,7 @@ impl ContainersPrunePage {
             podman::opts::ContainerPruneOpts::builder()
                 .filter(if imp.prune_until_row.enables_expansion() {
                     Some(podman::opts::ContainerPruneFilter::Until(
-                        imp.prune_until_row.prune_until_timestamp().to_string(),
+                        imp.prune_until_row.timestamp().to_string(),
                     ))
                 } else {
                     None
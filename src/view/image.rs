@@ -14,43 +14,60 @@ where
     W: IsA<gtk::Widget> + Downgrade<Weak = glib::WeakRef<W>>,
 {
     if let Some(image) = image {
-        match image.container_list().get(0) {
-            Some(container) => {
-                let dialog = adw::AlertDialog::builder()
-                    .heading(gettext("Confirm Image Deletion"))
-                    .body_use_markup(true)
-                    .body(gettext!(
-                        // Translators: The "{}" is a placeholder for the container name.
-                        "Image is used by container <b>{}</b>. Deleting the image will also delete all its associated containers.",
-                        container.name(),
-                    ))
-                    .build();
-
-                dialog.add_responses(&[
-                    ("cancel", &gettext("_Cancel")),
-                    ("delete", &gettext("_Delete")),
-                ]);
-                dialog.set_default_response(Some("cancel"));
-                dialog.set_response_appearance("delete", adw::ResponseAppearance::Destructive);
+        if !utils::confirm_destructive_actions() {
+            delete_image(widget, &image);
+            return;
+        }
 
-                dialog.choose(
-                    widget,
-                    gio::Cancellable::NONE,
-                    clone!(
-                        #[weak]
-                        widget,
-                        #[weak]
-                        image,
-                        move |response| {
-                            if response == "delete" {
-                                delete_image(&widget, &image);
-                            }
-                        }
-                    ),
-                );
+        let (heading, body) = match image.container_list().get(0) {
+            Some(container) => (
+                gettext("Confirm Image Deletion"),
+                gettext!(
+                    // Translators: The "{}" is a placeholder for the container name.
+                    "Image is used by container <b>{}</b>. Deleting the image will also delete all its associated containers.",
+                    container.name(),
+                ),
+            ),
+            None if image.is_manifest_list() => (
+                gettext("Confirm Manifest List Deletion"),
+                gettext(
+                    "Only the manifest list will be deleted. The images it refers to for each platform are kept.",
+                ),
+            ),
+            None => {
+                delete_image(widget, &image);
+                return;
             }
-            None => delete_image(widget, &image),
-        }
+        };
+
+        let dialog = adw::AlertDialog::builder()
+            .heading(heading)
+            .body_use_markup(true)
+            .body(body)
+            .build();
+
+        dialog.add_responses(&[
+            ("cancel", &gettext("_Cancel")),
+            ("delete", &gettext("_Delete")),
+        ]);
+        dialog.set_default_response(Some("cancel"));
+        dialog.set_response_appearance("delete", adw::ResponseAppearance::Destructive);
+
+        dialog.choose(
+            widget,
+            gio::Cancellable::NONE,
+            clone!(
+                #[weak]
+                widget,
+                #[weak]
+                image,
+                move |response| {
+                    if response == "delete" {
+                        delete_image(&widget, &image);
+                    }
+                }
+            ),
+        );
     }
 }
 
@@ -74,6 +91,27 @@ where
     ));
 }
 
+pub(crate) fn refresh<W>(widget: &W, image: Option<model::Image>)
+where
+    W: IsA<gtk::Widget> + Downgrade<Weak = glib::WeakRef<W>>,
+{
+    if let Some(image) = image {
+        image.refresh(clone!(
+            #[weak]
+            widget,
+            move |result| {
+                if let Err(e) = result {
+                    utils::show_error_toast(
+                        &widget,
+                        &gettext("Error on refreshing image"),
+                        &e.to_string(),
+                    );
+                }
+            }
+        ));
+    }
+}
+
 pub(crate) fn create_container<W: IsA<gtk::Widget>>(widget: &W, image: Option<model::Image>) {
     if let Some(image) = image {
         utils::Dialog::new(widget, &view::ContainerCreationPage::from(&image)).present();
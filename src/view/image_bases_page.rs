@@ -0,0 +1,325 @@
+use std::cell::Cell;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use adw::prelude::*;
+use adw::subclass::prelude::*;
+use gettextrs::gettext;
+use gettextrs::ngettext;
+use glib::Properties;
+use glib::clone;
+use gtk::CompositeTemplate;
+use gtk::glib;
+
+use crate::model;
+use crate::podman;
+use crate::utils;
+use crate::view;
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default, Properties, CompositeTemplate)]
+    #[properties(wrapper_type = super::ImageBasesPage)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/view/image_bases_page.ui")]
+    pub(crate) struct ImageBasesPage {
+        #[property(get, set, construct_only, nullable)]
+        pub(super) client: glib::WeakRef<model::Client>,
+        #[template_child]
+        pub(super) stack: TemplateChild<gtk::Stack>,
+        #[template_child]
+        pub(super) preferences_page: TemplateChild<adw::PreferencesPage>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for ImageBasesPage {
+        const NAME: &'static str = "PdsImageBasesPage";
+        type Type = super::ImageBasesPage;
+        type ParentType = gtk::Widget;
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.bind_template();
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for ImageBasesPage {
+        fn properties() -> &'static [glib::ParamSpec] {
+            Self::derived_properties()
+        }
+
+        fn set_property(&self, id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+            self.derived_set_property(id, value, pspec);
+        }
+
+        fn property(&self, id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            self.derived_property(id, pspec)
+        }
+
+        fn constructed(&self) {
+            self.parent_constructed();
+            self.obj().load();
+        }
+
+        fn dispose(&self) {
+            utils::unparent_children(&*self.obj());
+        }
+    }
+
+    impl WidgetImpl for ImageBasesPage {}
+}
+
+glib::wrapper! {
+    pub(crate) struct ImageBasesPage(ObjectSubclass<imp::ImageBasesPage>)
+        @extends gtk::Widget,
+        @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget;
+}
+
+impl From<&model::Client> for ImageBasesPage {
+    fn from(client: &model::Client) -> Self {
+        glib::Object::builder().property("client", client).build()
+    }
+}
+
+impl ImageBasesPage {
+    /// Retrieves the history of every tagged image and groups them once all are known.
+    /// Intermediate images are left out because they are the shared layers themselves.
+    fn load(&self) {
+        let Some(client) = self.client() else {
+            return;
+        };
+
+        let images = client
+            .image_list()
+            .iter::<model::Image>()
+            .map(Result::unwrap)
+            .filter(|image| image.repo_tags().len() > 0)
+            .collect::<Vec<_>>();
+
+        if images.is_empty() {
+            self.imp().stack.set_visible_child_name("empty");
+            return;
+        }
+
+        let pending = Rc::new(Cell::new(images.len()));
+        let chains = Rc::new(RefCell::new(Vec::new()));
+
+        images.iter().for_each(|image| {
+            image.history(clone!(
+                #[weak(rename_to = obj)]
+                self,
+                #[weak]
+                image,
+                #[strong]
+                pending,
+                #[strong]
+                chains,
+                move |result| {
+                    match result {
+                        Ok(history) => chains.borrow_mut().push(Chain {
+                            image: image.clone(),
+                            layers: layers(history),
+                        }),
+                        Err(e) => log::warn!(
+                            "Leaving out image '{}' when grouping by base: {e}",
+                            image.id()
+                        ),
+                    }
+
+                    pending.set(pending.get() - 1);
+                    if pending.get() == 0 {
+                        obj.show_bases(chains.take());
+                    }
+                }
+            ));
+        });
+    }
+
+    fn show_bases(&self, chains: Vec<Chain>) {
+        let imp = self.imp();
+
+        let mut by_base_layer = HashMap::<String, Vec<Chain>>::new();
+        chains
+            .into_iter()
+            .filter(|chain| !chain.layers.is_empty())
+            .for_each(|chain| {
+                by_base_layer
+                    .entry(chain.layers[0].key.clone())
+                    .or_default()
+                    .push(chain);
+            });
+
+        let mut bases = by_base_layer
+            .into_values()
+            .filter(|chains| chains.len() > 1)
+            .map(Base::new)
+            .collect::<Vec<_>>();
+        bases.sort_by(|a, b| b.saved_size().cmp(&a.saved_size()));
+
+        bases
+            .iter()
+            .for_each(|base| imp.preferences_page.add(&self.group(base)));
+
+        imp.stack
+            .set_visible_child_name(if bases.is_empty() { "empty" } else { "bases" });
+    }
+
+    fn group(&self, base: &Base) -> adw::PreferencesGroup {
+        let list_box = gtk::ListBox::builder()
+            .css_classes(["boxed-list"])
+            .selection_mode(gtk::SelectionMode::None)
+            .build();
+
+        let mut chains = base.chains.iter().collect::<Vec<_>>();
+        chains.sort_by_key(|chain| std::cmp::Reverse(base.unique_size(chain)));
+        chains
+            .into_iter()
+            .for_each(|chain| list_box.append(&self.row(base, chain)));
+
+        let len = base.chains.len() as u32;
+        let group = adw::PreferencesGroup::builder()
+            .title(
+                base.chains
+                    .iter()
+                    .find(|chain| chain.layers.len() == base.shared_layers)
+                    .map(|chain| image_name(&chain.image))
+                    .unwrap_or_else(|| gettext("Unnamed Base")),
+            )
+            .description(ngettext!(
+                // Translators: The first "{}" is a placeholder for a size like "80 MB".
+                "{} in {} layer shared by {} images",
+                "{} in {} layers shared by {} images",
+                base.shared_layers as u32,
+                glib::format_size(base.shared_size()),
+                base.shared_layers,
+                len,
+            ))
+            .build();
+        group.add(&list_box);
+
+        group
+    }
+
+    fn row(&self, base: &Base, chain: &Chain) -> adw::ActionRow {
+        let row = adw::ActionRow::builder()
+            .title(glib::markup_escape_text(&image_name(&chain.image)))
+            .subtitle(gettext!(
+                // Translators: The "{}" is a placeholder for a size like "80 MB".
+                "{} unique",
+                glib::format_size(base.unique_size(chain))
+            ))
+            .activatable(true)
+            .build();
+        row.add_suffix(&gtk::Image::from_icon_name("go-next-symbolic"));
+
+        row.connect_activated(clone!(
+            #[weak(rename_to = obj)]
+            self,
+            #[weak(rename_to = image)]
+            chain.image,
+            move |_| {
+                utils::navigation_view(&obj).push(
+                    &adw::NavigationPage::builder()
+                        .title(gettext!("Image {}", utils::format_id(&image.id())))
+                        .child(&view::ImageDetailsPage::from(&image))
+                        .build(),
+                );
+            }
+        ));
+
+        row
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Layer {
+    /// The id of the layer, or its creation if Podman doesn't know the id anymore.
+    key: String,
+    size: u64,
+}
+
+#[derive(Debug)]
+struct Chain {
+    image: model::Image,
+    /// The layers of the image starting with the lowest one.
+    layers: Vec<Layer>,
+}
+
+#[derive(Debug)]
+struct Base {
+    chains: Vec<Chain>,
+    /// The number of layers at the bottom that all chains have in common.
+    shared_layers: usize,
+}
+
+impl Base {
+    fn new(chains: Vec<Chain>) -> Self {
+        let first = &chains[0].layers;
+        let shared_layers = (0..first.len())
+            .take_while(|index| {
+                chains
+                    .iter()
+                    .all(|chain| chain.layers.get(*index) == Some(&first[*index]))
+            })
+            .count();
+
+        Self {
+            chains,
+            shared_layers,
+        }
+    }
+
+    fn shared_size(&self) -> u64 {
+        self.chains[0].layers[..self.shared_layers]
+            .iter()
+            .map(|layer| layer.size)
+            .sum()
+    }
+
+    fn unique_size(&self, chain: &Chain) -> u64 {
+        chain.layers[self.shared_layers..]
+            .iter()
+            .map(|layer| layer.size)
+            .sum()
+    }
+
+    /// The disk space that would be needed if no layers were shared.
+    fn saved_size(&self) -> u64 {
+        self.shared_size() * (self.chains.len() as u64 - 1)
+    }
+}
+
+/// The history lists the most recent layer first, but the chains are compared from the bottom.
+fn layers(history: &[podman::models::HistoryResponse]) -> Vec<Layer> {
+    history
+        .iter()
+        .rev()
+        .map(|entry| Layer {
+            key: entry
+                .id
+                .as_deref()
+                .filter(|id| *id != "<missing>")
+                .map(str::to_owned)
+                .unwrap_or_else(|| {
+                    format!(
+                        "{}\n{}",
+                        entry.created.unwrap_or_default(),
+                        entry.created_by.as_deref().unwrap_or_default()
+                    )
+                }),
+            size: entry.size.unwrap_or_default().max(0) as u64,
+        })
+        .collect()
+}
+
+fn image_name(image: &model::Image) -> String {
+    image
+        .repo_tags()
+        .get(0)
+        .map(|repo_tag| repo_tag.full())
+        .unwrap_or_else(|| utils::format_id(&image.id()))
+}
@@ -258,6 +258,7 @@ impl ImageBuildPage {
                     imp.labels()
                         .iter::<model::KeyVal>()
                         .map(Result::unwrap)
+                        .filter(|entry| !entry.key().is_empty())
                         .map(|entry| (entry.key(), entry.value())),
                 )
                 .build();
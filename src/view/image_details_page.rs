@@ -3,6 +3,7 @@ use std::cell::RefCell;
 use adw::prelude::*;
 use adw::subclass::prelude::*;
 use gettextrs::gettext;
+use gettextrs::ngettext;
 use glib::Properties;
 use glib::clone;
 use glib::closure;
@@ -11,14 +12,20 @@ use gtk::gdk;
 use gtk::glib;
 
 use crate::model;
+use crate::model::prelude::*;
+use crate::podman;
 use crate::utils;
 use crate::view;
 use crate::widget;
 
 const ACTION_TAG: &str = "image-details-page.tag";
+const ACTION_TOGGLE_TAG_SELECTION_MODE: &str = "image-details-page.toggle-tag-selection-mode";
+const ACTION_UNTAG_SELECTION: &str = "image-details-page.untag-selection";
 const ACTION_INSPECT_IMAGE: &str = "image-details-page.inspect-image";
 const ACTION_SHOW_HISTORY: &str = "image-details-page.show-history";
+const ACTION_SCAN: &str = "image-details-page.scan";
 const ACTION_DELETE_IMAGE: &str = "image-details-page.delete-image";
+const ACTION_COPY_SUMMARY: &str = "image-details-page.copy-summary";
 
 mod imp {
     use super::*;
@@ -27,6 +34,7 @@ mod imp {
     #[properties(wrapper_type = super::ImageDetailsPage)]
     #[template(resource = "/com/github/marhkb/Pods/ui/view/image_details_page.ui")]
     pub(crate) struct ImageDetailsPage {
+        pub(super) settings: utils::PodsSettings,
         pub(super) handler_id: RefCell<Option<glib::SignalHandlerId>>,
         #[property(get, set = Self::set_image, construct, nullable)]
         pub(super) image: glib::WeakRef<model::Image>,
@@ -43,13 +51,27 @@ mod imp {
         #[template_child]
         pub(super) size_row: TemplateChild<widget::PropertyRow>,
         #[template_child]
-        pub(super) command_row: TemplateChild<widget::PropertyRow>,
+        pub(super) ports_row: TemplateChild<widget::PropertyRow>,
         #[template_child]
-        pub(super) entrypoint_row: TemplateChild<widget::PropertyRow>,
+        pub(super) summary_group: TemplateChild<adw::PreferencesGroup>,
         #[template_child]
-        pub(super) ports_row: TemplateChild<widget::PropertyRow>,
+        pub(super) command_row: TemplateChild<adw::ActionRow>,
+        #[template_child]
+        pub(super) entrypoint_row: TemplateChild<adw::ActionRow>,
+        #[template_child]
+        pub(super) instruction_row: TemplateChild<adw::ActionRow>,
+        #[template_child]
+        pub(super) labels_group: TemplateChild<adw::PreferencesGroup>,
+        #[template_child]
+        pub(super) labels_list_box: TemplateChild<gtk::ListBox>,
         #[template_child]
         pub(super) repo_tags_list_box: TemplateChild<gtk::ListBox>,
+        #[template_child]
+        pub(super) platforms_spinner: TemplateChild<adw::Spinner>,
+        #[template_child]
+        pub(super) platforms_list_box: TemplateChild<gtk::ListBox>,
+        #[template_child]
+        pub(super) scan_row: TemplateChild<adw::ActionRow>,
     }
 
     #[glib::object_subclass]
@@ -65,6 +87,14 @@ mod imp {
                 widget.tag();
             });
 
+            klass.install_action(ACTION_TOGGLE_TAG_SELECTION_MODE, None, |widget, _, _| {
+                widget.toggle_tag_selection_mode();
+            });
+
+            klass.install_action_async(ACTION_UNTAG_SELECTION, None, async |widget, _, _| {
+                widget.untag_selection().await;
+            });
+
             klass.install_action(ACTION_INSPECT_IMAGE, None, |widget, _, _| {
                 widget.show_inspection();
             });
@@ -73,10 +103,22 @@ mod imp {
                 widget.show_history();
             });
 
+            klass.install_action(ACTION_SCAN, None, |widget, _, _| {
+                widget.show_scan();
+            });
+
             klass.install_action(ACTION_DELETE_IMAGE, None, |widget, _, _| {
                 widget.delete_image();
             });
 
+            klass.install_action(
+                ACTION_COPY_SUMMARY,
+                Some(glib::VariantTy::STRING),
+                |widget, _, data| {
+                    widget.copy_summary(&data.unwrap().get::<String>().unwrap());
+                },
+            );
+
             // For displaying a mnemonic.
             klass.add_binding_action(
                 gdk::Key::N,
@@ -115,6 +157,15 @@ mod imp {
 
             let obj = &*self.obj();
 
+            // The scan is only offered once a scanner has been configured.
+            self.settings
+                .bind("image-scanner-command", &*self.scan_row, "visible")
+                .mapping(|variant, _| {
+                    Some((!variant.str().unwrap_or_default().trim().is_empty()).to_value())
+                })
+                .get_only()
+                .build();
+
             let image_expr = Self::Type::this_expression("image");
             let data_expr = image_expr.chain_property::<model::Image>("data");
             let image_config_expr = data_expr.chain_property::<model::ImageData>("config");
@@ -123,9 +174,40 @@ mod imp {
                 image_config_expr.chain_property::<model::ImageConfig>("entrypoint");
             let exposed_ports_expr =
                 image_config_expr.chain_property::<model::ImageConfig>("exposed-ports");
+            let repo_tags_expr = image_expr.chain_property::<model::Image>("repo-tags");
 
-            image_expr
-                .chain_property::<model::Image>("to-be-deleted")
+            repo_tags_expr
+                .chain_property::<model::RepoTagList>("selection-mode")
+                .chain_closure::<bool>(closure!(|_: Self::Type, is_selection_mode: bool| {
+                    !is_selection_mode
+                }))
+                .bind(&*self.create_tag_row, "visible", Some(obj));
+
+            repo_tags_expr
+                .chain_property::<model::RepoTagList>("len")
+                .watch(
+                    Some(obj),
+                    clone!(
+                        #[weak]
+                        obj,
+                        move || {
+                            let len = obj
+                                .image()
+                                .map(|image| image.repo_tags().len())
+                                .unwrap_or(0);
+
+                            if len == 0
+                                && let Some(image) = obj.image()
+                            {
+                                image.repo_tags().set_selection_mode(false);
+                            }
+                            obj.update_actions();
+                        }
+                    ),
+                );
+
+            repo_tags_expr
+                .chain_property::<model::RepoTagList>("num-selected")
                 .watch(
                     Some(obj),
                     clone!(
@@ -133,15 +215,45 @@ mod imp {
                         obj,
                         move || {
                             obj.action_set_enabled(
-                                ACTION_DELETE_IMAGE,
+                                ACTION_UNTAG_SELECTION,
                                 obj.image()
-                                    .map(|image| !image.to_be_deleted())
+                                    .map(|image| image.repo_tags().num_selected() > 0)
                                     .unwrap_or(false),
                             );
                         }
                     ),
                 );
 
+            image_expr
+                .chain_property::<model::Image>("to-be-deleted")
+                .watch(
+                    Some(obj),
+                    clone!(
+                        #[weak]
+                        obj,
+                        move || obj.update_actions()
+                    ),
+                );
+
+            image_expr
+                .chain_property::<model::Image>("image-list")
+                .chain_property::<model::ImageList>("client")
+                .chain_property::<model::Client>("connection")
+                .chain_property::<model::Connection>("read-only")
+                .watch(
+                    Some(obj),
+                    clone!(
+                        #[weak]
+                        obj,
+                        move || {
+                            if let Some(image) = obj.image().filter(model::Image::is_read_only) {
+                                image.repo_tags().set_selection_mode(false);
+                            }
+                            obj.update_actions();
+                        }
+                    ),
+                );
+
             data_expr
                 .chain_closure::<bool>(closure!(|_: Self::Type, cmd: Option<model::ImageData>| {
                     cmd.is_none()
@@ -215,20 +327,43 @@ mod imp {
             )
             .bind(&*self.size_row, "value", Some(obj));
 
-            cmd_expr.bind(&*self.command_row, "value", Some(obj));
+            cmd_expr
+                .chain_closure::<String>(closure!(|_: Self::Type, cmd: Option<&str>| {
+                    cmd.unwrap_or_default().to_owned()
+                }))
+                .bind(&*self.command_row, "subtitle", Some(obj));
             cmd_expr
                 .chain_closure::<bool>(closure!(|_: Self::Type, cmd: Option<&str>| {
                     cmd.is_some()
                 }))
                 .bind(&*self.command_row, "visible", Some(obj));
 
-            entrypoint_expr.bind(&*self.entrypoint_row, "value", Some(obj));
+            entrypoint_expr
+                .chain_closure::<String>(closure!(|_: Self::Type, entrypoint: Option<&str>| {
+                    entrypoint.unwrap_or_default().to_owned()
+                }))
+                .bind(&*self.entrypoint_row, "subtitle", Some(obj));
             entrypoint_expr
                 .chain_closure::<bool>(closure!(|_: Self::Type, entrypoint: Option<&str>| {
                     entrypoint.is_some()
                 }))
                 .bind(&*self.entrypoint_row, "visible", Some(obj));
 
+            [
+                &*self.command_row,
+                &*self.entrypoint_row,
+                &*self.instruction_row,
+            ]
+            .into_iter()
+            .for_each(|row| {
+                row.connect_visible_notify(clone!(
+                    #[weak]
+                    obj,
+                    move |_| obj.imp().update_summary_group()
+                ));
+            });
+            self.update_summary_group();
+
             exposed_ports_expr
                 .chain_closure::<String>(closure!(
                     |_: Self::Type, exposed_ports: gtk::StringList| {
@@ -252,10 +387,24 @@ mod imp {
                     exposed_ports.n_items() > 0
                 }))
                 .bind(&*self.ports_row, "visible", Some(obj));
+
+            image_config_expr.watch(
+                Some(obj),
+                clone!(
+                    #[weak]
+                    obj,
+                    move || obj.imp().update_labels()
+                ),
+            );
         }
 
         fn dispose(&self) {
-            utils::unparent_children(&*self.obj());
+            let obj = &*self.obj();
+
+            if let Some(image) = obj.image() {
+                image.repo_tags().set_selection_mode(false);
+            }
+            utils::unparent_children(obj);
         }
     }
 
@@ -271,8 +420,11 @@ mod imp {
             self.window_title.set_subtitle("");
             if let Some(image) = obj.image() {
                 image.disconnect(self.handler_id.take().unwrap());
+                image.repo_tags().set_selection_mode(false);
             }
             self.repo_tags_list_box.unbind_model();
+            self.platforms_list_box.remove_all();
+            self.instruction_row.set_visible(false);
 
             if let Some(image) = value {
                 self.window_title
@@ -313,10 +465,52 @@ mod imp {
                     view::RepoTagRow::from(repo_tag).upcast()
                 });
                 self.repo_tags_list_box.append(&*self.create_tag_row);
+
+                if image.is_manifest_list() {
+                    obj.load_platforms(image);
+                }
+
+                image.history(clone!(
+                    #[weak]
+                    obj,
+                    move |result| {
+                        // Errors are logged by the model and the row just stays hidden.
+                        if let Some(instruction) = result.ok().and_then(last_build_instruction) {
+                            let row = &*obj.imp().instruction_row;
+                            row.set_subtitle(instruction);
+                            row.set_visible(true);
+                        }
+                    }
+                ));
             }
 
             self.image.set(value);
         }
+
+        /// The summary is only shown as long as it has something to tell.
+        fn update_summary_group(&self) {
+            self.summary_group.set_visible(
+                self.command_row.is_visible()
+                    || self.entrypoint_row.is_visible()
+                    || self.instruction_row.is_visible(),
+            );
+        }
+
+        /// Shows the labels the image has been built with, e.g. its maintainer or version.
+        fn update_labels(&self) {
+            let labels = self
+                .obj()
+                .image()
+                .and_then(|image| image.data())
+                .map(|data| data.config().labels());
+
+            self.labels_list_box.bind_model(labels.as_ref(), |item| {
+                let label = item.downcast_ref::<model::KeyVal>().unwrap();
+                widget::PropertyRow::new(&label.key(), &label.value()).upcast()
+            });
+            self.labels_group
+                .set_visible(labels.is_some_and(|labels| labels.n_items() > 0));
+        }
     }
 }
 
@@ -333,6 +527,69 @@ impl From<&model::Image> for ImageDetailsPage {
 }
 
 impl ImageDetailsPage {
+    fn update_actions(&self) {
+        let image = self.image();
+        let read_only = image.as_ref().is_some_and(model::Image::is_read_only);
+
+        self.action_set_enabled(ACTION_TAG, !read_only);
+        self.action_set_enabled(
+            ACTION_TOGGLE_TAG_SELECTION_MODE,
+            !read_only
+                && image
+                    .as_ref()
+                    .is_some_and(|image| image.repo_tags().len() > 0),
+        );
+        self.action_set_enabled(
+            ACTION_DELETE_IMAGE,
+            !read_only && image.as_ref().is_some_and(|image| !image.to_be_deleted()),
+        );
+        self.action_set_enabled(view::ContainersGroup::action_create_container(), !read_only);
+    }
+
+    fn load_platforms(&self, image: &model::Image) {
+        let imp = self.imp();
+        imp.platforms_spinner.set_visible(true);
+
+        image.manifest_entries(clone!(
+            #[weak(rename_to = obj)]
+            self,
+            move |result| {
+                let imp = obj.imp();
+                imp.platforms_spinner.set_visible(false);
+
+                match result {
+                    Ok(entries) => entries.iter().for_each(|entry| {
+                        imp.platforms_list_box.append(
+                            &adw::ActionRow::builder()
+                                .title(&entry.platform)
+                                .subtitle(gettext!(
+                                    // Translators: The first "{}" is a digest, the second one is a storage space.
+                                    "{} ({})",
+                                    utils::format_id(
+                                        entry
+                                            .digest
+                                            .split_once(':')
+                                            .map_or(&*entry.digest, |(_, hash)| hash)
+                                    ),
+                                    glib::format_size(entry.size)
+                                ))
+                                .subtitle_selectable(true)
+                                .build(),
+                        );
+                    }),
+                    Err(e) => {
+                        log::error!("Error on loading manifest list: {e}");
+                        utils::show_error_toast(
+                            &obj,
+                            &gettext("Error on loading manifest list"),
+                            &e.to_string(),
+                        );
+                    }
+                }
+            }
+        ));
+    }
+
     fn tag(&self) {
         self.exec_action(|| {
             if let Some(image) = self.image() {
@@ -343,6 +600,76 @@ impl ImageDetailsPage {
         });
     }
 
+    fn toggle_tag_selection_mode(&self) {
+        if let Some(image) = self.image() {
+            let repo_tags = image.repo_tags();
+            repo_tags.set_selection_mode(!repo_tags.is_selection_mode());
+        }
+    }
+
+    async fn untag_selection(&self) {
+        let Some(image) = self.image() else {
+            return;
+        };
+
+        let repo_tags = image.repo_tags();
+        let selected = repo_tags
+            .selected_items()
+            .into_iter()
+            .map(|selectable| selectable.downcast::<model::RepoTag>().unwrap())
+            .collect::<Vec<_>>();
+
+        if selected.is_empty() {
+            return;
+        }
+
+        if selected.len() as u32 == repo_tags.len() {
+            let dialog = adw::AlertDialog::builder()
+                .heading(gettext("Remove All Tags?"))
+                .body(gettext(
+                    "The image will become dangling and may be removed when pruning images.",
+                ))
+                .build();
+
+            dialog.add_responses(&[
+                ("cancel", &gettext("_Cancel")),
+                ("untag", &gettext("_Remove")),
+            ]);
+            dialog.set_default_response(Some("cancel"));
+            dialog.set_response_appearance("untag", adw::ResponseAppearance::Destructive);
+
+            if "untag" != dialog.choose_future(self).await {
+                return;
+            }
+        }
+
+        repo_tags.set_selection_mode(false);
+
+        let failures = futures::future::join_all(selected.iter().map(|repo_tag| async move {
+            repo_tag
+                .untag()
+                .await
+                .map_err(|e| format!("{}: {e}", repo_tag.full()))
+        }))
+        .await
+        .into_iter()
+        .filter_map(Result::err)
+        .collect::<Vec<_>>();
+
+        if !failures.is_empty() {
+            utils::show_error_toast(
+                self,
+                &ngettext!(
+                    "Error on untagging {} tag",
+                    "Error on untagging {} tags",
+                    failures.len() as u32,
+                    failures.len()
+                ),
+                &failures.join(", "),
+            );
+        }
+    }
+
     fn show_inspection(&self) {
         self.exec_action(|| {
             if let Some(image) = self.image() {
@@ -372,12 +699,38 @@ impl ImageDetailsPage {
         });
     }
 
+    fn show_scan(&self) {
+        self.exec_action(|| {
+            if let Some(image) = self.image() {
+                utils::navigation_view(self).push(
+                    &adw::NavigationPage::builder()
+                        .child(&view::ImageScanPage::from(&image))
+                        .build(),
+                );
+            }
+        });
+    }
+
     fn delete_image(&self) {
         self.exec_action(|| {
             view::image::delete_image_show_confirmation(self, self.image());
         });
     }
 
+    fn copy_summary(&self, name: &str) {
+        let imp = self.imp();
+
+        let row = match name {
+            "command" => &*imp.command_row,
+            "entrypoint" => &*imp.entrypoint_row,
+            _ => &*imp.instruction_row,
+        };
+        self.clipboard()
+            .set_text(&row.subtitle().unwrap_or_default());
+
+        utils::show_toast(self, gettext("Copied to clipboard"));
+    }
+
     fn create_container(&self) {
         self.exec_action(|| {
             view::image::create_container(self, self.image());
@@ -394,3 +747,17 @@ impl ImageDetailsPage {
         }
     }
 }
+
+/// Podman records the instructions of a Containerfile as shell commands with a no-op marker.
+fn last_build_instruction(history: &[podman::models::HistoryResponse]) -> Option<&str> {
+    history
+        .iter()
+        .filter_map(|entry| entry.created_by.as_deref())
+        .map(|created_by| {
+            created_by
+                .split_once("#(nop)")
+                .map_or(created_by, |(_, instruction)| instruction)
+                .trim()
+        })
+        .find(|instruction| !instruction.is_empty())
+}
@@ -8,7 +8,6 @@ use gtk::glib::clone;
 use gtk::pango;
 
 use crate::model;
-use crate::rt;
 use crate::utils;
 use crate::widget;
 
@@ -65,11 +64,7 @@ impl From<&model::Image> for ImageHistoryPage {
             .window_title
             .set_subtitle(&utils::format_id(&image.id()));
 
-        rt::Promise::new({
-            let api = image.api().unwrap();
-            async move { api.history().await }
-        })
-        .defer(clone!(
+        image.history(clone!(
             #[weak]
             obj,
             move |result| {
@@ -94,7 +89,7 @@ impl From<&model::Image> for ImageHistoryPage {
                             )
                         )));
 
-                        entries.into_iter().for_each(|entry| {
+                        entries.iter().for_each(|entry| {
                             let row = adw::ExpanderRow::builder()
                                 .title(
                                     entry
@@ -135,7 +130,7 @@ impl From<&model::Image> for ImageHistoryPage {
                                     .build(),
                             );
 
-                            if let Some(created_by) = entry.created_by {
+                            if let Some(created_by) = &entry.created_by {
                                 let box_ = gtk::Box::builder()
                                     .orientation(gtk::Orientation::Vertical)
                                     .spacing(9)
@@ -169,12 +164,12 @@ impl From<&model::Image> for ImageHistoryPage {
                                         .build(),
                                 );
                             }
-                            if let Some(comment) = entry.comment
+                            if let Some(comment) = &entry.comment
                                 && !comment.is_empty()
                             {
-                                row.add_row(&property_row(&gettext("Comment"), &comment));
+                                row.add_row(&property_row(&gettext("Comment"), comment));
                             }
-                            if let Some(tags) = entry.tags {
+                            if let Some(tags) = &entry.tags {
                                 row.add_row(&property_row(&gettext("Tags"), &tags.join(", ")));
                             }
 
@@ -12,6 +12,7 @@ use crate::view;
 
 const ACTION_CREATE_CONTAINER: &str = "image-menu-button.create-container";
 const ACTION_DELETE_IMAGE: &str = "image-menu-button.delete-image";
+const ACTION_REFRESH: &str = "image-menu-button.refresh";
 
 mod imp {
     use super::*;
@@ -41,6 +42,9 @@ mod imp {
             klass.install_action(ACTION_DELETE_IMAGE, None, |widget, _, _| {
                 widget.delete_image();
             });
+            klass.install_action(ACTION_REFRESH, None, |widget, _, _| {
+                view::image::refresh(widget, widget.image());
+            });
         }
 
         fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
@@ -72,8 +76,20 @@ mod imp {
                 Some(obj),
             );
 
-            let to_be_deleted_expr = Self::Type::this_expression("image")
-                .chain_property::<model::Image>("to-be-deleted");
+            let image_expr = Self::Type::this_expression("image");
+
+            image_expr
+                .chain_property::<model::Image>("refreshing")
+                .watch(
+                    Some(obj),
+                    clone!(
+                        #[weak]
+                        obj,
+                        move || obj.update_refreshing()
+                    ),
+                );
+
+            let to_be_deleted_expr = image_expr.chain_property::<model::Image>("to-be-deleted");
 
             to_be_deleted_expr
                 .chain_closure::<bool>(closure!(|_: Self::Type, to_be_deleted: bool| {
@@ -86,16 +102,23 @@ mod imp {
                 clone!(
                     #[weak]
                     obj,
-                    move || {
-                        obj.action_set_enabled(
-                            ACTION_DELETE_IMAGE,
-                            obj.image()
-                                .map(|image| !image.to_be_deleted())
-                                .unwrap_or(false),
-                        );
-                    }
+                    move || obj.update_actions()
                 ),
             );
+
+            image_expr
+                .chain_property::<model::Image>("image-list")
+                .chain_property::<model::ImageList>("client")
+                .chain_property::<model::Client>("connection")
+                .chain_property::<model::Connection>("read-only")
+                .watch(
+                    Some(obj),
+                    clone!(
+                        #[weak]
+                        obj,
+                        move || obj.update_actions()
+                    ),
+                );
         }
 
         fn dispose(&self) {
@@ -113,6 +136,33 @@ glib::wrapper! {
 }
 
 impl ImageMenuButton {
+    fn update_refreshing(&self) {
+        let refreshing = self
+            .image()
+            .map(|image| image.refreshing())
+            .unwrap_or(false);
+
+        self.action_set_enabled(ACTION_REFRESH, !refreshing);
+        utils::set_menu_button_busy(&self.imp().menu_button, refreshing);
+    }
+
+    fn update_actions(&self) {
+        let read_only = self
+            .image()
+            .map(|image| image.is_read_only())
+            .unwrap_or(false);
+
+        self.action_set_enabled(
+            ACTION_DELETE_IMAGE,
+            !read_only
+                && self
+                    .image()
+                    .map(|image| !image.to_be_deleted())
+                    .unwrap_or(false),
+        );
+        self.action_set_enabled(ACTION_CREATE_CONTAINER, !read_only);
+    }
+
     pub(crate) fn delete_image(&self) {
         view::image::delete_image_show_confirmation(self, self.image());
     }
@@ -1,11 +1,12 @@
 use adw::prelude::*;
 use adw::subclass::prelude::*;
+use gettextrs::gettext;
 use glib::Properties;
+use glib::clone;
 use gtk::CompositeTemplate;
 use gtk::glib;
 
 use crate::model;
-use crate::podman;
 use crate::utils;
 use crate::view;
 
@@ -62,24 +63,63 @@ mod imp {
     impl ImagePullPage {
         #[template_callback]
         fn on_image_selected(&self, image: &str) {
-            let opts = podman::opts::PullOpts::builder()
-                .reference(image)
-                .quiet(false)
+            let client = self.obj().client().unwrap();
+            let opts = client.pull_opts(image).quiet(false).build();
+
+            let page = view::ActionPage::from(&client.action_list().download_image(image, opts));
+
+            self.push_action_page(&page);
+        }
+
+        #[template_callback]
+        fn on_all_tags_selected(&self, repository: &str) {
+            let dialog = adw::AlertDialog::builder()
+                .heading(gettext("Pull All Tags?"))
+                .body_use_markup(true)
+                .body(gettext!(
+                    // Translators: The "{}" is a placeholder for the repository name.
+                    "Every tag of <b>{}</b> will be pulled. For large repositories this can take a long time and use a lot of disk space.",
+                    utils::escape(repository),
+                ))
                 .build();
 
-            let page = view::ActionPage::from(
-                &self
-                    .obj()
-                    .client()
-                    .unwrap()
-                    .action_list()
-                    .download_image(image, opts),
+            dialog.add_responses(&[("cancel", &gettext("_Cancel")), ("pull", &gettext("_Pull"))]);
+            dialog.set_default_response(Some("cancel"));
+            dialog.set_response_appearance("pull", adw::ResponseAppearance::Suggested);
+
+            let obj = &*self.obj();
+            dialog.connect_response(
+                Some("pull"),
+                clone!(
+                    #[weak]
+                    obj,
+                    #[to_owned]
+                    repository,
+                    move |_, _| {
+                        let client = obj.client().unwrap();
+                        let opts = client
+                            .pull_opts(&repository)
+                            .all_tags(true)
+                            .quiet(false)
+                            .build();
+
+                        let page = view::ActionPage::from(
+                            &client.action_list().download_all_tags(&repository, opts),
+                        );
+
+                        obj.imp().push_action_page(&page);
+                    }
+                ),
             );
 
+            dialog.present(Some(obj));
+        }
+
+        fn push_action_page(&self, page: &view::ActionPage) {
             self.navigation_view.push(
                 &adw::NavigationPage::builder()
                     .can_pop(false)
-                    .child(&page)
+                    .child(page)
                     .build(),
             );
         }
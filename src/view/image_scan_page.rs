@@ -0,0 +1,183 @@
+use std::cell::RefCell;
+use std::ffi::OsStr;
+use std::ffi::OsString;
+
+use adw::prelude::*;
+use adw::subclass::prelude::*;
+use gettextrs::gettext;
+use gtk::CompositeTemplate;
+use gtk::gio;
+use gtk::glib;
+use gtk::glib::clone;
+
+use crate::model;
+use crate::utils;
+
+const ACTION_CANCEL: &str = "image-scan-page.cancel";
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default, CompositeTemplate)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/view/image_scan_page.ui")]
+    pub(crate) struct ImageScanPage {
+        pub(super) subprocess: RefCell<Option<gio::Subprocess>>,
+        #[template_child]
+        pub(super) spinner: TemplateChild<adw::Spinner>,
+        #[template_child]
+        pub(super) window_title: TemplateChild<adw::WindowTitle>,
+        #[template_child]
+        pub(super) banner: TemplateChild<adw::Banner>,
+        #[template_child]
+        pub(super) scrolled_window: TemplateChild<gtk::ScrolledWindow>,
+        #[template_child]
+        pub(super) text_buffer: TemplateChild<gtk::TextBuffer>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for ImageScanPage {
+        const NAME: &'static str = "PdsImageScanPage";
+        type Type = super::ImageScanPage;
+        type ParentType = gtk::Widget;
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.bind_template();
+
+            klass.install_action(ACTION_CANCEL, None, |widget, _, _| {
+                widget.cancel();
+            });
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for ImageScanPage {
+        fn dispose(&self) {
+            utils::unparent_children(&*self.obj());
+        }
+    }
+
+    impl WidgetImpl for ImageScanPage {
+        fn unroot(&self) {
+            // Don't let the scanner run on once the page has been left.
+            self.obj().cancel();
+            self.parent_unroot();
+        }
+    }
+}
+
+glib::wrapper! {
+    pub(crate) struct ImageScanPage(ObjectSubclass<imp::ImageScanPage>)
+        @extends gtk::Widget,
+        @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget;
+}
+
+impl From<&model::Image> for ImageScanPage {
+    fn from(image: &model::Image) -> Self {
+        let obj: Self = glib::Object::builder().build();
+
+        let reference = image
+            .repo_tags()
+            .get(0)
+            .map(|repo_tag| repo_tag.full())
+            .unwrap_or_else(|| image.id());
+        obj.imp().window_title.set_subtitle(&reference);
+
+        glib::spawn_future_local(clone!(
+            #[weak]
+            obj,
+            async move {
+                obj.scan(&reference).await;
+            }
+        ));
+
+        obj
+    }
+}
+
+impl ImageScanPage {
+    async fn scan(&self, reference: &str) {
+        let imp = self.imp();
+
+        let subprocess = match spawn_scanner(reference) {
+            Ok(subprocess) => subprocess,
+            Err(e) => {
+                log::error!("Error on starting the vulnerability scanner: {e}");
+                utils::show_error_toast(
+                    self,
+                    &gettext("Error on starting the vulnerability scanner"),
+                    &e.to_string(),
+                );
+                utils::navigation_view(self).pop();
+                return;
+            }
+        };
+        imp.subprocess.replace(Some(subprocess.clone()));
+
+        let stream = gio::DataInputStream::new(&subprocess.stdout_pipe().unwrap());
+        loop {
+            match stream
+                .read_line_utf8_future(glib::Priority::default())
+                .await
+            {
+                Ok(Some(line)) => self.append_line(&line),
+                Ok(None) => break,
+                Err(e) => {
+                    log::warn!("Error on reading the output of the vulnerability scanner: {e}");
+                    break;
+                }
+            }
+        }
+
+        let result = subprocess.wait_future().await;
+        imp.subprocess.replace(None);
+
+        imp.spinner.set_visible(false);
+        self.action_set_enabled(ACTION_CANCEL, false);
+
+        imp.banner.set_title(&match result {
+            Err(e) => e.to_string(),
+            Ok(_) if !subprocess.has_exited() => gettext("Scan canceled"),
+            Ok(_) if subprocess.exit_status() == 0 => gettext("Scan finished"),
+            Ok(_) => gettext!("Scanner exited with status {}", subprocess.exit_status()),
+        });
+        imp.banner.set_revealed(true);
+    }
+
+    fn append_line(&self, line: &str) {
+        let imp = self.imp();
+
+        let buffer = &*imp.text_buffer;
+        buffer.insert(&mut buffer.end_iter(), line);
+        buffer.insert(&mut buffer.end_iter(), "\n");
+
+        let adjustment = imp.scrolled_window.vadjustment();
+        adjustment.set_value(adjustment.upper());
+    }
+
+    fn cancel(&self) {
+        if let Some(subprocess) = &*self.imp().subprocess.borrow() {
+            subprocess.force_exit();
+        }
+    }
+}
+
+/// Spawns the scanner that is configured in the preferences with the image reference as last
+/// argument. The scanner must be runnable from where the application runs; within the Flatpak
+/// sandbox there is deliberately no way to escape to the host.
+fn spawn_scanner(reference: &str) -> Result<gio::Subprocess, glib::Error> {
+    let command = utils::PodsSettings::default().string("image-scanner-command");
+
+    let mut argv = glib::shell_parse_argv(command.as_str())?;
+    argv.push(OsString::from(reference));
+
+    gio::Subprocess::newv(
+        &argv
+            .iter()
+            .map(OsString::as_os_str)
+            .collect::<Vec<&OsStr>>(),
+        gio::SubprocessFlags::STDOUT_PIPE | gio::SubprocessFlags::STDERR_MERGE,
+    )
+}
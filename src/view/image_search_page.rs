@@ -41,6 +41,8 @@ mod imp {
         pub(super) action_button_name: RefCell<String>,
         #[property(get, set, construct)]
         pub(super) top_level: OnceCell<bool>,
+        #[property(get, set)]
+        pub(super) allow_all_tags: Cell<bool>,
         #[template_child]
         pub(super) size_group: TemplateChild<gtk::SizeGroup>,
         #[template_child]
@@ -96,6 +98,9 @@ mod imp {
                     Signal::builder("image-selected")
                         .param_types([String::static_type()])
                         .build(),
+                    Signal::builder("all-tags-selected")
+                        .param_types([String::static_type()])
+                        .build(),
                 ]
             })
         }
@@ -327,6 +332,7 @@ impl ImageSearchPage {
         };
 
         let page = view::RepoTagSelectionPage::new(&client, &image, &self.action_button_name());
+        page.set_allow_all_tags(self.allow_all_tags());
 
         page.connect_image_selected(clone!(
             #[weak(rename_to = obj)]
@@ -337,6 +343,14 @@ impl ImageSearchPage {
             }
         ));
 
+        page.connect_all_tags_selected(clone!(
+            #[weak(rename_to = obj)]
+            self,
+            move |_, repository| {
+                obj.emit_by_name::<()>("all-tags-selected", &[repository]);
+            }
+        ));
+
         self.imp()
             .navigation_view
             .push(&adw::NavigationPage::new(&page, &gettext("Select Tag")));
@@ -15,6 +15,7 @@ use crate::view;
 
 const ACTION_SELECT_IMAGE: &str = "container-creation-page.select-image";
 const ACTION_SEARCH_IMAGE: &str = "container-creation-page.search-image";
+const ACTION_CLEAR_IMAGE: &str = "container-creation-page.clear-image";
 
 #[derive(Debug, Default, Copy, Clone, PartialEq, Eq, glib::Enum)]
 #[enum_type(name = "ImageSelectionMode")]
@@ -38,6 +39,11 @@ mod imp {
         pub(super) mode: Cell<ImageSelectionMode>,
         #[property(get, set = Self::set_image, construct, nullable)]
         pub(super) image: glib::WeakRef<model::Image>,
+        /// Whether the selection can be taken back, leaving the choice of the image to Podman.
+        #[property(get, set)]
+        pub(super) clearable: Cell<bool>,
+        #[template_child]
+        pub(super) clear_button: TemplateChild<gtk::Button>,
     }
 
     #[glib::object_subclass]
@@ -56,6 +62,10 @@ mod imp {
             klass.install_action(ACTION_SEARCH_IMAGE, None, |widget, _, _| {
                 widget.search_image();
             });
+
+            klass.install_action(ACTION_CLEAR_IMAGE, None, |widget, _, _| {
+                widget.clear_image();
+            });
         }
 
         fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
@@ -90,6 +100,17 @@ mod imp {
                     }
                 }))
                 .bind(obj, "title", Some(obj));
+
+            gtk::ClosureExpression::new::<bool>(
+                [
+                    Self::Type::this_expression("clearable"),
+                    Self::Type::this_expression("mode"),
+                ],
+                closure!(|_: Self::Type, clearable: bool, mode: ImageSelectionMode| {
+                    clearable && mode != ImageSelectionMode::Unset
+                }),
+            )
+            .bind(&*self.clear_button, "visible", Some(obj));
         }
     }
 
@@ -147,6 +168,38 @@ impl ImageSelectionComboRow {
         }
     }
 
+    /// Selects the local image tagged with `reference` or falls back to pulling it.
+    pub(crate) fn select_reference(&self, reference: &str) {
+        let image = self.client().and_then(|client| {
+            client
+                .image_list()
+                .iter::<model::Image>()
+                .map(Result::unwrap)
+                .find(|image| {
+                    image
+                        .repo_tags()
+                        .iter::<model::RepoTag>()
+                        .map(Result::unwrap)
+                        .any(|repo_tag| repo_tag.full() == reference)
+                })
+        });
+
+        match image {
+            Some(image) => self.set_image(Some(&image)),
+            None => {
+                self.set_image(Option::<model::Image>::None);
+                self.set_mode(ImageSelectionMode::Remote);
+                self.set_subtitle(reference);
+            }
+        }
+    }
+
+    pub(crate) fn clear_image(&self) {
+        self.set_image(Option::<model::Image>::None);
+        self.set_mode(ImageSelectionMode::Unset);
+        self.set_subtitle("");
+    }
+
     pub(crate) fn search_image(&self) {
         if let Some(client) = self.client() {
             let image_search_page = view::ImageSearchPage::new(&client, &gettext("Select"), false);
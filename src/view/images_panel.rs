@@ -24,11 +24,13 @@ use crate::view;
 const ACTION_PULL_IMAGE: &str = "images-panel.pull-image";
 const ACTION_BUILD_IMAGE: &str = "images-panel.build-image";
 const ACTION_PRUNE_UNUSED_IMAGES: &str = "images-panel.prune-unused-images";
+const ACTION_SHOW_SHARED_BASES: &str = "images-panel.show-shared-bases";
 const ACTION_ENTER_SELECTION_MODE: &str = "images-panel.enter-selection-mode";
 const ACTION_EXIT_SELECTION_MODE: &str = "images-panel.exit-selection-mode";
 const ACTION_SELECT_VISIBLE: &str = "images-panel.select-visible";
 const ACTION_SELECT_NONE: &str = "images-panel.select-none";
 const ACTION_DELETE_SELECTION: &str = "images-panel.delete-selection";
+const ACTION_COPY_INSPECTION_SELECTION: &str = "images-panel.copy-inspection-selection";
 const ACTION_TOGGLE_SORT_DIRECTION: &str = "images-panel.toggle-sort-direction";
 const ACTION_CHANGE_SORT_ATTRIBUTE: &str = "images-panel.change-sort-attribute";
 const ACTION_SHOW_ALL_IMAGES: &str = "images-panel.show-all-images";
@@ -159,6 +161,9 @@ mod imp {
             klass.install_action(ACTION_PRUNE_UNUSED_IMAGES, None, |widget, _, _| {
                 widget.show_prune_page();
             });
+            klass.install_action(ACTION_SHOW_SHARED_BASES, None, |widget, _, _| {
+                widget.show_shared_bases_page();
+            });
 
             klass.install_action(ACTION_ENTER_SELECTION_MODE, None, |widget, _, _| {
                 widget.enter_selection_mode();
@@ -177,6 +182,9 @@ mod imp {
             klass.install_action(ACTION_DELETE_SELECTION, None, |widget, _, _| {
                 widget.delete_selection();
             });
+            klass.install_action(ACTION_COPY_INSPECTION_SELECTION, None, |widget, _, _| {
+                widget.copy_inspection_selection();
+            });
 
             klass.install_action(ACTION_TOGGLE_SORT_DIRECTION, None, |widget, _, _| {
                 widget.toggle_sort_direction();
@@ -218,6 +226,27 @@ mod imp {
                 .bind("sort-attribute", obj, "sort-attribute")
                 .build();
 
+            // Images can be found by any of their tags, with or without the registry, or by id.
+            utils::add_type_ahead_find(&self.list_box, |row| {
+                row.downcast_ref::<view::ImageRow>()
+                    .unwrap()
+                    .image()
+                    .map(|image| {
+                        image
+                            .repo_tags()
+                            .iter::<model::RepoTag>()
+                            .map(Result::unwrap)
+                            .flat_map(|repo_tag| {
+                                let full = repo_tag.full();
+                                let short = full.rsplit('/').next().unwrap().to_owned();
+                                [full, short]
+                            })
+                            .chain(Some(image.id()))
+                            .collect()
+                    })
+                    .unwrap_or_default()
+            });
+
             let image_list_expr = Self::Type::this_expression("image-list");
             let image_list_len_expr = image_list_expr.chain_property::<model::ImageList>("len");
             let selection_mode_expr =
@@ -227,6 +256,19 @@ mod imp {
             ));
             let collapsed_expr = Self::Type::this_expression("collapsed");
 
+            Self::Type::this_expression("image-list")
+                .chain_property::<model::ImageList>("client")
+                .chain_property::<model::Client>("connection")
+                .chain_property::<model::Connection>("read-only")
+                .watch(
+                    Some(obj),
+                    clone!(
+                        #[weak]
+                        obj,
+                        move || obj.imp().update_actions()
+                    ),
+                );
+
             gtk::ClosureExpression::new::<Option<String>>(
                 [
                     &image_list_len_expr,
@@ -433,6 +475,9 @@ mod imp {
         fn on_notify_search_mode_enabled(&self) {
             if self.search_bar.is_search_mode() {
                 self.search_entry.grab_focus();
+            } else {
+                self.search_entry.set_text("");
+                self.list_box.child_focus(gtk::DirectionType::TabForward);
             }
         }
 
@@ -524,19 +569,17 @@ mod imp {
                 move |value| obj.imp().set_filter_stack_visible_child(value, &model)
             ));
 
-            obj.action_set_enabled(ACTION_DELETE_SELECTION, false);
             value.connect_notify_local(
                 Some("num-selected"),
                 clone!(
                     #[weak]
                     obj,
-                    move |list, _| {
-                        obj.action_set_enabled(ACTION_DELETE_SELECTION, list.num_selected() > 0);
-                    }
+                    move |_, _| obj.imp().update_actions()
                 ),
             );
 
             self.image_list.set(Some(value));
+            self.update_actions();
         }
 
         fn set_filter_stack_visible_child(
@@ -553,6 +596,25 @@ mod imp {
             );
         }
 
+        /// Disables all actions that modify the Podman host while the connection is read-only.
+        fn update_actions(&self) {
+            let obj = &*self.obj();
+            let image_list = obj.image_list();
+
+            let read_only = image_list
+                .as_ref()
+                .and_then(model::ImageList::client)
+                .is_some_and(|client| client.connection().read_only());
+            let any_selected = image_list.is_some_and(|list| list.num_selected() > 0);
+
+            obj.action_set_enabled(ACTION_DELETE_SELECTION, any_selected && !read_only);
+            obj.action_set_enabled(ACTION_COPY_INSPECTION_SELECTION, any_selected);
+
+            obj.action_set_enabled(ACTION_PULL_IMAGE, !read_only);
+            obj.action_set_enabled(ACTION_BUILD_IMAGE, !read_only);
+            obj.action_set_enabled(ACTION_PRUNE_UNUSED_IMAGES, !read_only);
+        }
+
         fn update_filter(&self, filter_change: gtk::FilterChange) {
             if let Some(filter) = self.filter.get() {
                 filter.changed(filter_change);
@@ -597,8 +659,13 @@ impl ImagesPanel {
         self.imp().search_bar.set_search_mode(value);
     }
 
-    pub(crate) fn toggle_search_mode(&self) {
-        self.set_search_mode(!self.imp().search_bar.is_search_mode());
+    pub(crate) fn focus_search(&self) {
+        let imp = self.imp();
+        if imp.search_bar.is_search_mode() {
+            imp.search_entry.grab_focus();
+        } else {
+            self.set_search_mode(true);
+        }
     }
 
     pub(crate) fn show_download_page(&self) {
@@ -623,6 +690,17 @@ impl ImagesPanel {
         }
     }
 
+    pub(crate) fn show_shared_bases_page(&self) {
+        if let Some(client) = self.client() {
+            utils::navigation_view(self).push(
+                &adw::NavigationPage::builder()
+                    .title(gettext("Shared Bases"))
+                    .child(&view::ImageBasesPage::from(&client))
+                    .build(),
+            );
+        }
+    }
+
     pub(crate) fn enter_selection_mode(&self) {
         if let Some(list) = self.image_list().filter(|list| list.len() > 0) {
             list.select_none();
@@ -656,6 +734,28 @@ impl ImagesPanel {
         }
     }
 
+    pub(crate) fn copy_inspection_selection(&self) {
+        let Some(list) = self.image_list() else {
+            return;
+        };
+
+        view::inspection::copy_combined_inspection(
+            self,
+            list.selected_items()
+                .iter()
+                .map(|obj| obj.downcast_ref::<model::Image>().unwrap())
+                .filter_map(|image| {
+                    image.api().map(|api| {
+                        view::inspection::Inspection::new(
+                            image.id(),
+                            async move { api.inspect().await },
+                        )
+                    })
+                })
+                .collect(),
+        );
+    }
+
     pub(crate) fn delete_selection(&self) {
         if self
             .image_list()
@@ -666,6 +766,11 @@ impl ImagesPanel {
             return;
         }
 
+        if !utils::confirm_destructive_actions() {
+            self.delete_selected();
+            return;
+        }
+
         let dialog = adw::AlertDialog::builder()
             .heading(gettext("Confirm Forced Deletion of Multiple Images"))
             .body(gettext(
@@ -685,29 +790,8 @@ impl ImagesPanel {
             clone!(
                 #[weak(rename_to = obj)]
                 self,
-                move |_, response| if response == "delete"
-                    && let Some(list) = obj.image_list()
-                {
-                    list.selected_items()
-                        .iter()
-                        .map(|obj| obj.downcast_ref::<model::Image>().unwrap())
-                        .for_each(|image| {
-                            image.delete(clone!(
-                                #[weak]
-                                obj,
-                                move |image, result| {
-                                    if let Err(e) = result {
-                                        utils::show_error_toast(
-                                            &obj,
-                                            // Translators: The first "{}" is a placeholder for the image id, the second is for an error message.
-                                            &gettext!("Error on deleting image '{}'", image.id()),
-                                            &e.to_string(),
-                                        );
-                                    }
-                                }
-                            ));
-                        });
-                    list.set_selection_mode(false);
+                move |_, response| if response == "delete" {
+                    obj.delete_selected();
                 }
             ),
         );
@@ -715,6 +799,33 @@ impl ImagesPanel {
         dialog.present(Some(self));
     }
 
+    fn delete_selected(&self) {
+        let Some(list) = self.image_list() else {
+            return;
+        };
+
+        list.selected_items()
+            .iter()
+            .map(|obj| obj.downcast_ref::<model::Image>().unwrap())
+            .for_each(|image| {
+                image.delete(clone!(
+                    #[weak(rename_to = obj)]
+                    self,
+                    move |image, result| {
+                        if let Err(e) = result {
+                            utils::show_error_toast(
+                                &obj,
+                                // Translators: The first "{}" is a placeholder for the image id, the second is for an error message.
+                                &gettext!("Error on deleting image '{}'", image.id()),
+                                &e.to_string(),
+                            );
+                        }
+                    }
+                ));
+            });
+        list.set_selection_mode(false);
+    }
+
     fn deselect_hidden_images(&self, model: &gio::ListModel) {
         let visible_images = model
             .iter::<glib::Object>()
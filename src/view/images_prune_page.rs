@@ -110,7 +110,7 @@ impl ImagesPrunePage {
                 .external(imp.pods_settings.get("prune-external-images"))
                 .filter(if imp.prune_until_row.enables_expansion() {
                     Some(podman::opts::ImagePruneFilter::Until(
-                        imp.prune_until_row.prune_until_timestamp().to_string(),
+                        imp.prune_until_row.timestamp().to_string(),
                     ))
                 } else {
                     None
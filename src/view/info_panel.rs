@@ -5,6 +5,7 @@ use adw::subclass::prelude::*;
 use gettextrs::gettext;
 use gettextrs::ngettext;
 use glib::Properties;
+use glib::clone;
 use gtk::CompositeTemplate;
 use gtk::glib;
 
@@ -24,11 +25,21 @@ mod imp {
     pub(crate) struct InfoPanel {
         pub(super) store_graph_options_rows: RefCell<Vec<widget::PropertyRow>>,
         pub(super) store_graph_status_rows: RefCell<Vec<widget::PropertyRow>>,
+        pub(super) io_rates_handler:
+            RefCell<Option<(glib::SignalHandlerId, glib::WeakRef<model::ContainerList>)>>,
         #[property(get, set, nullable)]
         pub(super) client: glib::WeakRef<model::Client>,
         #[template_child]
         pub(super) stack: TemplateChild<gtk::Stack>,
         #[template_child]
+        pub(super) network_throughput_row: TemplateChild<adw::ActionRow>,
+        #[template_child]
+        pub(super) network_throughput_graph: TemplateChild<widget::ThroughputGraph>,
+        #[template_child]
+        pub(super) block_throughput_row: TemplateChild<adw::ActionRow>,
+        #[template_child]
+        pub(super) block_throughput_graph: TemplateChild<widget::ThroughputGraph>,
+        #[template_child]
         pub(super) version_api_version_row: TemplateChild<widget::PropertyRow>,
         #[template_child]
         pub(super) version_built_time_row: TemplateChild<widget::PropertyRow>,
@@ -119,7 +130,9 @@ mod imp {
     impl InfoPanel {
         #[template_callback]
         async fn on_notify_client(&self) {
-            self.obj().refresh().await;
+            let obj = &*self.obj();
+            obj.watch_io_rates();
+            obj.refresh().await;
         }
     }
 }
@@ -131,6 +144,49 @@ glib::wrapper! {
 }
 
 impl InfoPanel {
+    fn watch_io_rates(&self) {
+        let imp = self.imp();
+
+        if let Some((handler, container_list)) = imp.io_rates_handler.take()
+            && let Some(container_list) = container_list.upgrade()
+        {
+            container_list.disconnect(handler);
+        }
+
+        imp.network_throughput_graph.clear();
+        imp.block_throughput_graph.clear();
+        imp.network_throughput_row.set_subtitle("");
+        imp.block_throughput_row.set_subtitle("");
+
+        let Some(container_list) = self.client().map(|client| client.container_list()) else {
+            return;
+        };
+
+        let handler = container_list.connect_io_rates_notify(clone!(
+            #[weak(rename_to = obj)]
+            self,
+            move |container_list| {
+                let Some(rates) = container_list.io_rates() else {
+                    return;
+                };
+
+                let imp = obj.imp();
+
+                imp.network_throughput_graph
+                    .push(rates.net_input, rates.net_output);
+                imp.network_throughput_row
+                    .set_subtitle(&format_rates(rates.net_input, rates.net_output));
+
+                imp.block_throughput_graph
+                    .push(rates.block_input, rates.block_output);
+                imp.block_throughput_row
+                    .set_subtitle(&format_rates(rates.block_input, rates.block_output));
+            }
+        ));
+        imp.io_rates_handler
+            .replace(Some((handler, container_list.downgrade())));
+    }
+
     pub(crate) async fn refresh(&self) {
         let client = if let Some(client) = self.client() {
             client
@@ -296,3 +352,12 @@ impl InfoPanel {
         self.action_set_enabled(ACTION_REFRESH, true);
     }
 }
+
+fn format_rates(input: u64, output: u64) -> String {
+    gettext!(
+        // Translators: For example 5 MB / s in, 1 MB / s out.
+        "{} / s in, {} / s out",
+        glib::format_size(input),
+        glib::format_size(output)
+    )
+}
@@ -0,0 +1,143 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use adw::prelude::*;
+use futures::Future;
+use futures::FutureExt;
+use futures::StreamExt;
+use futures::future::BoxFuture;
+use futures::stream;
+use gettextrs::gettext;
+use gettextrs::ngettext;
+use glib::clone;
+use glib::clone::Downgrade;
+use gtk::glib;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::podman;
+use crate::rt;
+use crate::utils;
+
+/// The maximum number of objects that are inspected at the same time.
+const MAX_CONCURRENT_INSPECTIONS: usize = 8;
+
+/// The id or name of an object together with the request of its inspection data.
+pub(crate) struct Inspection(String, BoxFuture<'static, anyhow::Result<Value>>);
+
+impl Inspection {
+    pub(crate) fn new<F, T>(id: String, future: F) -> Self
+    where
+        F: Future<Output = podman::Result<T>> + Send + 'static,
+        T: Serialize,
+    {
+        Self(
+            id,
+            async move { serde_json::to_value(future.await?).map_err(anyhow::Error::from) }.boxed(),
+        )
+    }
+
+    /// For objects whose inspection data is already known.
+    pub(crate) fn ready<T: Serialize>(id: String, data: &T) -> Self {
+        let value = serde_json::to_value(data).map_err(anyhow::Error::from);
+        Self(id, async move { value }.boxed())
+    }
+}
+
+/// Inspects all objects concurrently and copies the results as one JSON array to the clipboard,
+/// which resembles the output of `podman inspect obj1 obj2`. Objects that couldn't be inspected
+/// are represented by their id and the error.
+pub(crate) fn copy_combined_inspection<W>(widget: &W, inspections: Vec<Inspection>)
+where
+    W: IsA<gtk::Widget> + Downgrade<Weak = glib::WeakRef<W>>,
+{
+    let total = inspections.len();
+    if total == 0 {
+        return;
+    }
+
+    let toast = adw::Toast::builder()
+        .title(gettext!("Inspecting {} of {} objects", 0, total))
+        .timeout(0)
+        .build();
+    widget
+        .ancestor(adw::ToastOverlay::static_type())
+        .unwrap()
+        .downcast::<adw::ToastOverlay>()
+        .unwrap()
+        .add_toast(toast.clone());
+
+    let results = Rc::new(RefCell::new(Vec::with_capacity(total)));
+
+    rt::Pipe::new((), move |_| {
+        stream::iter(inspections)
+            .map(|Inspection(id, future)| async move { (id, future.await) })
+            .buffered(MAX_CONCURRENT_INSPECTIONS)
+            .boxed()
+    })
+    .on_next(clone!(
+        #[strong]
+        toast,
+        #[strong]
+        results,
+        move |(id, result): (String, anyhow::Result<Value>)| {
+            let mut results = results.borrow_mut();
+            results.push(match result {
+                Ok(value) => value,
+                Err(e) => {
+                    log::warn!("Error on inspecting '{id}': {e}");
+                    serde_json::json!({ "Id": id, "Error": e.to_string() })
+                }
+            });
+            toast.set_title(&gettext!(
+                "Inspecting {} of {} objects",
+                results.len(),
+                total
+            ));
+
+            glib::ControlFlow::Continue
+        }
+    ))
+    .on_finish(clone!(
+        #[weak]
+        widget,
+        move || {
+            toast.dismiss();
+
+            let results = results.take();
+            let failed = results
+                .iter()
+                .filter(|value| value.get("Error").is_some())
+                .count();
+
+            match serde_json::to_string_pretty(&results) {
+                Ok(text) => {
+                    widget.clipboard().set_text(&text);
+                    utils::show_toast(
+                        &widget,
+                        if failed == 0 {
+                            ngettext!(
+                                "Copied inspection of {} object",
+                                "Copied inspection of {} objects",
+                                total as u32,
+                                total
+                            )
+                        } else {
+                            ngettext!(
+                                "Copied inspection, {} object failed",
+                                "Copied inspection, {} objects failed",
+                                failed as u32,
+                                failed
+                            )
+                        },
+                    );
+                }
+                Err(e) => utils::show_error_toast(
+                    &widget,
+                    &gettext("Error on copying inspection"),
+                    &e.to_string(),
+                ),
+            }
+        }
+    ));
+}
@@ -178,4 +178,14 @@ impl KeyValRow {
     pub(crate) fn set_value_placeholder_text(&self, value: Option<&str>) {
         self.imp().value_entry.set_placeholder_text(value);
     }
+
+    /// Marks the value as invalid.
+    pub(crate) fn set_value_error(&self, error: bool) {
+        let value_entry = &*self.imp().value_entry;
+        if error {
+            value_entry.add_css_class("error");
+        } else {
+            value_entry.remove_css_class("error");
+        }
+    }
 }
@@ -0,0 +1,188 @@
+use std::cell::OnceCell;
+
+use adw::prelude::*;
+use adw::subclass::prelude::*;
+use gettextrs::gettext;
+use glib::Properties;
+use glib::clone;
+use glib::closure;
+use gtk::CompositeTemplate;
+use gtk::gio;
+use gtk::glib;
+
+use crate::model;
+use crate::utils;
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default, Properties, CompositeTemplate)]
+    #[properties(wrapper_type = super::MiniView)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/view/mini_view.ui")]
+    pub(crate) struct MiniView {
+        pub(super) filter: OnceCell<gtk::Filter>,
+        #[property(get, set = Self::set_container_list, nullable)]
+        pub(super) container_list: glib::WeakRef<model::ContainerList>,
+        #[template_child]
+        pub(super) stack: TemplateChild<gtk::Stack>,
+        #[template_child]
+        pub(super) list_box: TemplateChild<gtk::ListBox>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for MiniView {
+        const NAME: &'static str = "PdsMiniView";
+        type Type = super::MiniView;
+        type ParentType = gtk::Widget;
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.bind_template();
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for MiniView {
+        fn properties() -> &'static [glib::ParamSpec] {
+            Self::derived_properties()
+        }
+
+        fn set_property(&self, id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+            self.derived_set_property(id, value, pspec);
+        }
+
+        fn property(&self, id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+            self.derived_property(id, pspec)
+        }
+
+        fn constructed(&self) {
+            self.parent_constructed();
+
+            let filter = gtk::EveryFilter::new();
+            filter.append(
+                gtk::BoolFilter::builder()
+                    .expression(model::Container::this_expression("is-infra"))
+                    .invert(true)
+                    .build(),
+            );
+            // An expression is watched for every container, so the filter follows status changes.
+            filter.append(
+                gtk::BoolFilter::builder()
+                    .expression(
+                        model::Container::this_expression("status").chain_closure::<bool>(
+                            closure!(|_: model::Container, status: model::ContainerStatus| {
+                                status == model::ContainerStatus::Running
+                            }),
+                        ),
+                    )
+                    .build(),
+            );
+
+            self.filter.set(filter.upcast()).unwrap();
+        }
+
+        fn dispose(&self) {
+            utils::unparent_children(&*self.obj());
+        }
+    }
+
+    impl WidgetImpl for MiniView {}
+
+    impl MiniView {
+        pub(super) fn set_container_list(&self, value: Option<&model::ContainerList>) {
+            let obj = &*self.obj();
+            if obj.container_list().as_ref() == value {
+                return;
+            }
+
+            if let Some(value) = value {
+                let model =
+                    gtk::FilterListModel::new(Some(value.to_owned()), self.filter.get().cloned());
+
+                self.update_stack(&model);
+                model.connect_items_changed(clone!(
+                    #[weak]
+                    obj,
+                    move |model, _, _, _| obj.imp().update_stack(model)
+                ));
+
+                self.list_box.bind_model(Some(&model), |item| {
+                    container_row(item.downcast_ref::<model::Container>().unwrap()).upcast()
+                });
+            } else {
+                self.list_box.bind_model(None::<&gio::ListModel>, |_| {
+                    unreachable!();
+                });
+            }
+
+            self.container_list.set(value);
+            obj.notify_container_list();
+        }
+
+        fn update_stack(&self, model: &gtk::FilterListModel) {
+            self.stack.set_visible_child_name(if model.n_items() > 0 {
+                "containers"
+            } else {
+                "empty"
+            });
+        }
+    }
+}
+
+glib::wrapper! {
+    pub(crate) struct MiniView(ObjectSubclass<imp::MiniView>)
+        @extends gtk::Widget,
+        @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget;
+}
+
+/// A compact row showing the name and the resource usage of a running container. Activating it
+/// leaves the mini mode and shows the details of the container.
+fn container_row(container: &model::Container) -> adw::ActionRow {
+    let row = adw::ActionRow::builder().activatable(true).build();
+
+    container
+        .bind_property("name", &row, "title")
+        .sync_create()
+        .build();
+
+    let stats_expr = model::Container::this_expression("stats").chain_closure::<String>(closure!(
+        |_: model::Container, stats: Option<model::BoxedContainerStats>| {
+            stats
+                .map(|stats| {
+                    gettext!(
+                        "CPU: {} %, Memory: {}",
+                        stats
+                            .cpu
+                            .map(|perc| format!("{perc:.1}"))
+                            .unwrap_or_else(|| gettext("?")),
+                        stats
+                            .mem_usage
+                            .map(glib::format_size)
+                            .map(String::from)
+                            .unwrap_or_else(|| gettext("?")),
+                    )
+                })
+                .unwrap_or_default()
+        }
+    ));
+    stats_expr.bind(&row, "subtitle", Some(container));
+
+    row.add_suffix(&gtk::Image::from_icon_name("go-next-symbolic"));
+
+    row.connect_activated(clone!(
+        #[weak]
+        container,
+        move |_| {
+            gio::Application::default()
+                .unwrap()
+                .downcast::<crate::Application>()
+                .unwrap()
+                .main_window()
+                .show_container(&container);
+        }
+    ));
+
+    row
+}
@@ -11,7 +11,9 @@ mod connection_row;
 mod connections_sidebar;
 mod container;
 mod container_card;
+mod container_changes_page;
 mod container_commit_page;
+mod container_config_diff_page;
 mod container_creation_page;
 mod container_details_page;
 mod container_files_get_page;
@@ -20,10 +22,12 @@ mod container_health_check_log_row;
 mod container_health_check_page;
 mod container_log_page;
 mod container_menu_button;
+mod container_privileges_page;
 mod container_properties_group;
 mod container_renamer;
 mod container_resources;
 mod container_row;
+mod container_templates_page;
 mod container_terminal;
 mod container_terminal_page;
 mod container_volume_row;
@@ -36,12 +40,14 @@ mod containers_prune_page;
 mod containers_row;
 mod device_row;
 mod image;
+mod image_bases_page;
 mod image_build_page;
 mod image_details_page;
 mod image_history_page;
 mod image_menu_button;
 mod image_pull_page;
 mod image_row;
+mod image_scan_page;
 mod image_search_page;
 mod image_search_response_row;
 mod image_selection_combo_row;
@@ -51,7 +57,9 @@ mod images_prune_page;
 mod images_row;
 mod info_panel;
 mod info_row;
+mod inspection;
 mod key_val_row;
+mod mini_view;
 mod mount_row;
 mod pod;
 mod pod_creation_page;
@@ -63,6 +71,7 @@ mod pods_panel;
 mod pods_prune_page;
 mod pods_row;
 mod port_mapping_row;
+mod preferences_dialog;
 mod repo_tag_add_dialog;
 mod repo_tag_push_page;
 mod repo_tag_row;
@@ -97,7 +106,9 @@ pub(crate) use self::connection_row::ConnectionRow;
 pub(crate) use self::connections_sidebar::ConnectionsSidebar;
 pub(crate) use self::container::container_status_css_class;
 pub(crate) use self::container_card::ContainerCard;
+pub(crate) use self::container_changes_page::ContainerChangesPage;
 pub(crate) use self::container_commit_page::ContainerCommitPage;
+pub(crate) use self::container_config_diff_page::ContainerConfigDiffPage;
 pub(crate) use self::container_creation_page::ContainerCreationPage;
 pub(crate) use self::container_details_page::ContainerDetailsPage;
 pub(crate) use self::container_files_get_page::ContainerFilesGetPage;
@@ -106,10 +117,13 @@ pub(crate) use self::container_health_check_log_row::ContainerHealthCheckLogRow;
 pub(crate) use self::container_health_check_page::ContainerHealthCheckPage;
 pub(crate) use self::container_log_page::ContainerLogPage;
 pub(crate) use self::container_menu_button::ContainerMenuButton;
+pub(crate) use self::container_privileges_page::ContainerPrivilegesPage;
 pub(crate) use self::container_properties_group::ContainerPropertiesGroup;
 pub(crate) use self::container_renamer::ContainerRenamer;
 pub(crate) use self::container_resources::ContainerResources;
 pub(crate) use self::container_row::ContainerRow;
+pub(crate) use self::container_templates_page::ContainerTemplatesPage;
+pub(crate) use self::container_templates_page::ask_template_name;
 pub(crate) use self::container_terminal::ContainerTerminal;
 pub(crate) use self::container_terminal_page::ContainerTerminalPage;
 pub(crate) use self::container_volume_row::ContainerVolumeRow;
@@ -121,12 +135,14 @@ pub(crate) use self::containers_panel::ContainersPanel;
 pub(crate) use self::containers_prune_page::ContainersPrunePage;
 pub(crate) use self::containers_row::ContainersRow;
 pub(crate) use self::device_row::DeviceRow;
+pub(crate) use self::image_bases_page::ImageBasesPage;
 pub(crate) use self::image_build_page::ImageBuildPage;
 pub(crate) use self::image_details_page::ImageDetailsPage;
 pub(crate) use self::image_history_page::ImageHistoryPage;
 pub(crate) use self::image_menu_button::ImageMenuButton;
 pub(crate) use self::image_pull_page::ImagePullPage;
 pub(crate) use self::image_row::ImageRow;
+pub(crate) use self::image_scan_page::ImageScanPage;
 pub(crate) use self::image_search_page::ImageSearchPage;
 pub(crate) use self::image_search_response_row::ImageSearchResponseRow;
 pub(crate) use self::image_selection_combo_row::ImageSelectionComboRow;
@@ -138,6 +154,7 @@ pub(crate) use self::images_row::ImagesRow;
 pub(crate) use self::info_panel::InfoPanel;
 pub(crate) use self::info_row::InfoRow;
 pub(crate) use self::key_val_row::KeyValRow;
+pub(crate) use self::mini_view::MiniView;
 pub(crate) use self::mount_row::MountRow;
 pub(crate) use self::pod::pod_status_css_class;
 pub(crate) use self::pod_creation_page::PodCreationPage;
@@ -149,6 +166,7 @@ pub(crate) use self::pods_panel::PodsPanel;
 pub(crate) use self::pods_prune_page::PodsPrunePage;
 pub(crate) use self::pods_row::PodsRow;
 pub(crate) use self::port_mapping_row::PortMappingRow;
+pub(crate) use self::preferences_dialog::PreferencesDialog;
 pub(crate) use self::repo_tag_add_dialog::RepoTagAddDialog;
 pub(crate) use self::repo_tag_push_page::RepoTagPushPage;
 pub(crate) use self::repo_tag_row::RepoTagRow;
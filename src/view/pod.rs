@@ -45,13 +45,18 @@ pod_action!(fn restart => restart(false) => { gettextrs::gettext("Error on resta
 pod_action!(fn pause => pause() => { gettextrs::gettext("Error on pausing pod") });
 pod_action!(fn resume => resume() => { gettextrs::gettext("Error on resuming pod") });
 pod_action!(fn delete => delete(false) => { gettextrs::gettext("Error on deleting pod") });
+pod_action!(fn refresh => refresh() => { gettextrs::gettext("Error on refreshing pod") });
 
 pub(crate) fn show_delete_confirmation_dialog<W>(widget: &W, pod: Option<model::Pod>)
 where
     W: IsA<gtk::Widget> + Downgrade<Weak = glib::WeakRef<W>>,
 {
     if let Some(pod) = pod {
-        match pod.container_list().first_non_infra() {
+        match pod
+            .container_list()
+            .first_non_infra()
+            .filter(|_| utils::confirm_destructive_actions())
+        {
             Some(container) => {
                 let dialog = adw::AlertDialog::builder()
                 .heading(gettext("Confirm Pod Deletion"))
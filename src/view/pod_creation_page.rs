@@ -424,21 +424,15 @@ impl PodCreationPage {
     fn pull_and_create(&self, reference: &str) {
         let imp = self.imp();
 
-        let pull_opts = podman::opts::PullOpts::builder()
-            .reference(reference)
-            .quiet(false)
-            .build();
+        let client = self.client().unwrap();
+        let pull_opts = client.pull_opts(reference).quiet(false).build();
 
         let page = view::ActionPage::new(
-            &self
-                .client()
-                .unwrap()
-                .action_list()
-                .create_pod_download_infra(
-                    imp.name_entry_row.text().as_str(),
-                    pull_opts,
-                    self.opts(),
-                ),
+            &client.action_list().create_pod_download_infra(
+                imp.name_entry_row.text().as_str(),
+                pull_opts,
+                self.opts(),
+            ),
             self.show_view_artifact(),
         );
 
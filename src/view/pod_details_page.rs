@@ -55,6 +55,10 @@ mod imp {
         #[template_child]
         pub(super) status_label: TemplateChild<gtk::Label>,
         #[template_child]
+        pub(super) derived_status_row: TemplateChild<widget::PropertyWidgetRow>,
+        #[template_child]
+        pub(super) derived_status_label: TemplateChild<gtk::Label>,
+        #[template_child]
         pub(super) hostname_row: TemplateChild<widget::PropertyRow>,
     }
 
@@ -191,6 +195,32 @@ mod imp {
                 ))
                 .bind(&*self.status_label, "css-classes", Some(obj));
 
+            let derived_status_expr = pod_expr.chain_property::<model::Pod>("derived-status");
+            derived_status_expr
+                .chain_closure::<String>(closure!(|_: Self::Type, status: model::PodStatus| {
+                    status.to_string()
+                }))
+                .bind(&*self.derived_status_label, "label", Some(obj));
+            let css_classes = utils::css_classes(&*self.derived_status_label);
+            derived_status_expr
+                .chain_closure::<Vec<String>>(closure!(
+                    |_: Self::Type, status: model::PodStatus| {
+                        css_classes
+                            .iter()
+                            .cloned()
+                            .chain(Some(String::from(super::super::pod_status_css_class(
+                                status,
+                            ))))
+                            .collect::<Vec<_>>()
+                    }
+                ))
+                .bind(&*self.derived_status_label, "css-classes", Some(obj));
+            derived_status_expr
+                .chain_closure::<bool>(closure!(|_: Self::Type, status: model::PodStatus| {
+                    status != model::PodStatus::Unknown
+                }))
+                .bind(&*self.derived_status_row, "visible", Some(obj));
+
             hostname_expr.bind(&*self.hostname_row, "value", Some(obj));
             hostname_expr
                 .chain_closure::<bool>(closure!(
@@ -206,6 +236,19 @@ mod imp {
                     move || obj.update_actions()
                 ),
             );
+            pod_expr
+                .chain_property::<model::Pod>("pod-list")
+                .chain_property::<model::PodList>("client")
+                .chain_property::<model::Client>("connection")
+                .chain_property::<model::Connection>("read-only")
+                .watch(
+                    Some(obj),
+                    clone!(
+                        #[weak]
+                        obj,
+                        move || obj.update_actions()
+                    ),
+                );
             pod_expr
                 .chain_property::<model::Pod>("action-ongoing")
                 .watch(
@@ -295,7 +338,9 @@ impl PodDetailsPage {
                 .set_visible(!pod.action_ongoing() && can_stop);
             imp.spinning_button.set_visible(
                 pod.action_ongoing()
-                    || (!imp.start_or_resume_button.is_visible() && !imp.stop_button.is_visible()),
+                    || (!pod.is_read_only()
+                        && !imp.start_or_resume_button.is_visible()
+                        && !imp.stop_button.is_visible()),
             );
 
             self.action_set_enabled(ACTION_START_OR_RESUME, can_start_or_resume);
@@ -304,6 +349,10 @@ impl PodDetailsPage {
             self.action_set_enabled(ACTION_RESTART, pod.can_restart());
             self.action_set_enabled(ACTION_PAUSE, pod.can_pause());
             self.action_set_enabled(ACTION_DELETE, pod.can_delete());
+            self.action_set_enabled(
+                view::ContainersGroup::action_create_container(),
+                !pod.is_read_only(),
+            );
         }
     }
 
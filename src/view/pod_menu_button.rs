@@ -18,6 +18,7 @@ const ACTION_RESTART: &str = "pod-menu-button.restart";
 const ACTION_PAUSE: &str = "pod-menu-button.pause";
 const ACTION_RESUME: &str = "pod-menu-button.resume";
 const ACTION_DELETE: &str = "pod-menu-button.delete";
+const ACTION_REFRESH: &str = "pod-menu-button.refresh";
 
 mod imp {
     use super::*;
@@ -67,6 +68,10 @@ mod imp {
             klass.install_action(ACTION_DELETE, None, |widget, _, _| {
                 view::pod::show_delete_confirmation_dialog(widget, widget.pod());
             });
+
+            klass.install_action(ACTION_REFRESH, None, |widget, _, _| {
+                view::pod::refresh(widget, widget.pod());
+            });
         }
 
         fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
@@ -107,6 +112,15 @@ mod imp {
                 }))
                 .bind(&*self.menu_button, "sensitive", Some(obj));
 
+            pod_expr.chain_property::<model::Pod>("refreshing").watch(
+                Some(obj),
+                clone!(
+                    #[weak]
+                    obj,
+                    move || obj.update_refreshing()
+                ),
+            );
+
             pod_expr.chain_property::<model::Pod>("status").watch(
                 Some(obj),
                 clone!(
@@ -115,6 +129,20 @@ mod imp {
                     move || obj.update_actions()
                 ),
             );
+
+            pod_expr
+                .chain_property::<model::Pod>("pod-list")
+                .chain_property::<model::PodList>("client")
+                .chain_property::<model::Client>("connection")
+                .chain_property::<model::Connection>("read-only")
+                .watch(
+                    Some(obj),
+                    clone!(
+                        #[weak]
+                        obj,
+                        move || obj.update_actions()
+                    ),
+                );
         }
 
         fn dispose(&self) {
@@ -136,6 +164,13 @@ impl PodMenuButton {
         view::pod::create_container(self, self.pod());
     }
 
+    fn update_refreshing(&self) {
+        let refreshing = self.pod().map(|pod| pod.refreshing()).unwrap_or(false);
+
+        self.action_set_enabled(ACTION_REFRESH, !refreshing);
+        utils::set_menu_button_busy(&self.imp().menu_button, refreshing);
+    }
+
     fn update_actions(&self) {
         if let Some(pod) = self.pod() {
             let can_stop = pod.can_stop();
@@ -147,6 +182,7 @@ impl PodMenuButton {
             self.action_set_enabled(ACTION_RESUME, pod.can_resume());
             self.action_set_enabled(ACTION_PAUSE, pod.can_pause());
             self.action_set_enabled(ACTION_DELETE, pod.can_delete());
+            self.action_set_enabled(ACTION_CREATE_CONTAINER, !pod.is_read_only());
         }
     }
 }
@@ -34,6 +34,7 @@ const ACTION_START_OR_RESUME_SELECTION: &str = "pods-panel.start-or-resume-selec
 const ACTION_STOP_SELECTION: &str = "pods-panel.stop-selection";
 const ACTION_PAUSE_SELECTION: &str = "pods-panel.pause-selection";
 const ACTION_DELETE_SELECTION: &str = "pods-panel.delete-selection";
+const ACTION_COPY_INSPECTION_SELECTION: &str = "pods-panel.copy-inspection-selection";
 const ACTION_TOGGLE_SORT_DIRECTION: &str = "pods-panel.toggle-sort-direction";
 const ACTION_CHANGE_SORT_ATTRIBUTE: &str = "pods-panel.change-sort-attribute";
 const ACTION_TOGGLE_SHOW_RUNNING_PODS_FIRST: &str = "pods-panel.toggle-show-running-pods-first";
@@ -206,6 +207,9 @@ mod imp {
             klass.install_action(ACTION_DELETE_SELECTION, None, |widget, _, _| {
                 widget.delete_selection();
             });
+            klass.install_action(ACTION_COPY_INSPECTION_SELECTION, None, |widget, _, _| {
+                widget.copy_inspection_selection();
+            });
 
             klass.install_action(ACTION_TOGGLE_SORT_DIRECTION, None, |widget, _, _| {
                 widget.toggle_sort_direction();
@@ -263,6 +267,19 @@ mod imp {
             ));
             let collapsed_expr = Self::Type::this_expression("collapsed");
 
+            Self::Type::this_expression("pod-list")
+                .chain_property::<model::PodList>("client")
+                .chain_property::<model::Client>("connection")
+                .chain_property::<model::Connection>("read-only")
+                .watch(
+                    Some(obj),
+                    clone!(
+                        #[weak]
+                        obj,
+                        move || obj.imp().update_actions()
+                    ),
+                );
+
             gtk::ClosureExpression::new::<Option<String>>(
                 [
                     &pod_list_len_expr,
@@ -473,6 +490,9 @@ mod imp {
         fn on_notify_search_mode_enabled(&self) {
             if self.search_bar.is_search_mode() {
                 self.search_entry.grab_focus();
+            } else {
+                self.search_entry.set_text("");
+                self.list_box.child_focus(gtk::DirectionType::TabForward);
             }
         }
 
@@ -547,23 +567,17 @@ mod imp {
                 move |value| obj.imp().set_filter_stack_visible_child(value, &model)
             ));
 
-            ACTIONS_SELECTION
-                .iter()
-                .for_each(|action_name| obj.action_set_enabled(action_name, false));
             value.connect_notify_local(
                 Some("num-selected"),
                 clone!(
                     #[weak]
                     obj,
-                    move |list, _| {
-                        ACTIONS_SELECTION.iter().for_each(|action_name| {
-                            obj.action_set_enabled(action_name, list.num_selected() > 0);
-                        });
-                    }
+                    move |_, _| obj.imp().update_actions()
                 ),
             );
 
             self.pod_list.set(Some(value));
+            self.update_actions();
         }
 
         fn set_filter_stack_visible_child(
@@ -580,6 +594,26 @@ mod imp {
             );
         }
 
+        /// Disables all actions that modify the Podman host while the connection is read-only.
+        fn update_actions(&self) {
+            let obj = &*self.obj();
+            let pod_list = obj.pod_list();
+
+            let read_only = pod_list
+                .as_ref()
+                .and_then(model::PodList::client)
+                .is_some_and(|client| client.connection().read_only());
+            let any_selected = pod_list.is_some_and(|list| list.num_selected() > 0);
+
+            ACTIONS_SELECTION.iter().for_each(|action_name| {
+                obj.action_set_enabled(action_name, any_selected && !read_only);
+            });
+            obj.action_set_enabled(ACTION_COPY_INSPECTION_SELECTION, any_selected);
+
+            obj.action_set_enabled(ACTION_CREATE_POD, !read_only);
+            obj.action_set_enabled(ACTION_PRUNE_PODS, !read_only);
+        }
+
         fn update_filter(&self, filter_change: gtk::FilterChange) {
             if let Some(filter) = self.filter.get() {
                 filter.changed(filter_change);
@@ -624,8 +658,13 @@ impl PodsPanel {
         self.imp().search_bar.set_search_mode(value);
     }
 
-    pub(crate) fn toggle_search_mode(&self) {
-        self.set_search_mode(!self.imp().search_bar.is_search_mode());
+    pub(crate) fn focus_search(&self) {
+        let imp = self.imp();
+        if imp.search_bar.is_search_mode() {
+            imp.search_entry.grab_focus();
+        } else {
+            self.set_search_mode(true);
+        }
     }
 
     pub(crate) fn create_pod(&self) {
@@ -800,11 +839,38 @@ impl PodsPanel {
         }
     }
 
+    pub(crate) fn copy_inspection_selection(&self) {
+        let Some(list) = self.pod_list() else {
+            return;
+        };
+
+        view::inspection::copy_combined_inspection(
+            self,
+            list.selected_items()
+                .iter()
+                .map(|obj| obj.downcast_ref::<model::Pod>().unwrap())
+                .filter_map(|pod| {
+                    pod.api().map(|api| {
+                        view::inspection::Inspection::new(
+                            pod.id(),
+                            async move { api.inspect().await },
+                        )
+                    })
+                })
+                .collect(),
+        );
+    }
+
     pub(crate) fn delete_selection(&self) {
         if self.pod_list().map(|list| list.num_selected()).unwrap_or(0) == 0 {
             return;
         }
 
+        if !utils::confirm_destructive_actions() {
+            self.delete_selected();
+            return;
+        }
+
         let dialog = adw::AlertDialog::builder()
             .heading(gettext("Confirm Forced Deletion of Multiple Pods"))
             .body_use_markup(true)
@@ -823,31 +889,8 @@ impl PodsPanel {
             clone!(
                 #[weak(rename_to = obj)]
                 self,
-                move |_, response| if response == "delete"
-                    && let Some(list) = obj.pod_list()
-                {
-                    list.selected_items()
-                        .iter()
-                        .map(|obj| obj.downcast_ref::<model::Pod>().unwrap())
-                        .for_each(|pod| {
-                            pod.delete(
-                                true,
-                                clone!(
-                                    #[weak]
-                                    obj,
-                                    move |result| {
-                                        if let Err(e) = result {
-                                            utils::show_error_toast(
-                                                &obj,
-                                                &gettext("Error on deleting pod"),
-                                                &e.to_string(),
-                                            );
-                                        }
-                                    }
-                                ),
-                            );
-                        });
-                    list.set_selection_mode(false);
+                move |_, response| if response == "delete" {
+                    obj.delete_selected();
                 }
             ),
         );
@@ -855,6 +898,35 @@ impl PodsPanel {
         dialog.present(Some(self));
     }
 
+    fn delete_selected(&self) {
+        let Some(list) = self.pod_list() else {
+            return;
+        };
+
+        list.selected_items()
+            .iter()
+            .map(|obj| obj.downcast_ref::<model::Pod>().unwrap())
+            .for_each(|pod| {
+                pod.delete(
+                    true,
+                    clone!(
+                        #[weak(rename_to = obj)]
+                        self,
+                        move |result| {
+                            if let Err(e) = result {
+                                utils::show_error_toast(
+                                    &obj,
+                                    &gettext("Error on deleting pod"),
+                                    &e.to_string(),
+                                );
+                            }
+                        }
+                    ),
+                );
+            });
+        list.set_selection_mode(false);
+    }
+
     fn deselect_hidden_pods(&self, model: &gio::ListModel) {
         let visible_pods = model
             .iter::<glib::Object>()
@@ -0,0 +1,376 @@
+use std::cell::RefCell;
+
+use adw::prelude::*;
+use adw::subclass::prelude::*;
+use gettextrs::gettext;
+use glib::clone;
+use gtk::CompositeTemplate;
+use gtk::glib;
+
+use crate::model;
+use crate::utils;
+use crate::view;
+
+const ACTION_RESTART_EVENT_LISTENER: &str = "preferences-dialog.restart-event-listener";
+const ACTION_REGISTRY_LOGIN: &str = "preferences-dialog.registry-login";
+
+/// The container name schemes in the order of the combo row.
+const CONTAINER_NAME_SCHEMES: [&str; 3] = ["words", "uuid", "template"];
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default, CompositeTemplate)]
+    #[template(resource = "/com/github/marhkb/Pods/ui/view/preferences_dialog.ui")]
+    pub(crate) struct PreferencesDialog {
+        pub(super) settings: utils::PodsSettings,
+        pub(super) client: glib::WeakRef<model::Client>,
+        pub(super) handler_id: RefCell<Option<glib::SignalHandlerId>>,
+        #[template_child]
+        pub(super) events_polling_interval_spin_row: TemplateChild<adw::SpinRow>,
+        #[template_child]
+        pub(super) confirm_destructive_actions_switch_row: TemplateChild<adw::SwitchRow>,
+        #[template_child]
+        pub(super) low_resource_mode_switch_row: TemplateChild<adw::SwitchRow>,
+        #[template_child]
+        pub(super) notify_lifecycle_changes_switch_row: TemplateChild<adw::SwitchRow>,
+        #[template_child]
+        pub(super) notify_image_changes_switch_row: TemplateChild<adw::SwitchRow>,
+        #[template_child]
+        pub(super) notify_errors_switch_row: TemplateChild<adw::SwitchRow>,
+        #[template_child]
+        pub(super) open_logs_after_start_switch_row: TemplateChild<adw::SwitchRow>,
+        #[template_child]
+        pub(super) container_name_scheme_combo_row: TemplateChild<adw::ComboRow>,
+        #[template_child]
+        pub(super) container_name_template_entry_row: TemplateChild<adw::EntryRow>,
+        #[template_child]
+        pub(super) collapse_stopped_containers_switch_row: TemplateChild<adw::SwitchRow>,
+        #[template_child]
+        pub(super) collapse_stopped_containers_delay_spin_row: TemplateChild<adw::SpinRow>,
+        #[template_child]
+        pub(super) terminal_shell_entry_row: TemplateChild<adw::EntryRow>,
+        #[template_child]
+        pub(super) image_scanner_command_entry_row: TemplateChild<adw::EntryRow>,
+        #[template_child]
+        pub(super) registry_logins_group: TemplateChild<adw::PreferencesGroup>,
+        #[template_child]
+        pub(super) registry_logins_list_box: TemplateChild<gtk::ListBox>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for PreferencesDialog {
+        const NAME: &'static str = "PdsPreferencesDialog";
+        type Type = super::PreferencesDialog;
+        type ParentType = adw::PreferencesDialog;
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.bind_template();
+
+            klass.install_action(ACTION_RESTART_EVENT_LISTENER, None, |widget, _, _| {
+                widget.restart_event_listener();
+            });
+
+            klass.install_action_async(ACTION_REGISTRY_LOGIN, None, async |widget, _, _| {
+                widget.registry_login().await;
+            });
+        }
+
+        fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
+            obj.init_template();
+        }
+    }
+
+    impl ObjectImpl for PreferencesDialog {
+        fn constructed(&self) {
+            self.parent_constructed();
+
+            self.settings
+                .bind(
+                    "events-polling-interval",
+                    &*self.events_polling_interval_spin_row,
+                    "value",
+                )
+                .build();
+            self.settings
+                .bind(
+                    "low-resource-mode",
+                    &*self.low_resource_mode_switch_row,
+                    "active",
+                )
+                .build();
+            self.settings
+                .bind(
+                    "confirm-destructive-actions",
+                    &*self.confirm_destructive_actions_switch_row,
+                    "active",
+                )
+                .build();
+            self.settings
+                .bind(
+                    "notify-lifecycle-changes",
+                    &*self.notify_lifecycle_changes_switch_row,
+                    "active",
+                )
+                .build();
+            self.settings
+                .bind(
+                    "notify-image-changes",
+                    &*self.notify_image_changes_switch_row,
+                    "active",
+                )
+                .build();
+            self.settings
+                .bind("notify-errors", &*self.notify_errors_switch_row, "active")
+                .build();
+            self.settings
+                .bind(
+                    "open-logs-after-start",
+                    &*self.open_logs_after_start_switch_row,
+                    "active",
+                )
+                .build();
+            self.settings
+                .bind(
+                    "container-name-scheme",
+                    &*self.container_name_scheme_combo_row,
+                    "selected",
+                )
+                .mapping(|variant, _| {
+                    let scheme = variant.str().unwrap_or_default();
+                    CONTAINER_NAME_SCHEMES
+                        .iter()
+                        .position(|s| *s == scheme)
+                        .map(|position| (position as u32).to_value())
+                })
+                .set_mapping(|value, _| {
+                    CONTAINER_NAME_SCHEMES
+                        .get(value.get::<u32>().ok()? as usize)
+                        .map(|scheme| scheme.to_variant())
+                })
+                .build();
+            self.settings
+                .bind(
+                    "container-name-scheme",
+                    &*self.container_name_template_entry_row,
+                    "visible",
+                )
+                .mapping(|variant, _| Some((variant.str() == Some("template")).to_value()))
+                .get_only()
+                .build();
+            self.settings
+                .bind(
+                    "container-name-template",
+                    &*self.container_name_template_entry_row,
+                    "text",
+                )
+                .build();
+            self.settings
+                .bind(
+                    "collapse-stopped-containers",
+                    &*self.collapse_stopped_containers_switch_row,
+                    "active",
+                )
+                .build();
+            self.settings
+                .bind(
+                    "collapse-stopped-containers-delay",
+                    &*self.collapse_stopped_containers_delay_spin_row,
+                    "value",
+                )
+                .build();
+            self.settings
+                .bind("terminal-shell", &*self.terminal_shell_entry_row, "text")
+                .build();
+            self.settings
+                .bind(
+                    "image-scanner-command",
+                    &*self.image_scanner_command_entry_row,
+                    "text",
+                )
+                .build();
+        }
+
+        fn dispose(&self) {
+            if let Some(client) = self.client.upgrade() {
+                client.disconnect(self.handler_id.take().unwrap());
+            }
+        }
+    }
+
+    impl WidgetImpl for PreferencesDialog {
+        fn root(&self) {
+            self.parent_root();
+
+            // Registry logins belong to the client of the window the dialog is shown on.
+            let obj = &*self.obj();
+            let Some(client) = obj.active_client() else {
+                return;
+            };
+
+            if let Some(old_client) = self.client.upgrade() {
+                old_client.disconnect(self.handler_id.take().unwrap());
+            }
+            self.handler_id
+                .replace(Some(client.connect_registry_logins_changed(clone!(
+                    #[weak]
+                    obj,
+                    move |_| obj.update_registry_logins()
+                ))));
+            self.client.set(Some(&client));
+
+            self.registry_logins_group.set_visible(true);
+            obj.update_registry_logins();
+        }
+    }
+    impl AdwDialogImpl for PreferencesDialog {}
+    impl PreferencesDialogImpl for PreferencesDialog {}
+}
+
+glib::wrapper! {
+    pub(crate) struct PreferencesDialog(ObjectSubclass<imp::PreferencesDialog>)
+        @extends gtk::Widget, adw::Dialog, adw::PreferencesDialog,
+        @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget;
+}
+
+impl Default for PreferencesDialog {
+    fn default() -> Self {
+        glib::Object::builder().build()
+    }
+}
+
+impl PreferencesDialog {
+    fn active_client(&self) -> Option<model::Client> {
+        self.root()
+            .and_downcast::<view::Window>()
+            .and_then(|window| window.connection_manager().client())
+    }
+
+    fn restart_event_listener(&self) {
+        let client = self.active_client();
+
+        self.add_toast(adw::Toast::new(&match client {
+            Some(client) => {
+                client.restart_event_listener();
+                gettext("Event stream has been restarted")
+            }
+            None => gettext("There is no active connection"),
+        }));
+    }
+
+    fn update_registry_logins(&self) {
+        let imp = self.imp();
+
+        imp.registry_logins_list_box.remove_all();
+
+        let Some(client) = imp.client.upgrade() else {
+            return;
+        };
+
+        client
+            .registry_logins()
+            .into_iter()
+            .for_each(|(registry, username)| {
+                let row = adw::ActionRow::builder()
+                    .title(&registry)
+                    .subtitle(&username)
+                    .use_markup(false)
+                    .build();
+
+                let button = gtk::Button::builder()
+                    .css_classes(["flat"])
+                    .icon_name("system-log-out-symbolic")
+                    .tooltip_text(gettext("Log Out"))
+                    .valign(gtk::Align::Center)
+                    .build();
+                button.connect_clicked(clone!(
+                    #[weak(rename_to = obj)]
+                    self,
+                    #[weak]
+                    client,
+                    move |_| {
+                        client.registry_logout(
+                            &registry,
+                            clone!(
+                                #[weak]
+                                obj,
+                                move |result| if let Err(e) = result {
+                                    log::error!("Error on logging out of registry: {e}");
+                                    obj.add_toast(adw::Toast::new(&gettext!(
+                                        "Error on logging out: {}",
+                                        e
+                                    )));
+                                }
+                            ),
+                        );
+                    }
+                ));
+                row.add_suffix(&button);
+
+                imp.registry_logins_list_box.append(&row);
+            });
+    }
+
+    async fn registry_login(&self) {
+        let Some(client) = self.imp().client.upgrade() else {
+            return;
+        };
+
+        let registry_entry_row = adw::EntryRow::builder()
+            .title(gettext("Registry"))
+            .text("docker.io")
+            .build();
+        let username_entry_row = adw::EntryRow::builder().title(gettext("Username")).build();
+        let password_entry_row = adw::PasswordEntryRow::builder()
+            .title(gettext("Password or Token"))
+            .build();
+
+        let list_box = gtk::ListBox::builder()
+            .css_classes(["boxed-list"])
+            .selection_mode(gtk::SelectionMode::None)
+            .build();
+        list_box.append(&registry_entry_row);
+        list_box.append(&username_entry_row);
+        list_box.append(&password_entry_row);
+
+        let dialog = adw::AlertDialog::builder()
+            .heading(gettext("Log In to Registry"))
+            .extra_child(&list_box)
+            .build();
+
+        dialog.add_responses(&[
+            ("cancel", &gettext("_Cancel")),
+            ("login", &gettext("_Log In")),
+        ]);
+        dialog.set_default_response(Some("login"));
+        dialog.set_response_appearance("login", adw::ResponseAppearance::Suggested);
+
+        if "login" != dialog.choose_future(self).await {
+            return;
+        }
+
+        let registry = registry_entry_row.text();
+        if registry.trim().is_empty() {
+            return;
+        }
+
+        client.registry_login(
+            &registry,
+            &username_entry_row.text(),
+            &password_entry_row.text(),
+            clone!(
+                #[weak(rename_to = obj)]
+                self,
+                move |result| {
+                    obj.add_toast(adw::Toast::new(&match result {
+                        Ok(()) => gettext("Logged in to registry"),
+                        Err(e) => {
+                            log::error!("Error on logging in to registry: {e}");
+                            gettext!("Error on logging in: {}", e)
+                        }
+                    }));
+                }
+            ),
+        );
+    }
+}
@@ -305,6 +305,8 @@ impl RepoTagPushPage {
                     .password(imp.password_entry_row.text())
                     .build(),
             )
+        } else if let Some(auth) = client.registry_auth(&destination) {
+            opts.auth(auth)
         } else {
             opts
         };
@@ -3,12 +3,12 @@ use adw::subclass::prelude::*;
 use gettextrs::gettext;
 use glib::Properties;
 use glib::clone;
+use glib::closure;
 use gtk::CompositeTemplate;
 use gtk::glib;
 
 use crate::model;
 use crate::podman;
-use crate::rt;
 use crate::utils;
 use crate::view;
 
@@ -26,7 +26,13 @@ mod imp {
         #[property(get, set, construct_only, nullable)]
         pub(super) repo_tag: glib::WeakRef<model::RepoTag>,
         #[template_child]
+        pub(super) check_button_revealer: TemplateChild<gtk::Revealer>,
+        #[template_child]
+        pub(super) check_button: TemplateChild<gtk::CheckButton>,
+        #[template_child]
         pub(super) label: TemplateChild<gtk::Label>,
+        #[template_child]
+        pub(super) end_box_revealer: TemplateChild<gtk::Revealer>,
     }
 
     #[glib::object_subclass]
@@ -72,6 +78,29 @@ mod imp {
 
             let obj = &*self.obj();
 
+            let repo_tag_list_expr = Self::Type::this_expression("repo-tag")
+                .chain_property::<model::RepoTag>("repo-tag-list");
+            let selection_mode_expr =
+                repo_tag_list_expr.chain_property::<model::RepoTagList>("selection-mode");
+
+            selection_mode_expr.bind(&*self.check_button_revealer, "reveal-child", Some(obj));
+            gtk::ClosureExpression::new::<bool>(
+                [
+                    selection_mode_expr.upcast_ref(),
+                    repo_tag_list_expr
+                        .chain_property::<model::RepoTagList>("image")
+                        .chain_property::<model::Image>("image-list")
+                        .chain_property::<model::ImageList>("client")
+                        .chain_property::<model::Client>("connection")
+                        .chain_property::<model::Connection>("read-only")
+                        .upcast_ref(),
+                ],
+                closure!(|_: Self::Type, is_selection_mode: bool, read_only: bool| {
+                    !is_selection_mode && !read_only
+                }),
+            )
+            .bind(&*self.end_box_revealer, "reveal-child", Some(obj));
+
             let style_manager = adw::StyleManager::default();
             style_manager.connect_dark_notify(clone!(
                 #[weak]
@@ -96,6 +125,11 @@ mod imp {
             ));
 
             if let Some(repo_tag) = obj.repo_tag() {
+                repo_tag
+                    .bind_property("selected", &*self.check_button, "active")
+                    .flags(glib::BindingFlags::SYNC_CREATE | glib::BindingFlags::BIDIRECTIONAL)
+                    .build();
+
                 repo_tag.connect_notify_local(
                     Some("to-be-deleted"),
                     clone!(
@@ -163,7 +197,7 @@ impl RepoTagRow {
 
     fn update(&self) {
         if let Some(repo_tag) = self.repo_tag()
-            && let Some(action_list) = repo_tag
+            && let Some(client) = repo_tag
                 .repo_tag_list()
                 .as_ref()
                 .and_then(model::RepoTagList::image)
@@ -171,15 +205,13 @@ impl RepoTagRow {
                 .and_then(model::Image::image_list)
                 .as_ref()
                 .and_then(model::ImageList::client)
-                .as_ref()
-                .map(model::Client::action_list)
         {
             let reference = repo_tag.full();
 
-            action_list.download_image(
+            client.action_list().download_image(
                 &reference,
-                podman::opts::PullOpts::builder()
-                    .reference(&reference)
+                client
+                    .pull_opts(&reference)
                     .policy(podman::opts::PullPolicy::Newer)
                     .build(),
             );
@@ -193,37 +225,9 @@ impl RepoTagRow {
     }
 
     async fn untag(&self) {
-        let repo_tag = self.repo_tag().unwrap();
-        repo_tag.set_to_be_deleted(true);
-
-        let result = rt::Promise::new({
-            let image = repo_tag
-                .repo_tag_list()
-                .unwrap()
-                .image()
-                .unwrap()
-                .api()
-                .unwrap();
-            let repo = repo_tag.repo();
-            let tag = repo_tag.tag();
-            async move {
-                image
-                    .untag(
-                        &podman::opts::ImageTagOpts::builder()
-                            .repo(repo)
-                            .tag(tag)
-                            .build(),
-                    )
-                    .await
-            }
-        })
-        .exec()
-        .await;
-
-        if let Err(e) = result {
-            repo_tag.set_to_be_deleted(false);
-
-            log::warn!("Error on untagging image: {e}");
+        if let Some(repo_tag) = self.repo_tag()
+            && let Err(e) = repo_tag.untag().await
+        {
             utils::show_error_toast(self, &gettext("Error on untagging image"), &e.to_string());
         }
     }
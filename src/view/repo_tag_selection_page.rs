@@ -1,3 +1,4 @@
+use std::cell::Cell;
 use std::cell::OnceCell;
 use std::cell::RefCell;
 use std::sync::OnceLock;
@@ -20,6 +21,7 @@ use crate::utils;
 use crate::view;
 
 const ACTION_SELECT: &str = "repo-tag-selection-page.select";
+const ACTION_SELECT_ALL: &str = "repo-tag-selection-page.select-all";
 
 mod imp {
     use super::*;
@@ -38,6 +40,8 @@ mod imp {
         pub(super) image_name: OnceCell<String>,
         #[property(get, set, construct)]
         pub(super) action_button_name: RefCell<String>,
+        #[property(get, set)]
+        pub(super) allow_all_tags: Cell<bool>,
         #[template_child]
         pub(super) filter_button: TemplateChild<gtk::ToggleButton>,
         #[template_child]
@@ -80,6 +84,9 @@ mod imp {
             klass.install_action(ACTION_SELECT, None, |widget, _, _| {
                 widget.select();
             });
+            klass.install_action(ACTION_SELECT_ALL, None, |widget, _, _| {
+                widget.select_all();
+            });
         }
 
         fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
@@ -95,6 +102,9 @@ mod imp {
                     Signal::builder("image-selected")
                         .param_types([String::static_type()])
                         .build(),
+                    Signal::builder("all-tags-selected")
+                        .param_types([String::static_type()])
+                        .build(),
                 ]
             })
         }
@@ -117,6 +127,7 @@ mod imp {
             let obj = &*self.obj();
 
             obj.action_set_enabled(ACTION_SELECT, false);
+            obj.action_set_enabled(ACTION_SELECT_ALL, false);
 
             let (abort_handle, abort_registration) = future::AbortHandle::new_pair();
             self.search_abort_handle.set(abort_handle).unwrap();
@@ -142,6 +153,7 @@ mod imp {
                             let imp = obj.imp();
 
                             obj.action_set_enabled(ACTION_SELECT, true);
+                            obj.action_set_enabled(ACTION_SELECT_ALL, true);
 
                             responses.into_iter().for_each(|response| {
                                 obj.imp()
@@ -343,6 +355,12 @@ impl RepoTagSelectionPage {
         }
     }
 
+    pub(crate) fn select_all(&self) {
+        if self.allow_all_tags() {
+            self.emit_by_name::<()>("all-tags-selected", &[&self.image_name()]);
+        }
+    }
+
     pub(crate) fn selected_image(&self) -> Option<String> {
         self.imp()
             .selection
@@ -363,4 +381,17 @@ impl RepoTagSelectionPage {
             None
         })
     }
+
+    pub(crate) fn connect_all_tags_selected<F: Fn(&Self, &String) + 'static>(
+        &self,
+        f: F,
+    ) -> glib::SignalHandlerId {
+        self.connect_local("all-tags-selected", true, move |values| {
+            let obj = values[0].get::<Self>().unwrap();
+            let repository = values[1].get::<String>().unwrap();
+            f(&obj, &repository);
+
+            None
+        })
+    }
 }
@@ -19,6 +19,7 @@ use crate::widget;
 
 const ACTION_TOGGLE_SEARCH: &str = "source-view-page.toggle-search";
 const ACTION_EXIT_SEARCH: &str = "source-view-page.exit-search";
+const ACTION_COPY_TO_CLIPBOARD: &str = "source-view-page.copy-to-clipboard";
 const ACTION_SAVE_TO_FILE: &str = "source-view-page.save-to-file";
 const ACTION_ZOOM_OUT: &str = "source-view-page.zoom-out";
 const ACTION_ZOOM_IN: &str = "source-view-page.zoom-in";
@@ -31,6 +32,7 @@ pub(crate) enum Entity {
         container: glib::WeakRef<model::Container>,
         mode: Mode,
     },
+    ContainerQuadlet(glib::WeakRef<model::Container>),
     Pod {
         pod: glib::WeakRef<model::Pod>,
         mode: Mode,
@@ -48,6 +50,9 @@ impl Entity {
                     mode.file_ext()
                 )
             }
+            Self::ContainerQuadlet(container) => {
+                format!("{}.container", container.upgrade().unwrap().name())
+            }
             Self::Pod { pod, mode } => {
                 format!("{}.{}", pod.upgrade().unwrap().name(), mode.file_ext())
             }
@@ -70,6 +75,20 @@ impl Mode {
             Self::Kube => "yaml",
         }
     }
+
+    fn language(&self) -> &str {
+        match self {
+            Self::Inspect => "json",
+            Self::Kube => "yaml",
+        }
+    }
+
+    fn error_heading(&self) -> String {
+        match self {
+            Self::Inspect => gettext("Inspection error"),
+            Self::Kube => gettext("Kube generation error"),
+        }
+    }
 }
 
 mod imp {
@@ -127,6 +146,10 @@ mod imp {
                 widget.set_search_mode(false);
             });
 
+            klass.install_action(ACTION_COPY_TO_CLIPBOARD, None, |widget, _, _| {
+                widget.copy_to_clipboard();
+            });
+
             klass.install_action_async(ACTION_SAVE_TO_FILE, None, |widget, _, _| async move {
                 widget.save_to_file().await;
             });
@@ -285,6 +308,15 @@ impl From<Entity> for ScalableTextViewPage {
                     container.upgrade().as_ref(),
                 );
             }
+            Entity::ContainerQuadlet(container) => {
+                imp.window_title
+                    .set_title(&gettext("Container Quadlet Generation"));
+                model::Container::this_expression("name").bind(
+                    &*imp.window_title,
+                    "subtitle",
+                    container.upgrade().as_ref(),
+                );
+            }
             Entity::Pod { mode, pod } => {
                 imp.window_title.set_title(&match mode {
                     Mode::Inspect => gettext("Pod Inspection"),
@@ -305,14 +337,8 @@ impl From<Entity> for ScalableTextViewPage {
 
         let language = match &entity {
             Entity::Image(_) | Entity::Volume(_) => "json",
-            Entity::Container { mode, .. } => match mode {
-                Mode::Inspect => "json",
-                Mode::Kube => "yaml",
-            },
-            Entity::Pod { mode, .. } => match mode {
-                Mode::Inspect => "json",
-                Mode::Kube => "yaml",
-            },
+            Entity::ContainerQuadlet(_) => "ini",
+            Entity::Container { mode, .. } | Entity::Pod { mode, .. } => mode.language(),
         };
 
         match sourceview5::LanguageManager::default().language(language) {
@@ -338,7 +364,7 @@ impl From<Entity> for ScalableTextViewPage {
                 .defer(clone!(
                     #[weak]
                     obj,
-                    move |result| obj.init(result, Mode::Inspect)
+                    move |result| obj.init(result, Mode::Inspect.error_heading())
                 ));
             }
             Entity::Container { container, mode } => {
@@ -363,7 +389,14 @@ impl From<Entity> for ScalableTextViewPage {
                 .defer(clone!(
                     #[weak]
                     obj,
-                    move |result| obj.init(result, mode)
+                    move |result| obj.init(result, mode.error_heading())
+                ));
+            }
+            Entity::ContainerQuadlet(container) => {
+                container.upgrade().unwrap().to_quadlet(clone!(
+                    #[weak]
+                    obj,
+                    move |result| obj.init(result, gettext("Quadlet generation error"))
                 ));
             }
             Entity::Pod { pod, mode } => {
@@ -388,14 +421,14 @@ impl From<Entity> for ScalableTextViewPage {
                 .defer(clone!(
                     #[weak]
                     obj,
-                    move |result| obj.init(result, mode)
+                    move |result| obj.init(result, mode.error_heading())
                 ));
             }
             Entity::Volume(volume) => {
                 obj.init(
                     serde_json::to_string_pretty(&*volume.upgrade().unwrap().inner())
                         .map_err(anyhow::Error::from),
-                    Mode::Inspect,
+                    Mode::Inspect.error_heading(),
                 );
             }
         };
@@ -407,7 +440,7 @@ impl From<Entity> for ScalableTextViewPage {
 }
 
 impl ScalableTextViewPage {
-    fn init(&self, result: anyhow::Result<String>, mode: Mode) {
+    fn init(&self, result: anyhow::Result<String>, error_heading: String) {
         let imp = self.imp();
         match result {
             Ok(text) => {
@@ -416,19 +449,20 @@ impl ScalableTextViewPage {
             }
             Err(e) => {
                 imp.spinner.set_visible(false);
-                utils::show_error_toast(
-                    self,
-                    &match mode {
-                        Mode::Inspect => gettext("Inspection error"),
-                        Mode::Kube => gettext("Kube generation error"),
-                    },
-                    &e.to_string(),
-                );
+                utils::show_error_toast(self, &error_heading, &e.to_string());
                 utils::navigation_view(self).pop();
             }
         }
     }
 
+    fn copy_to_clipboard(&self) {
+        let buffer = &*self.imp().source_buffer;
+        self.clipboard()
+            .set_text(&buffer.text(&buffer.start_iter(), &buffer.end_iter(), false));
+
+        utils::show_toast(self, gettext("Copied to clipboard"));
+    }
+
     async fn save_to_file(&self) {
         let imp = self.imp();
 
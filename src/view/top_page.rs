@@ -1,7 +1,9 @@
 use std::cell::OnceCell;
 use std::cell::RefCell;
 use std::collections::HashMap;
+use std::ops::Deref;
 use std::rc::Rc;
+use std::time::Duration;
 
 use adw::prelude::*;
 use adw::subclass::prelude::*;
@@ -12,8 +14,10 @@ use glib::clone;
 use glib::closure;
 use gtk::CompositeTemplate;
 use gtk::gdk;
+use gtk::gio;
 use gtk::glib;
 
+use crate::config;
 use crate::model;
 use crate::podman;
 use crate::rt;
@@ -22,6 +26,27 @@ use crate::utils;
 const ACTION_SEARCH: &str = "top-page.search";
 const ACTION_KILL: &str = "top-page.kill";
 
+/// Column widths are saved once resizing has settled for this long.
+const SAVE_COLUMN_WIDTHS_DELAY_MS: u64 = 500;
+
+#[derive(Debug)]
+struct Settings(gio::Settings);
+impl Default for Settings {
+    fn default() -> Self {
+        Self(gio::Settings::new(&format!(
+            "{}.view.pages.top",
+            config::APP_ID
+        )))
+    }
+}
+impl Deref for Settings {
+    type Target = gio::Settings;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
 mod imp {
     use super::*;
 
@@ -29,10 +54,12 @@ mod imp {
     #[properties(wrapper_type = super::TopPage)]
     #[template(resource = "/com/github/marhkb/Pods/ui/view/top_page.ui")]
     pub(crate) struct TopPage {
+        pub(super) settings: Settings,
         pub(super) filter: OnceCell<gtk::Filter>,
         pub(super) search_term: RefCell<String>,
         pub(super) selection: OnceCell<gtk::MultiSelection>,
         pub(super) action_bar: OnceCell<gtk::ActionBar>,
+        pub(super) save_columns_source: RefCell<Option<glib::SourceId>>,
         #[property(get, set, construct_only, nullable)]
         /// A `Container` or a `Pod`
         pub(super) top_source: glib::WeakRef<glib::Object>,
@@ -119,6 +146,13 @@ mod imp {
                 CpuTime,
             }
 
+            // Columns can be hidden from the header menu of any column. Which ones are hidden is
+            // remembered along with the widths the columns have been resized to.
+            let column_widths = self.settings.get::<HashMap<String, i32>>("column-widths");
+            let hidden_columns = self.settings.strv("hidden-columns");
+            let columns_menu = gio::Menu::new();
+            let column_actions = gio::SimpleActionGroup::new();
+
             [
                 (
                     "user",
@@ -285,9 +319,13 @@ mod imp {
                 });
 
                 let column = gtk::ColumnViewColumn::builder()
-                    .title(title)
+                    .id(property_name)
+                    .title(title.as_str())
                     .factory(&factory)
                     .expand(hexpand)
+                    .resizable(true)
+                    .header_menu(&columns_menu)
+                    .visible(!hidden_columns.iter().any(|id| id == property_name))
                     .sorter(&match property_type {
                         PropertyType::String => {
                             gtk::StringSorter::new(Some(property_expr)).upcast::<gtk::Sorter>()
@@ -301,8 +339,46 @@ mod imp {
                     })
                     .build();
 
+                if let Some(width) = column_widths.get(property_name) {
+                    column.set_fixed_width(*width);
+                }
+                column.connect_fixed_width_notify(clone!(
+                    #[weak]
+                    obj,
+                    move |_| obj.schedule_save_columns()
+                ));
+                column.connect_visible_notify(clone!(
+                    #[weak]
+                    obj,
+                    move |_| obj.save_columns()
+                ));
+
+                column_actions.add_action(&gio::PropertyAction::new(
+                    property_name,
+                    &column,
+                    "visible",
+                ));
+                columns_menu.append(Some(&title), Some(&format!("columns.{property_name}")));
+
                 self.column_view.append_column(&column);
             });
+            obj.insert_action_group("columns", Some(&column_actions));
+
+            let sort_column = self.settings.string("sort-column");
+            if let Some(column) = obj
+                .columns()
+                .into_iter()
+                .find(|column| column.id().is_some_and(|id| id == sort_column))
+            {
+                self.column_view.sort_by_column(
+                    Some(&column),
+                    if self.settings.string("sort-direction") == "desc" {
+                        gtk::SortType::Descending
+                    } else {
+                        gtk::SortType::Ascending
+                    },
+                );
+            }
 
             let model = obj
                 .top_source()
@@ -318,6 +394,17 @@ mod imp {
                 .unwrap();
 
             let sorter = self.column_view.sorter().unwrap();
+            let column_view_sorter = sorter.downcast_ref::<gtk::ColumnViewSorter>().unwrap();
+            column_view_sorter.connect_primary_sort_column_notify(clone!(
+                #[weak]
+                obj,
+                move |_| obj.save_sorting()
+            ));
+            column_view_sorter.connect_primary_sort_order_notify(clone!(
+                #[weak]
+                obj,
+                move |_| obj.save_sorting()
+            ));
             model.connect_updated(clone!(
                 #[weak]
                 sorter,
@@ -394,6 +481,10 @@ mod imp {
         }
 
         fn dispose(&self) {
+            if let Some(source_id) = self.save_columns_source.take() {
+                source_id.remove();
+                self.obj().save_columns();
+            }
             utils::unparent_children(&*self.obj());
         }
     }
@@ -467,6 +558,90 @@ impl TopPage {
         self.set_search_mode(!self.imp().search_bar.is_search_mode());
     }
 
+    fn columns(&self) -> impl Iterator<Item = gtk::ColumnViewColumn> {
+        self.imp()
+            .column_view
+            .columns()
+            .iter::<gtk::ColumnViewColumn>()
+            .map(Result::unwrap)
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+
+    /// Dragging a column emits a width change for every pixel, so saving is deferred until the
+    /// resize is over.
+    fn schedule_save_columns(&self) {
+        let imp = self.imp();
+
+        if let Some(source_id) = imp.save_columns_source.take() {
+            source_id.remove();
+        }
+
+        imp.save_columns_source
+            .replace(Some(glib::timeout_add_local_once(
+                Duration::from_millis(SAVE_COLUMN_WIDTHS_DELAY_MS),
+                clone!(
+                    #[weak(rename_to = obj)]
+                    self,
+                    move || {
+                        obj.imp().save_columns_source.take();
+                        obj.save_columns();
+                    }
+                ),
+            )));
+    }
+
+    fn save_columns(&self) {
+        let settings = &self.imp().settings;
+
+        let column_widths = self
+            .columns()
+            .filter(|column| column.fixed_width() > 0)
+            .filter_map(|column| column.id().map(|id| (id.to_string(), column.fixed_width())))
+            .collect::<HashMap<_, _>>();
+        if let Err(e) = settings.set("column-widths", column_widths) {
+            log::warn!("Error on saving the process column widths: {e}");
+        }
+
+        let hidden_columns = self
+            .columns()
+            .filter(|column| !column.is_visible())
+            .filter_map(|column| column.id())
+            .collect::<Vec<_>>();
+        if let Err(e) = settings.set_strv("hidden-columns", hidden_columns) {
+            log::warn!("Error on saving the hidden process columns: {e}");
+        }
+    }
+
+    fn save_sorting(&self) {
+        let imp = self.imp();
+
+        let Some(sorter) = imp
+            .column_view
+            .sorter()
+            .and_downcast::<gtk::ColumnViewSorter>()
+        else {
+            return;
+        };
+
+        let sort_column = sorter
+            .primary_sort_column()
+            .and_then(|column| column.id())
+            .unwrap_or_default();
+        if let Err(e) = imp.settings.set_string("sort-column", &sort_column) {
+            log::warn!("Error on saving the process sort column: {e}");
+        }
+        if let Err(e) = imp.settings.set_string(
+            "sort-direction",
+            match sorter.primary_sort_order() {
+                gtk::SortType::Descending => "desc",
+                _ => "asc",
+            },
+        ) {
+            log::warn!("Error on saving the process sort direction: {e}");
+        }
+    }
+
     pub(crate) async fn kill_selected_processes(&self) {
         let top_source = self.top_source();
 
@@ -18,6 +18,9 @@ where
     };
 
     match volume.container_list().get(0) {
+        Some(_) if !utils::confirm_destructive_actions() => {
+            delete_volume(widget, volume, true).await;
+        }
         Some(container) => {
             let dialog = adw::AlertDialog::builder()
                 .heading(gettext("Confirm Volume Deletion"))
@@ -1,13 +1,19 @@
 use std::cell::RefCell;
+use std::path::Path;
 
 use adw::prelude::*;
 use adw::subclass::prelude::*;
+use ashpd::WindowIdentifier;
+use ashpd::desktop::file_chooser::FileFilter;
+use ashpd::desktop::file_chooser::OpenFileRequest;
+use ashpd::desktop::file_chooser::SaveFileRequest;
 use gettextrs::gettext;
 use glib::Properties;
 use glib::clone;
 use glib::closure;
 use gtk::CompositeTemplate;
 use gtk::gdk;
+use gtk::gio;
 use gtk::glib;
 
 use crate::model;
@@ -17,6 +23,8 @@ use crate::widget;
 
 const ACTION_INSPECT_VOLUME: &str = "volume-details-page.inspect-volume";
 const ACTION_DELETE_VOLUME: &str = "volume-details-page.delete-volume";
+const ACTION_EXPORT_VOLUME: &str = "volume-details-page.export-volume";
+const ACTION_IMPORT_VOLUME: &str = "volume-details-page.import-volume";
 
 mod imp {
     use super::*;
@@ -57,6 +65,13 @@ mod imp {
                 widget.delete_volume().await;
             });
 
+            klass.install_action_async(ACTION_EXPORT_VOLUME, None, async |widget, _, _| {
+                widget.export_volume().await;
+            });
+            klass.install_action_async(ACTION_IMPORT_VOLUME, None, async |widget, _, _| {
+                widget.import_volume().await;
+            });
+
             klass.add_binding_action(
                 gdk::Key::N,
                 gdk::ModifierType::CONTROL_MASK,
@@ -104,14 +119,30 @@ mod imp {
                     clone!(
                         #[weak]
                         obj,
-                        move || {
-                            obj.action_set_enabled(
-                                ACTION_DELETE_VOLUME,
-                                obj.volume()
-                                    .map(|volume| !volume.to_be_deleted())
-                                    .unwrap_or(false),
-                            );
-                        }
+                        move || obj.update_actions()
+                    ),
+                );
+            volume_expr
+                .chain_property::<model::Volume>("action-ongoing")
+                .watch(
+                    Some(obj),
+                    clone!(
+                        #[weak]
+                        obj,
+                        move || obj.update_actions()
+                    ),
+                );
+            volume_expr
+                .chain_property::<model::Volume>("volume-list")
+                .chain_property::<model::VolumeList>("client")
+                .chain_property::<model::Client>("connection")
+                .chain_property::<model::Connection>("read-only")
+                .watch(
+                    Some(obj),
+                    clone!(
+                        #[weak]
+                        obj,
+                        move || obj.update_actions()
                     ),
                 );
 
@@ -230,6 +261,23 @@ impl VolumeDetailsPage {
         .await;
     }
 
+    fn update_actions(&self) {
+        let volume = self.volume();
+        let read_only = volume.as_ref().is_some_and(model::Volume::is_read_only);
+
+        let idle = volume
+            .as_ref()
+            .is_some_and(|volume| !volume.to_be_deleted() && !volume.action_ongoing());
+
+        self.action_set_enabled(
+            ACTION_DELETE_VOLUME,
+            !read_only && volume.is_some_and(|volume| !volume.to_be_deleted()),
+        );
+        self.action_set_enabled(ACTION_EXPORT_VOLUME, idle);
+        self.action_set_enabled(ACTION_IMPORT_VOLUME, !read_only && idle);
+        self.action_set_enabled(view::ContainersGroup::action_create_container(), !read_only);
+    }
+
     pub(crate) async fn delete_volume(&self) {
         self.exec_action(async || {
             view::volume::delete_volume_show_confirmation(self, self.volume().as_ref()).await;
@@ -237,6 +285,148 @@ impl VolumeDetailsPage {
         .await;
     }
 
+    pub(crate) async fn export_volume(&self) {
+        let Some(volume) = self.volume() else {
+            return;
+        };
+
+        let request = SaveFileRequest::default()
+            .identifier(WindowIdentifier::from_native(&self.native().unwrap()).await)
+            .title(gettext("Back Up Volume Data").as_str())
+            .current_name(
+                format!("{}.tar", utils::format_volume_name(&volume.inner().name)).as_str(),
+            )
+            .filter(FileFilter::new(&gettext("Tar Archive")).mimetype("application/x-tar"))
+            .modal(true);
+
+        utils::show_save_file_dialog(
+            request,
+            self,
+            clone!(
+                #[weak(rename_to = obj)]
+                self,
+                #[weak]
+                volume,
+                move |files| {
+                    let Some(path) = gio::File::for_uri(files.uris()[0].as_str()).path() else {
+                        return;
+                    };
+
+                    volume.export(
+                        path,
+                        clone!(
+                            #[weak]
+                            obj,
+                            move |result| match result {
+                                Ok(()) => utils::show_toast(&obj, gettext("Volume data backed up")),
+                                Err(e) => utils::show_error_toast(
+                                    &obj,
+                                    &gettext("Error on backing up volume data"),
+                                    &e.to_string(),
+                                ),
+                            }
+                        ),
+                    );
+                }
+            ),
+        )
+        .await;
+    }
+
+    pub(crate) async fn import_volume(&self) {
+        let Some(volume) = self.volume() else {
+            return;
+        };
+
+        let request = OpenFileRequest::default()
+            .identifier(WindowIdentifier::from_native(&self.native().unwrap()).await)
+            .title(gettext("Restore Volume Data").as_str())
+            .filter(FileFilter::new(&gettext("Tar Archive")).mimetype("application/x-tar"))
+            .modal(true);
+
+        utils::show_open_file_dialog(
+            request,
+            self,
+            clone!(
+                #[weak(rename_to = obj)]
+                self,
+                #[weak]
+                volume,
+                move |files| {
+                    let Some(path) = gio::File::for_uri(files.uris()[0].as_str()).path() else {
+                        return;
+                    };
+
+                    glib::spawn_future_local(clone!(
+                        #[weak]
+                        obj,
+                        #[weak]
+                        volume,
+                        async move {
+                            if !obj.confirm_import(&path).await {
+                                return;
+                            }
+
+                            volume.import(
+                                path,
+                                clone!(
+                                    #[weak]
+                                    obj,
+                                    move |result| match result {
+                                        Ok(()) => utils::show_toast(
+                                            &obj,
+                                            gettext("Volume data restored")
+                                        ),
+                                        Err(e) => utils::show_error_toast(
+                                            &obj,
+                                            &gettext("Error on restoring volume data"),
+                                            &e.to_string(),
+                                        ),
+                                    }
+                                ),
+                            );
+                        }
+                    ));
+                }
+            ),
+        )
+        .await;
+    }
+
+    /// Restoring overwrites files in the volume, so it is confirmed unless confirmations are
+    /// turned off. Large archives are pointed out because they are read into memory completely.
+    async fn confirm_import(&self, path: &Path) -> bool {
+        if !utils::confirm_destructive_actions() {
+            return true;
+        }
+
+        let mut body =
+            gettext("Files in the volume will be overwritten by those from the archive.");
+        if let Ok(metadata) = std::fs::metadata(path)
+            && metadata.len() > model::LARGE_ARCHIVE_SIZE
+        {
+            body.push_str("\n\n");
+            body.push_str(&gettext!(
+                "The archive is {} large and will be loaded into memory completely.",
+                glib::format_size(metadata.len())
+            ));
+        }
+
+        let dialog = adw::AlertDialog::builder()
+            .heading(gettext("Restore Volume Data?"))
+            .body(body)
+            .build();
+
+        dialog.add_responses(&[
+            ("cancel", &gettext("_Cancel")),
+            ("restore", &gettext("_Restore")),
+        ]);
+        dialog.set_default_response(Some("cancel"));
+        dialog.set_response_appearance("restore", adw::ResponseAppearance::Destructive);
+
+        dialog.choose_future(self).await == "restore"
+    }
+
     pub(crate) async fn create_container(&self) {
         self.exec_action(async || {
             view::volume::create_container(self, self.volume());
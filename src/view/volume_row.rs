@@ -98,16 +98,25 @@ mod imp {
                 volume_expr.chain_property::<model::Volume>("to-be-deleted");
             let container_list_expr = volume_expr.chain_property::<model::Volume>("container-list");
 
-            let selection_mode_expr = volume_expr
-                .chain_property::<model::Volume>("volume-list")
-                .chain_property::<model::VolumeList>("selection-mode");
+            let volume_list_expr = volume_expr.chain_property::<model::Volume>("volume-list");
+            let selection_mode_expr =
+                volume_list_expr.chain_property::<model::VolumeList>("selection-mode");
 
             selection_mode_expr.bind(&*self.check_button_revealer, "reveal-child", Some(obj));
-            selection_mode_expr
-                .chain_closure::<bool>(closure!(|_: Self::Type, is_selection_mode: bool| {
-                    !is_selection_mode
-                }))
-                .bind(&*self.end_box_revealer, "reveal-child", Some(obj));
+            gtk::ClosureExpression::new::<bool>(
+                [
+                    selection_mode_expr.upcast_ref(),
+                    volume_list_expr
+                        .chain_property::<model::VolumeList>("client")
+                        .chain_property::<model::Client>("connection")
+                        .chain_property::<model::Connection>("read-only")
+                        .upcast_ref(),
+                ],
+                closure!(|_: Self::Type, is_selection_mode: bool, read_only: bool| {
+                    !is_selection_mode && !read_only
+                }),
+            )
+            .bind(&*self.end_box_revealer, "reveal-child", Some(obj));
 
             gtk::ClosureExpression::new::<String>(
                 [
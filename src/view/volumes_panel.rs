@@ -1,6 +1,7 @@
 use std::cell::Cell;
 use std::cell::OnceCell;
 use std::cell::RefCell;
+use std::collections::BTreeSet;
 use std::ops::Deref;
 
 use adw::prelude::*;
@@ -30,9 +31,12 @@ const ACTION_EXIT_SELECTION_MODE: &str = "volumes-panel.exit-selection-mode";
 const ACTION_SELECT_VISIBLE: &str = "volumes-panel.select-visible";
 const ACTION_SELECT_NONE: &str = "volumes-panel.select-none";
 const ACTION_DELETE_SELECTION: &str = "volumes-panel.delete-selection";
+const ACTION_COPY_INSPECTION_SELECTION: &str = "volumes-panel.copy-inspection-selection";
 const ACTION_TOGGLE_SORT_DIRECTION: &str = "volumes-panel.toggle-sort-direction";
 const ACTION_CHANGE_SORT_ATTRIBUTE: &str = "volumes-panel.change-sort-attribute";
 const ACTION_SHOW_ALL_VOLUMES: &str = "volumes-panel.show-all-volumes";
+const ACTION_FILTER_BY_DRIVER: &str = "volumes-panel.filter-by-driver";
+const ACTION_SHOW_ONLY_DANGLING_VOLUMES: &str = "volumes-panel.show-only-dangling-volumes";
 
 #[derive(Debug)]
 pub(crate) struct Settings(gio::Settings);
@@ -100,7 +104,11 @@ mod imp {
         #[template_child]
         pub(super) view_options_split_button: TemplateChild<adw::SplitButton>,
         #[property(get, set)]
-        pub(super) show_only_used_volumes: Cell<bool>,
+        pub(super) show_only_dangling_volumes: Cell<bool>,
+        #[property(get, set)]
+        pub(super) driver_filter: RefCell<String>,
+        #[template_child]
+        pub(super) driver_menu: TemplateChild<gio::Menu>,
         #[template_child]
         pub(super) main_stack: TemplateChild<gtk::Stack>,
         #[template_child]
@@ -175,6 +183,9 @@ mod imp {
             klass.install_action_async(ACTION_DELETE_SELECTION, None, async |widget, _, _| {
                 widget.delete_selection().await;
             });
+            klass.install_action(ACTION_COPY_INSPECTION_SELECTION, None, |widget, _, _| {
+                widget.copy_inspection_selection();
+            });
 
             klass.install_action(ACTION_TOGGLE_SORT_DIRECTION, None, |widget, _, _| {
                 widget.toggle_sort_direction();
@@ -184,6 +195,12 @@ mod imp {
             klass.install_action(ACTION_SHOW_ALL_VOLUMES, None, |widget, _, _| {
                 widget.show_all_volumes();
             });
+
+            klass.install_property_action(ACTION_FILTER_BY_DRIVER, "driver-filter");
+            klass.install_property_action(
+                ACTION_SHOW_ONLY_DANGLING_VOLUMES,
+                "show-only-dangling-volumes",
+            );
         }
 
         fn instance_init(obj: &glib::subclass::InitializingObject<Self>) {
@@ -225,6 +242,19 @@ mod imp {
             ));
             let collapsed_expr = Self::Type::this_expression("collapsed");
 
+            Self::Type::this_expression("volume-list")
+                .chain_property::<model::VolumeList>("client")
+                .chain_property::<model::Client>("connection")
+                .chain_property::<model::Connection>("read-only")
+                .watch(
+                    Some(obj),
+                    clone!(
+                        #[weak]
+                        obj,
+                        move || obj.imp().update_actions()
+                    ),
+                );
+
             gtk::ClosureExpression::new::<Option<String>>(
                 [
                     &volume_list_len_expr,
@@ -335,13 +365,19 @@ mod imp {
                 #[upgrade_or]
                 false,
                 move |item| {
-                    let term = &*obj.imp().search_term.borrow();
-                    item.downcast_ref::<model::Volume>()
-                        .unwrap()
-                        .inner()
-                        .name
-                        .to_lowercase()
-                        .contains(term)
+                    let volume = item.downcast_ref::<model::Volume>().unwrap();
+                    let driver = obj.driver_filter();
+
+                    // A volume is dangling if no container mounts it. While its containers are
+                    // still searched, it is not known to be dangling yet.
+                    (!obj.show_only_dangling_volumes()
+                        || (!volume.searching_containers() && volume.container_list().len() == 0))
+                        && (driver.is_empty() || volume.inner().driver == driver)
+                        && volume
+                            .inner()
+                            .name
+                            .to_lowercase()
+                            .contains(&*obj.imp().search_term.borrow())
                 }
             ));
 
@@ -438,18 +474,26 @@ mod imp {
         }
 
         #[template_callback]
-        fn on_notify_show_only_used_volumes(&self) {
-            self.update_filter(if self.obj().show_only_used_volumes() {
+        fn on_notify_show_only_dangling_volumes(&self) {
+            self.update_filter(if self.obj().show_only_dangling_volumes() {
                 gtk::FilterChange::MoreStrict
             } else {
                 gtk::FilterChange::LessStrict
             });
         }
 
+        #[template_callback]
+        fn on_notify_driver_filter(&self) {
+            self.update_filter(gtk::FilterChange::Different);
+        }
+
         #[template_callback]
         fn on_notify_search_mode_enabled(&self) {
             if self.search_bar.is_search_mode() {
                 self.search_entry.grab_focus();
+            } else {
+                self.search_entry.set_text("");
+                self.list_box.child_focus(gtk::DirectionType::TabForward);
             }
         }
 
@@ -476,21 +520,29 @@ mod imp {
             value.connect_containers_of_volume_changed(clone!(
                 #[weak]
                 obj,
-                move |_, _| if obj.sort_attribute() == SortAttribute::Containers {
-                    obj.imp().update_sorter();
+                move |_, _| {
+                    if obj.sort_attribute() == SortAttribute::Containers {
+                        obj.imp().update_sorter();
+                    }
+                    if obj.show_only_dangling_volumes() {
+                        obj.imp().update_filter(gtk::FilterChange::Different);
+                    }
                 }
             ));
 
-            obj.action_set_enabled(ACTION_DELETE_SELECTION, false);
+            self.update_driver_menu(value);
+            value.connect_items_changed(clone!(
+                #[weak]
+                obj,
+                move |list, _, _, _| obj.imp().update_driver_menu(list)
+            ));
 
             value.connect_notify_local(
                 Some("num-selected"),
                 clone!(
                     #[weak]
                     obj,
-                    move |list, _| {
-                        obj.action_set_enabled(ACTION_DELETE_SELECTION, list.num_selected() > 0);
-                    }
+                    move |_, _| obj.imp().update_actions()
                 ),
             );
 
@@ -542,6 +594,7 @@ mod imp {
             ));
 
             self.volume_list.set(Some(value));
+            self.update_actions();
         }
 
         fn set_filter_stack_visible_child(
@@ -558,6 +611,53 @@ mod imp {
             );
         }
 
+        /// Offers a filter entry for every driver that is used by at least one volume.
+        fn update_driver_menu(&self, volume_list: &model::VolumeList) {
+            let drivers = volume_list
+                .iter::<model::Volume>()
+                .map_while(Result::ok)
+                .map(|volume| volume.inner().driver.clone())
+                .collect::<BTreeSet<_>>();
+
+            let obj = &*self.obj();
+            if !obj.driver_filter().is_empty() && !drivers.contains(&obj.driver_filter()) {
+                obj.set_driver_filter("");
+            }
+
+            self.driver_menu.remove_all();
+
+            let item = gio::MenuItem::new(Some(&gettext("All Drivers")), None);
+            item.set_action_and_target_value(Some(ACTION_FILTER_BY_DRIVER), Some(&"".to_variant()));
+            self.driver_menu.append_item(&item);
+
+            drivers.iter().for_each(|driver| {
+                let item = gio::MenuItem::new(Some(driver), None);
+                item.set_action_and_target_value(
+                    Some(ACTION_FILTER_BY_DRIVER),
+                    Some(&driver.to_variant()),
+                );
+                self.driver_menu.append_item(&item);
+            });
+        }
+
+        /// Disables all actions that modify the Podman host while the connection is read-only.
+        fn update_actions(&self) {
+            let obj = &*self.obj();
+            let volume_list = obj.volume_list();
+
+            let read_only = volume_list
+                .as_ref()
+                .and_then(model::VolumeList::client)
+                .is_some_and(|client| client.connection().read_only());
+            let any_selected = volume_list.is_some_and(|list| list.num_selected() > 0);
+
+            obj.action_set_enabled(ACTION_DELETE_SELECTION, any_selected && !read_only);
+            obj.action_set_enabled(ACTION_COPY_INSPECTION_SELECTION, any_selected);
+
+            obj.action_set_enabled(ACTION_CREATE_VOLUME, !read_only);
+            obj.action_set_enabled(ACTION_PRUNE_VOLUMES, !read_only);
+        }
+
         fn update_filter(&self, filter_change: gtk::FilterChange) {
             if let Some(filter) = self.filter.get() {
                 filter.changed(filter_change);
@@ -595,7 +695,8 @@ impl VolumesPanel {
     }
 
     pub(crate) fn show_all_volumes(&self) {
-        self.set_show_only_used_volumes(false);
+        self.set_show_only_dangling_volumes(false);
+        self.set_driver_filter("");
         self.set_search_mode(false);
     }
 
@@ -603,8 +704,13 @@ impl VolumesPanel {
         self.imp().search_bar.set_search_mode(value);
     }
 
-    pub(crate) fn toggle_search_mode(&self) {
-        self.set_search_mode(!self.imp().search_bar.is_search_mode());
+    pub(crate) fn focus_search(&self) {
+        let imp = self.imp();
+        if imp.search_bar.is_search_mode() {
+            imp.search_entry.grab_focus();
+        } else {
+            self.set_search_mode(true);
+        }
     }
 
     pub(crate) fn create_volume(&self) {
@@ -658,6 +764,24 @@ impl VolumesPanel {
         }
     }
 
+    pub(crate) fn copy_inspection_selection(&self) {
+        let Some(list) = self.volume_list() else {
+            return;
+        };
+
+        view::inspection::copy_combined_inspection(
+            self,
+            list.selected_items()
+                .iter()
+                .map(|obj| obj.downcast_ref::<model::Volume>().unwrap())
+                .map(|volume| {
+                    let inner = volume.inner();
+                    view::inspection::Inspection::ready(inner.name.clone(), &*inner)
+                })
+                .collect(),
+        );
+    }
+
     pub(crate) async fn delete_selection(&self) {
         let volume_list = if let Some(volume_list) = self.volume_list() {
             volume_list
@@ -669,22 +793,24 @@ impl VolumesPanel {
             return;
         }
 
-        let dialog = adw::AlertDialog::builder()
-            .heading(gettext("Confirm Forced Deletion of Multiple Volumes"))
-            .body(gettext(
-                "There may be containers associated with some of the volumes, which will also be removed!",
-            ))
-            .build();
-
-        dialog.add_responses(&[
-            ("cancel", &gettext("_Cancel")),
-            ("delete", &gettext("_Delete")),
-        ]);
-        dialog.set_default_response(Some("cancel"));
-        dialog.set_response_appearance("delete", adw::ResponseAppearance::Destructive);
-
-        if "delete" != dialog.choose_future(self).await {
-            return;
+        if utils::confirm_destructive_actions() {
+            let dialog = adw::AlertDialog::builder()
+                .heading(gettext("Confirm Forced Deletion of Multiple Volumes"))
+                .body(gettext(
+                    "There may be containers associated with some of the volumes, which will also be removed!",
+                ))
+                .build();
+
+            dialog.add_responses(&[
+                ("cancel", &gettext("_Cancel")),
+                ("delete", &gettext("_Delete")),
+            ]);
+            dialog.set_default_response(Some("cancel"));
+            dialog.set_response_appearance("delete", adw::ResponseAppearance::Destructive);
+
+            if "delete" != dialog.choose_future(self).await {
+                return;
+            }
         }
 
         stream::iter(
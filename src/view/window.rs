@@ -1,9 +1,16 @@
+use std::cell::Cell;
+use std::cell::RefCell;
 use std::sync::OnceLock;
 
 use adw::prelude::*;
 use adw::subclass::prelude::AdwApplicationWindowImpl;
 use adw::subclass::prelude::*;
+use ashpd::WindowIdentifier;
+use ashpd::desktop::file_chooser::FileFilter;
+use ashpd::desktop::file_chooser::OpenFileRequest;
+use ashpd::desktop::file_chooser::SaveFileRequest;
 use gettextrs::gettext;
+use gettextrs::ngettext;
 use gtk::CompositeTemplate;
 use gtk::gdk;
 use gtk::gio;
@@ -18,9 +25,14 @@ use crate::view;
 
 const ACTION_CLOSE: &str = "win.close";
 const ACTION_GLOBAL_SEARCH: &str = "win.toggle-global-search";
-const ACTION_SEARCH: &str = "win.toggle-search";
+const ACTION_SEARCH: &str = "win.focus-search";
 const ACTION_CREATE_CONNECTION: &str = "win.create-connection";
 const ACTION_REMOVE_CONNECTION: &str = "win.remove-connection";
+const ACTION_EXPORT_CONNECTIONS: &str = "win.export-connections";
+const ACTION_IMPORT_CONNECTIONS: &str = "win.import-connections";
+const ACTION_TOGGLE_MINI_MODE: &str = "win.toggle-mini-mode";
+const ACTION_ABORT_CONNECTING: &str = "win.abort-connecting";
+const ACTION_RECONNECT: &str = "win.reconnect";
 
 mod imp {
     use super::*;
@@ -30,11 +42,15 @@ mod imp {
     pub(crate) struct Window {
         pub(super) settings: utils::PodsSettings,
         pub(super) connection_manager: model::ConnectionManager,
+        pub(super) mini_mode: Cell<bool>,
+        pub(super) sleep_subscription: RefCell<Option<gio::SignalSubscription>>,
         #[template_child]
         pub(super) toast_overlay: TemplateChild<adw::ToastOverlay>,
         #[template_child]
         pub(super) main_stack: TemplateChild<gtk::Stack>,
         #[template_child]
+        pub(super) connecting_status_page: TemplateChild<adw::StatusPage>,
+        #[template_child]
         pub(super) connection_chooser_page: TemplateChild<view::ConnectionChooserPage>,
         #[template_child]
         pub(super) client_view: TemplateChild<view::ClientView>,
@@ -59,8 +75,9 @@ mod imp {
             });
 
             klass.add_binding_action(gdk::Key::F, gdk::ModifierType::CONTROL_MASK, ACTION_SEARCH);
+            klass.add_binding_action(gdk::Key::slash, gdk::ModifierType::empty(), ACTION_SEARCH);
             klass.install_action(ACTION_SEARCH, None, |widget, _, _| {
-                widget.toggle_search();
+                widget.focus_search();
             });
 
             klass.add_binding_action(
@@ -81,6 +98,30 @@ mod imp {
                 },
             );
 
+            klass.install_action_async(ACTION_EXPORT_CONNECTIONS, None, async |widget, _, _| {
+                widget.export_connections().await;
+            });
+            klass.install_action_async(ACTION_IMPORT_CONNECTIONS, None, async |widget, _, _| {
+                widget.import_connections().await;
+            });
+
+            klass.add_binding_action(
+                gdk::Key::M,
+                gdk::ModifierType::CONTROL_MASK | gdk::ModifierType::SHIFT_MASK,
+                ACTION_TOGGLE_MINI_MODE,
+            );
+            klass.install_action(ACTION_TOGGLE_MINI_MODE, None, |widget, _, _| {
+                widget.toggle_mini_mode();
+            });
+
+            klass.install_action(ACTION_ABORT_CONNECTING, None, |widget, _, _| {
+                widget.abort_connecting();
+            });
+
+            klass.install_action(ACTION_RECONNECT, None, |widget, _, _| {
+                widget.reconnect();
+            });
+
             klass.add_binding_action(gdk::Key::W, gdk::ModifierType::CONTROL_MASK, ACTION_CLOSE);
             klass.install_action(ACTION_CLOSE, None, |widget, _, _| {
                 widget.close();
@@ -132,6 +173,21 @@ mod imp {
                 obj.maximize();
             }
 
+            obj.action_set_enabled(ACTION_TOGGLE_MINI_MODE, false);
+            self.main_stack.connect_visible_child_name_notify(clone!(
+                #[weak]
+                obj,
+                move |stack| {
+                    obj.action_set_enabled(
+                        ACTION_TOGGLE_MINI_MODE,
+                        matches!(
+                            stack.visible_child_name().as_deref(),
+                            Some("client" | "mini")
+                        ),
+                    );
+                }
+            ));
+
             self.connection_manager.connect_items_changed(clone!(
                 #[weak]
                 obj,
@@ -139,6 +195,10 @@ mod imp {
                     if connection_manager.n_items() == 0 {
                         obj.imp().main_stack.set_visible_child_name("welcome");
                     }
+                    obj.action_set_enabled(
+                        ACTION_EXPORT_CONNECTIONS,
+                        connection_manager.n_items() > 0,
+                    );
                 }
             ));
 
@@ -146,38 +206,9 @@ mod imp {
                 #[weak]
                 obj,
                 move |manager| match manager.client() {
-                    Some(client) => client.check_service(
-                        clone!(
-                            #[weak]
-                            obj,
-                            move || {
-                                obj.imp().main_stack.set_visible_child_full(
-                                    "client",
-                                    gtk::StackTransitionType::None,
-                                );
-                            }
-                        ),
-                        clone!(
-                            #[weak]
-                            obj,
-                            move |e| obj.client_err_op(e)
-                        ),
-                        clone!(
-                            #[weak]
-                            obj,
-                            #[weak]
-                            manager,
-                            move |e| {
-                                utils::show_error_toast(
-                                    &*obj.imp().toast_overlay,
-                                    "Connection lost",
-                                    &e.to_string(),
-                                );
-                                manager.unset_client();
-                            }
-                        ),
-                    ),
+                    Some(client) => obj.check_service(&client),
                     None => {
+                        obj.set_mini_mode(false);
                         obj.imp().main_stack.set_visible_child_full(
                             if manager.n_items() > 0 {
                                 "connection-chooser"
@@ -190,6 +221,9 @@ mod imp {
                 }
             ));
 
+            obj.watch_sleep();
+
+            obj.action_set_enabled(ACTION_EXPORT_CONNECTIONS, false);
             self.connection_manager.setup(clone!(
                 #[weak]
                 obj,
@@ -197,6 +231,14 @@ mod imp {
                     obj.on_connection_manager_setup_error(e);
                 }
             ));
+
+            // The last used connection is pinged in the background. Until it answers, the user can
+            // still pick another connection.
+            if let Some(connection) = self.connection_manager.connecting_connection() {
+                self.connecting_status_page
+                    .set_description(Some(&utils::escape(&connection.name())));
+                self.main_stack.set_visible_child_name("connecting");
+            }
         }
     }
 
@@ -210,6 +252,7 @@ mod imp {
             if let Err(err) = window.save_window_size() {
                 log::warn!("Failed to save window state, {}", &err);
             }
+            self.client_view.save_session();
 
             if view::show_ongoing_actions_warning_dialog(
                 window,
@@ -254,7 +297,7 @@ impl Window {
         }
     }
 
-    pub(crate) fn toggle_search(&self) {
+    pub(crate) fn focus_search(&self) {
         let imp = self.imp();
 
         match imp
@@ -263,8 +306,8 @@ impl Window {
             .unwrap_or_default()
             .as_str()
         {
-            "client" => imp.client_view.toggle_panel_search(),
-            "connection-chooser" => imp.connection_chooser_page.toggle_filter_mode(),
+            "client" => imp.client_view.focus_panel_search(),
+            "connection-chooser" => imp.connection_chooser_page.focus_filter(),
             _ => {}
         }
     }
@@ -293,10 +336,187 @@ impl Window {
         self.connection_manager().remove_connection(uuid).await;
     }
 
+    async fn export_connections(&self) {
+        let request = SaveFileRequest::default()
+            .identifier(WindowIdentifier::from_native(&self.native().unwrap()).await)
+            .title(gettext("Export Connections").as_str())
+            .current_name("pods-connections.json")
+            .filter(FileFilter::new(&gettext("JSON Files")).mimetype("application/json"))
+            .modal(true);
+
+        utils::show_save_file_dialog(
+            request,
+            self,
+            clone!(
+                #[weak(rename_to = obj)]
+                self,
+                move |files| {
+                    let Some(path) = gio::File::for_uri(files.uris()[0].as_str()).path() else {
+                        return;
+                    };
+
+                    glib::spawn_future_local(clone!(
+                        #[weak]
+                        obj,
+                        async move {
+                            let imp = obj.imp();
+                            match imp.connection_manager.export_connections(path).await {
+                                Ok(exported) => utils::show_toast(
+                                    &*imp.toast_overlay,
+                                    ngettext!(
+                                        "Exported {} connection",
+                                        "Exported {} connections",
+                                        exported as u32,
+                                        exported
+                                    ),
+                                ),
+                                Err(e) => utils::show_error_toast(
+                                    &*imp.toast_overlay,
+                                    &gettext("Error on exporting connections"),
+                                    &e.to_string(),
+                                ),
+                            }
+                        }
+                    ));
+                }
+            ),
+        )
+        .await;
+    }
+
+    async fn import_connections(&self) {
+        let request = OpenFileRequest::default()
+            .identifier(WindowIdentifier::from_native(&self.native().unwrap()).await)
+            .title(gettext("Import Connections").as_str())
+            .filter(FileFilter::new(&gettext("JSON Files")).mimetype("application/json"))
+            .modal(true);
+
+        utils::show_open_file_dialog(
+            request,
+            self,
+            clone!(
+                #[weak(rename_to = obj)]
+                self,
+                move |files| {
+                    let Some(path) = gio::File::for_uri(files.uris()[0].as_str()).path() else {
+                        return;
+                    };
+
+                    glib::spawn_future_local(clone!(
+                        #[weak]
+                        obj,
+                        async move {
+                            let imp = obj.imp();
+                            match imp.connection_manager.import_connections(path).await {
+                                Ok((imported, skipped)) => {
+                                    if imported > 0
+                                        && imp.main_stack.visible_child_name().as_deref()
+                                            == Some("welcome")
+                                    {
+                                        imp.main_stack.set_visible_child_name("connection-chooser");
+                                    }
+
+                                    utils::show_toast(
+                                        &*imp.toast_overlay,
+                                        if skipped == 0 {
+                                            ngettext!(
+                                                "Imported {} connection",
+                                                "Imported {} connections",
+                                                imported as u32,
+                                                imported
+                                            )
+                                        } else {
+                                            ngettext!(
+                                                "Imported {} connection, skipped {} existing",
+                                                "Imported {} connections, skipped {} existing",
+                                                imported as u32,
+                                                imported,
+                                                skipped
+                                            )
+                                        },
+                                    );
+                                }
+                                Err(e) => utils::show_error_toast(
+                                    &*imp.toast_overlay,
+                                    &gettext("Error on importing connections"),
+                                    &e.to_string(),
+                                ),
+                            }
+                        }
+                    ));
+                }
+            ),
+        )
+        .await;
+    }
+
+    pub(crate) fn toggle_mini_mode(&self) {
+        self.set_mini_mode(!self.imp().mini_mode.get());
+    }
+
+    /// Switches between the full layout and a compact one that only lists the running
+    /// containers. Both remember their own window size.
+    pub(crate) fn set_mini_mode(&self, mini_mode: bool) {
+        let imp = self.imp();
+
+        if imp.mini_mode.get() == mini_mode {
+            return;
+        }
+
+        if let Err(err) = self.save_window_size() {
+            log::warn!("Failed to save window state, {}", &err);
+        }
+        imp.mini_mode.set(mini_mode);
+
+        if mini_mode {
+            self.unmaximize();
+            self.set_default_size(
+                imp.settings.int("mini-window-width"),
+                imp.settings.int("mini-window-height"),
+            );
+        } else {
+            self.set_default_size(
+                imp.settings.int("window-width"),
+                imp.settings.int("window-height"),
+            );
+            if imp.settings.boolean("is-maximized") {
+                self.maximize();
+            }
+        }
+
+        if matches!(
+            imp.main_stack.visible_child_name().as_deref(),
+            Some("client" | "mini")
+        ) {
+            imp.main_stack.set_visible_child_full(
+                if mini_mode { "mini" } else { "client" },
+                gtk::StackTransitionType::Crossfade,
+            );
+        }
+    }
+
+    /// Leaves the mini mode and shows the details of the container.
+    pub(crate) fn show_container(&self, container: &model::Container) {
+        self.set_mini_mode(false);
+
+        self.navigation_view().push(
+            &adw::NavigationPage::builder()
+                .child(&view::ContainerDetailsPage::from(container))
+                .build(),
+        );
+    }
+
     pub(crate) fn save_window_size(&self) -> Result<(), glib::BoolError> {
         let (width, height) = self.default_size();
 
         let imp = self.imp();
+
+        if imp.mini_mode.get() {
+            imp.settings.set_int("mini-window-width", width)?;
+            imp.settings.set_int("mini-window-height", height)?;
+            return Ok(());
+        }
+
         imp.settings.set_int("window-width", width)?;
         imp.settings.set_int("window-height", height)?;
         imp.settings
@@ -305,6 +525,118 @@ impl Window {
         Ok(())
     }
 
+    fn abort_connecting(&self) {
+        let imp = self.imp();
+
+        imp.connection_manager.abort();
+        imp.main_stack
+            .set_visible_child_name(if imp.connection_manager.n_items() > 0 {
+                "connection-chooser"
+            } else {
+                "welcome"
+            });
+    }
+
+    /// Pings the service of the client and, if it answers, refreshes everything and (re)starts
+    /// listening to events.
+    fn check_service(&self, client: &model::Client) {
+        client.check_service(
+            clone!(
+                #[weak(rename_to = obj)]
+                self,
+                move || {
+                    let imp = obj.imp();
+                    imp.main_stack.set_visible_child_full(
+                        if imp.mini_mode.get() {
+                            "mini"
+                        } else {
+                            "client"
+                        },
+                        gtk::StackTransitionType::None,
+                    );
+                }
+            ),
+            clone!(
+                #[weak(rename_to = obj)]
+                self,
+                move |e| obj.client_err_op(e)
+            ),
+            clone!(
+                #[weak(rename_to = obj)]
+                self,
+                move |e| {
+                    let imp = obj.imp();
+                    utils::show_error_toast(&*imp.toast_overlay, "Connection lost", &e.to_string());
+                    imp.connection_manager.unset_client();
+                }
+            ),
+        );
+    }
+
+    /// Listens to the login manager announcing that the system is going to sleep or has just woken
+    /// up. Connections, especially remote ones, are often dead after resuming.
+    fn watch_sleep(&self) {
+        gio::bus_get(
+            gio::BusType::System,
+            gio::Cancellable::NONE,
+            clone!(
+                #[weak(rename_to = obj)]
+                self,
+                move |result| match result {
+                    Ok(connection) => {
+                        let subscription = connection.subscribe_to_signal(
+                            Some("org.freedesktop.login1"),
+                            Some("org.freedesktop.login1.Manager"),
+                            Some("PrepareForSleep"),
+                            Some("/org/freedesktop/login1"),
+                            None,
+                            gio::DBusSignalFlags::NONE,
+                            clone!(
+                                #[weak]
+                                obj,
+                                move |signal| {
+                                    if let Some((false,)) = signal.parameters.get::<(bool,)>() {
+                                        obj.on_resume();
+                                    }
+                                }
+                            ),
+                        );
+                        obj.imp().sleep_subscription.replace(Some(subscription));
+                    }
+                    Err(e) => log::warn!("Cannot watch for system sleep: {e}"),
+                }
+            ),
+        );
+    }
+
+    fn on_resume(&self) {
+        let Some(client) = self.imp().connection_manager.client() else {
+            return;
+        };
+
+        log::info!(
+            "Checking connection '{}' after resuming from sleep",
+            client.connection().name()
+        );
+
+        client.connection().check_socket();
+        self.check_service(&client);
+    }
+
+    fn reconnect(&self) {
+        self.imp().connection_manager.reconnect(clone!(
+            #[weak(rename_to = obj)]
+            self,
+            move |result| if let Err(e) = result {
+                utils::show_error_toast(
+                    &*obj.imp().toast_overlay,
+                    &gettext("Error on reconnecting"),
+                    &e.to_string(),
+                );
+            }
+        ));
+    }
+
     fn on_connection_manager_setup_error(&self, e: impl ToString) {
         let imp = self.imp();
 
@@ -28,9 +28,9 @@ mod imp {
         pub(super) desktop_settings: utils::DesktopSettings,
         pub(super) time_format: Cell<TimeFormat>,
         #[property(get, set)]
-        pub(super) prune_until_timestamp: Cell<i64>,
+        pub(super) timestamp: Cell<i64>,
         #[template_child]
-        pub(super) prune_until_label: TemplateChild<gtk::Label>,
+        pub(super) timestamp_label: TemplateChild<gtk::Label>,
         #[template_child]
         pub(super) calendar: TemplateChild<gtk::Calendar>,
         #[template_child]
@@ -124,9 +124,9 @@ mod imp {
                     .to_unix()
                 }),
             )
-            .bind(obj, "prune-until-timestamp", Some(obj));
+            .bind(obj, "timestamp", Some(obj));
 
-            Self::Type::this_expression("prune-until-timestamp")
+            Self::Type::this_expression("timestamp")
                 .chain_closure::<String>(closure!(|_: Self::Type, unix: i64| {
                     glib::DateTime::from_unix_local(unix)
                         .unwrap()
@@ -136,7 +136,7 @@ mod imp {
                         )
                         .unwrap_or_else(|_| gettext("Invalid date format").into())
                 }))
-                .bind(&*self.prune_until_label, "label", Some(obj));
+                .bind(&*self.timestamp_label, "label", Some(obj));
 
             let (hour, minute) = glib::DateTime::now_local()
                 .map(|now| (now.hour(), now.minute()))
@@ -169,6 +169,12 @@ glib::wrapper! {
         @implements gtk::Accessible, gtk::Actionable, gtk::Buildable, gtk::ConstraintTarget, gtk::ListBoxRow;
 }
 
+impl Default for DateTimeRow {
+    fn default() -> Self {
+        glib::Object::builder().build()
+    }
+}
+
 impl DateTimeRow {
     fn load_time_format(&self) {
         let imp = self.imp();
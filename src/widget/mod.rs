@@ -9,6 +9,7 @@ mod scalable_text_view;
 mod source_view_search_widget;
 mod spinner;
 mod text_search_entry;
+mod throughput_graph;
 mod zoom_control;
 
 pub(crate) use self::circular_progress_bar::CircularProgressBar;
@@ -22,4 +23,5 @@ pub(crate) use self::scalable_text_view::ScalableTextView;
 pub(crate) use self::source_view_search_widget::SourceViewSearchWidget;
 pub(crate) use self::spinner::Spinner;
 pub(crate) use self::text_search_entry::TextSearchEntry;
+pub(crate) use self::throughput_graph::ThroughputGraph;
 pub(crate) use self::zoom_control::ZoomControl;
@@ -5,13 +5,21 @@ use adw::subclass::prelude::*;
 use gtk::CompositeTemplate;
 use gtk::glib;
 
+use crate::utils;
+
+/// The placeholder that is replaced with a counter in name templates.
+const COUNTER_PLACEHOLDER: &str = "{n}";
+
 mod imp {
     use super::*;
 
     #[derive(Default, CompositeTemplate)]
     #[template(resource = "/com/github/marhkb/Pods/ui/widget/random_name_entry_row.ui")]
     pub(crate) struct RandomNameEntryRow {
+        pub(super) settings: utils::PodsSettings,
         pub(super) names: RefCell<names::Generator<'static>>,
+        /// Tells whether a name is already in use. Names follow the configured scheme once set.
+        pub(super) name_taken_func: RefCell<Option<Box<dyn Fn(&str) -> bool>>>,
         #[template_child]
         pub(super) generate_button: TemplateChild<gtk::Button>,
     }
@@ -82,6 +90,43 @@ impl Default for RandomNameEntryRow {
 
 impl RandomNameEntryRow {
     pub(crate) fn generate_random_name(&self) {
-        self.set_text(&self.imp().names.borrow_mut().next().unwrap());
+        let imp = self.imp();
+
+        let name = match imp.name_taken_func.borrow().as_ref() {
+            Some(is_taken) => match imp.settings.string("container-name-scheme").as_str() {
+                "uuid" => Some(glib::uuid_string_random()[..8].to_owned()),
+                "template" => {
+                    let template = imp.settings.string("container-name-template");
+                    let template = template.trim();
+                    (!template.is_empty()).then(|| from_template(template, is_taken))
+                }
+                _ => None,
+            },
+            None => None,
+        };
+
+        self.set_text(&name.unwrap_or_else(|| imp.names.borrow_mut().next().unwrap()));
     }
+
+    /// Makes the row follow the configured name scheme, counting up template names until one is
+    /// free.
+    pub(crate) fn set_name_taken_func<F: Fn(&str) -> bool + 'static>(&self, is_taken: F) {
+        self.imp().name_taken_func.replace(Some(Box::new(is_taken)));
+        self.generate_random_name();
+    }
+}
+
+/// Replaces the counter placeholder with the lowest number that gives a free name. The counter
+/// is appended if the template has no placeholder.
+fn from_template(template: &str, is_taken: &dyn Fn(&str) -> bool) -> String {
+    let template = if template.contains(COUNTER_PLACEHOLDER) {
+        template.to_owned()
+    } else {
+        format!("{template}-{COUNTER_PLACEHOLDER}")
+    };
+
+    (1..)
+        .map(|n: u32| template.replace(COUNTER_PLACEHOLDER, &n.to_string()))
+        .find(|name| !is_taken(name))
+        .unwrap()
 }
@@ -0,0 +1,121 @@
+use std::cell::RefCell;
+use std::collections::VecDeque;
+
+use adw::prelude::*;
+use adw::subclass::prelude::*;
+use gtk::glib;
+use gtk::gsk;
+
+/// The number of samples that are shown at once.
+const CAPACITY: usize = 60;
+const HEIGHT: i32 = 32;
+const WIDTH: i32 = 120;
+const LINE_WIDTH: f32 = 1.5;
+
+mod imp {
+    use super::*;
+
+    #[derive(Debug, Default)]
+    pub(crate) struct ThroughputGraph {
+        pub(super) samples: RefCell<VecDeque<(u64, u64)>>,
+    }
+
+    #[glib::object_subclass]
+    impl ObjectSubclass for ThroughputGraph {
+        const NAME: &'static str = "PdsThroughputGraph";
+        type Type = super::ThroughputGraph;
+        type ParentType = gtk::Widget;
+
+        fn class_init(klass: &mut Self::Class) {
+            klass.set_css_name("throughputgraph");
+        }
+    }
+
+    impl ObjectImpl for ThroughputGraph {}
+
+    impl WidgetImpl for ThroughputGraph {
+        fn measure(&self, orientation: gtk::Orientation, _for_size: i32) -> (i32, i32, i32, i32) {
+            match orientation {
+                gtk::Orientation::Horizontal => (WIDTH, WIDTH, -1, -1),
+                _ => (HEIGHT, HEIGHT, -1, -1),
+            }
+        }
+
+        fn snapshot(&self, snapshot: &gtk::Snapshot) {
+            let widget = &*self.obj();
+            let samples = self.samples.borrow();
+
+            if samples.len() < 2 {
+                return;
+            }
+
+            let width = widget.width() as f32;
+            let height = widget.height() as f32 - LINE_WIDTH;
+            let max = samples
+                .iter()
+                .map(|(input, output)| *input.max(output))
+                .max()
+                .unwrap_or(0)
+                .max(1) as f32;
+            let step = width / (CAPACITY - 1) as f32;
+            let offset = (CAPACITY - samples.len()) as f32 * step;
+
+            let path = |value: fn(&(u64, u64)) -> u64| {
+                let builder = gsk::PathBuilder::new();
+                samples.iter().enumerate().for_each(|(i, sample)| {
+                    let x = offset + i as f32 * step;
+                    let y = LINE_WIDTH / 2.0 + height - value(sample) as f32 / max * height;
+                    if i == 0 {
+                        builder.move_to(x, y);
+                    } else {
+                        builder.line_to(x, y);
+                    }
+                });
+                builder.to_path()
+            };
+
+            let stroke = gsk::Stroke::new(LINE_WIDTH);
+
+            let mut output_color = widget.color();
+            output_color.set_alpha(0.55);
+            snapshot.append_stroke(&path(|(_, output)| *output), &stroke, &output_color);
+
+            snapshot.append_stroke(
+                &path(|(input, _)| *input),
+                &stroke,
+                &adw::StyleManager::default().accent_color_rgba(),
+            );
+        }
+    }
+}
+
+glib::wrapper! {
+    /// Draws the recent history of an input and an output throughput as two lines.
+    pub(crate) struct ThroughputGraph(ObjectSubclass<imp::ThroughputGraph>)
+        @extends gtk::Widget,
+        @implements gtk::Accessible, gtk::Buildable, gtk::ConstraintTarget;
+}
+
+impl Default for ThroughputGraph {
+    fn default() -> Self {
+        glib::Object::builder().build()
+    }
+}
+
+impl ThroughputGraph {
+    pub(crate) fn push(&self, input: u64, output: u64) {
+        let mut samples = self.imp().samples.borrow_mut();
+        if samples.len() == CAPACITY {
+            samples.pop_front();
+        }
+        samples.push_back((input, output));
+        drop(samples);
+
+        self.queue_draw();
+    }
+
+    pub(crate) fn clear(&self) {
+        self.imp().samples.borrow_mut().clear();
+        self.queue_draw();
+    }
+}